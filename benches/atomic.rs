@@ -0,0 +1,79 @@
+//! Cost of `send_latest` on a `Flower` and on an `AtomicFlower`, with and without a consumer polling:
+//!
+//! cargo bench --bench atomic --features atomic
+
+use flowync::{AtomicFlower, Flower};
+use std::{
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+    time::{Duration, Instant},
+};
+
+const VALUES: u64 = 1_000_000;
+
+fn main() {
+    for polled in [false, true] {
+        let consumer = if polled { "polled" } else { "idle" };
+
+        let flower: Flower<u64, ()> = Flower::new(1);
+        let handle = flower.handle();
+        let elapsed = with_consumer(
+            polled,
+            move || flower.then(|_| (), |_| ()),
+            || {
+                for i in 0..VALUES {
+                    handle.send_latest(i);
+                }
+            },
+        );
+        report("flower", consumer, elapsed);
+
+        let flower: AtomicFlower<u64, ()> = AtomicFlower::new(2);
+        let handle = flower.handle();
+        let elapsed = with_consumer(
+            polled,
+            move || flower.then(|_| (), |_| ()),
+            || {
+                for i in 0..VALUES {
+                    handle.send_latest(i);
+                }
+            },
+        );
+        report("atomic", consumer, elapsed);
+    }
+}
+
+fn with_consumer(
+    polled: bool,
+    poll: impl Fn() + Send + 'static,
+    produce: impl FnOnce(),
+) -> Duration {
+    let stop = Arc::new(AtomicBool::new(false));
+    let consumer = polled.then(|| {
+        let stop = stop.clone();
+        std::thread::spawn(move || {
+            while !stop.load(Ordering::Relaxed) {
+                poll();
+            }
+        })
+    });
+    let instant = Instant::now();
+    produce();
+    let elapsed = instant.elapsed();
+    stop.store(true, Ordering::Relaxed);
+    if let Some(consumer) = consumer {
+        consumer.join().unwrap();
+    }
+    elapsed
+}
+
+fn report(name: &str, consumer: &str, elapsed: Duration) {
+    println!(
+        "{} {}: {:?} per send_latest",
+        name,
+        consumer,
+        elapsed / VALUES as u32
+    );
+}
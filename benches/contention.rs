@@ -0,0 +1,73 @@
+//! Latency of the `then` calls taking the result while a producer is spamming `send_latest`,
+//! and of every `then` call:
+//!
+//! cargo bench --bench contention
+//! cargo bench --bench contention --features parking-lot
+
+use flowync::Flower;
+use std::{
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+    time::{Duration, Instant},
+};
+
+const RUNS: usize = 20_000;
+
+fn main() {
+    let backend = if cfg!(any(feature = "parking-lot", feature = "parking_lot")) {
+        "parking_lot"
+    } else {
+        "std"
+    };
+    let flower: Flower<Vec<u64>, u64> = Flower::new(1);
+    let stop = Arc::new(AtomicBool::new(false));
+    let handle = flower.handle();
+    let spammer = std::thread::spawn({
+        let handle = handle.clone();
+        let stop = stop.clone();
+        move || {
+            while !stop.load(Ordering::Relaxed) {
+                handle.send_latest(vec![0; 64]);
+                std::hint::spin_loop();
+            }
+        }
+    });
+
+    let mut results = Vec::with_capacity(RUNS);
+    let mut polls = Vec::with_capacity(RUNS * 4);
+    for run in 0..RUNS as u64 {
+        handle.activate();
+        handle.ok(run);
+        let mut done = false;
+        while !done {
+            let instant = Instant::now();
+            flower.then(|_| (), |_| done = true);
+            let elapsed = instant.elapsed();
+            polls.push(elapsed);
+            if done {
+                results.push(elapsed);
+            }
+        }
+    }
+    stop.store(true, Ordering::Relaxed);
+    spammer.join().unwrap();
+
+    report(backend, "result", results);
+    report(backend, "every", polls);
+}
+
+fn report(backend: &str, name: &str, mut latencies: Vec<Duration>) {
+    latencies.sort();
+    let at = |q: f64| latencies[((latencies.len() - 1) as f64 * q) as usize];
+    println!(
+        "{} {}: {} polls, p50 {:?}, p99 {:?}, p99.9 {:?}",
+        backend,
+        name,
+        latencies.len(),
+        at(0.5),
+        at(0.99),
+        at(0.999)
+    );
+}
@@ -0,0 +1,57 @@
+//! A worker spinning on `set_progress` while the consumer spins on `is_active`,
+//! both sides should barely slow each other down. Needs at least two cores to mean anything:
+//!
+//! cargo bench --bench false_sharing
+
+use flowync::Flower;
+use std::{
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc, Barrier,
+    },
+    time::{Duration, Instant},
+};
+
+const RUN: Duration = Duration::from_secs(1);
+
+fn main() {
+    if std::thread::available_parallelism().map_or(1, |n| n.get()) < 2 {
+        println!("single core, the threads never run at the same time");
+    }
+    let flower: Flower<(), ()> = Flower::new(1);
+    let handle = flower.handle();
+    handle.activate();
+    let stop = Arc::new(AtomicBool::new(false));
+    let start = Arc::new(Barrier::new(2));
+    let worker = std::thread::spawn({
+        let stop = stop.clone();
+        let start = start.clone();
+        move || {
+            start.wait();
+            let mut reports = 0u64;
+            while !stop.load(Ordering::Relaxed) {
+                handle.set_progress((reports % 100) as f32 / 100.0);
+                reports += 1;
+            }
+            reports
+        }
+    });
+
+    start.wait();
+    let instant = Instant::now();
+    let mut checks = 0u64;
+    while instant.elapsed() < RUN {
+        for _ in 0..1_000 {
+            std::hint::black_box(flower.is_active());
+        }
+        checks += 1_000;
+    }
+    stop.store(true, Ordering::Relaxed);
+    let reports = worker.join().unwrap();
+    let elapsed = instant.elapsed().as_secs_f64();
+    println!(
+        "is_active: {:.0} checks/s, set_progress: {:.0} reports/s",
+        checks as f64 / elapsed,
+        reports as f64 / elapsed
+    );
+}
@@ -0,0 +1,58 @@
+//! Send/then throughput of the flower, compare the backends with:
+//!
+//! cargo bench --bench throughput
+//! cargo bench --bench throughput --features parking-lot
+//! cargo bench --bench throughput --features park
+
+use flowync::Flower;
+use std::time::{Duration, Instant};
+
+const VALUES: u32 = 100_000;
+
+fn drive(flower: Flower<u32, ()>) -> Duration {
+    let instant = Instant::now();
+    let worker = std::thread::spawn({
+        let handle = flower.handle();
+        handle.activate();
+        move || {
+            for i in 0..VALUES {
+                handle.send(i).unwrap();
+            }
+            handle.ok(());
+        }
+    });
+
+    let mut done = false;
+    while !done {
+        flower.then(|_| (), |_| done = true);
+    }
+    worker.join().unwrap();
+    instant.elapsed()
+}
+
+fn main() {
+    let backend = if cfg!(any(feature = "parking-lot", feature = "parking_lot")) {
+        "parking_lot"
+    } else {
+        "std"
+    };
+    let backend = if cfg!(feature = "park") {
+        format!("{}+park", backend)
+    } else {
+        backend.to_string()
+    };
+    for (name, flower) in [
+        ("unbuffered", Flower::new(1)),
+        ("buffered", Flower::new_buffered(2)),
+    ] {
+        let elapsed = drive(flower);
+        println!(
+            "{} {}: {} values in {:?} ({:.0} values/s)",
+            backend,
+            name,
+            VALUES,
+            elapsed,
+            VALUES as f64 / elapsed.as_secs_f64()
+        );
+    }
+}
@@ -0,0 +1,80 @@
+// cargo run --example download_progress --features cli
+use ::indicatif::{ProgressBar, ProgressStyle};
+use flowync::{indicatif, Flower, FlowerHandle};
+use std::{
+    io::{BufRead, BufReader, Read, Write},
+    net::{TcpListener, TcpStream},
+    thread,
+    time::Duration,
+};
+
+const FILE_LEN: usize = 4 * 1024 * 1024;
+
+// A slow local file server, so the example runs offline.
+fn serve() -> std::io::Result<u16> {
+    let listener = TcpListener::bind("127.0.0.1:0")?;
+    let port = listener.local_addr()?.port();
+    thread::spawn(move || {
+        for mut stream in listener.incoming().flatten() {
+            let _ = stream.write_all(
+                format!("HTTP/1.1 200 OK\r\nContent-Length: {}\r\n\r\n", FILE_LEN).as_bytes(),
+            );
+            for chunk in vec![0u8; FILE_LEN].chunks(64 * 1024) {
+                thread::sleep(Duration::from_millis(30));
+                if stream.write_all(chunk).is_err() {
+                    break;
+                }
+            }
+        }
+    });
+    Ok(port)
+}
+
+// Send `(downloaded, length)` as the body comes in, return the body.
+fn download(port: u16, handle: &FlowerHandle<(u64, u64), Vec<u8>>) -> std::io::Result<Vec<u8>> {
+    let mut stream = TcpStream::connect(("127.0.0.1", port))?;
+    stream.write_all(b"GET /file HTTP/1.1\r\nHost: localhost\r\n\r\n")?;
+    let mut reader = BufReader::new(stream);
+    let mut len = 0;
+    loop {
+        let mut line = String::new();
+        reader.read_line(&mut line)?;
+        if line.trim().is_empty() {
+            break;
+        }
+        if let Some(value) = line.strip_prefix("Content-Length:") {
+            len = value.trim().parse().unwrap_or(0);
+        }
+    }
+
+    let mut body = Vec::with_capacity(len as usize);
+    let mut buf = [0u8; 16 * 1024];
+    while (body.len() as u64) < len && !handle.should_cancel() {
+        let n = reader.read(&mut buf)?;
+        if n == 0 {
+            break;
+        }
+        body.extend_from_slice(&buf[..n]);
+        handle.send_latest((body.len() as u64, len));
+    }
+    Ok(body)
+}
+
+fn main() -> std::io::Result<()> {
+    let port = serve()?;
+    let flower: Flower<(u64, u64), Vec<u8>> =
+        Flower::spawn(1, move |handle| match download(port, &handle) {
+            Ok(body) => handle.ok(body),
+            Err(e) => handle.err(e.to_string()),
+        });
+
+    let bar = ProgressBar::new(0).with_style(
+        ProgressStyle::with_template("{bar:40} {bytes}/{total_bytes} ({bytes_per_sec})")
+            .expect("valid template"),
+    );
+    match indicatif::drive_to_completion(&flower, bar) {
+        Ok(body) => println!("downloaded {} bytes", body.len()),
+        Err(e) => println!("download failed: {}", e),
+    }
+    Ok(())
+}
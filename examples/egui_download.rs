@@ -0,0 +1,104 @@
+// cargo run --example egui_download --features egui
+use flowync::{egui::show_progress, Flower, FlowerHandle};
+use std::{thread, time::Duration};
+
+const FILE_LEN: u64 = 8 * 1024 * 1024;
+const CHUNK_LEN: u64 = 128 * 1024;
+
+// A fake download, the downloaded bytes go through the channel and the progress is set.
+fn download(handle: &FlowerHandle<u64, u64>) {
+    let mut downloaded = 0;
+    while downloaded < FILE_LEN {
+        if handle.should_cancel() {
+            return handle.cancel_ack();
+        }
+        thread::sleep(Duration::from_millis(40));
+        downloaded += CHUNK_LEN;
+        handle.send_latest(downloaded);
+        handle.set_progress(downloaded as f32 / FILE_LEN as f32);
+    }
+    handle.ok(downloaded);
+}
+
+struct App {
+    ctx: egui::Context,
+    flower: Option<Flower<u64, u64>>,
+    downloaded: u64,
+    status: String,
+}
+
+impl App {
+    fn start(&mut self) {
+        let flower = Flower::new_auto();
+        // Repaint as soon as the worker sends something, not on the next mouse move.
+        flower.attach_egui_ctx(self.ctx.clone());
+        let handle = flower.handle();
+        handle.activate();
+        thread::spawn(move || download(&handle));
+        self.flower = Some(flower);
+        self.downloaded = 0;
+        self.status = "downloading".into();
+    }
+}
+
+impl eframe::App for App {
+    fn ui(&mut self, ui: &mut egui::Ui, _frame: &mut eframe::Frame) {
+        if let Some(flower) = &self.flower {
+            let (downloaded, status) = (&mut self.downloaded, &mut self.status);
+            flower.then(
+                |value| {
+                    if let Some(value) = value {
+                        *downloaded = value;
+                    }
+                },
+                |result| {
+                    *status = match result {
+                        Ok(len) => format!("downloaded {} bytes", len),
+                        Err(e) => e,
+                    }
+                },
+            );
+        }
+
+        egui::CentralPanel::default().show(ui, |ui| {
+            ui.heading("Fake download");
+            let running = self.flower.as_ref().is_some_and(Flower::is_active);
+            ui.horizontal(|ui| {
+                if ui
+                    .add_enabled(!running, egui::Button::new("Download"))
+                    .clicked()
+                {
+                    self.start();
+                }
+                if ui
+                    .add_enabled(running, egui::Button::new("Cancel"))
+                    .clicked()
+                {
+                    if let Some(flower) = &self.flower {
+                        flower.cancel();
+                    }
+                }
+            });
+            if let Some(flower) = &self.flower {
+                show_progress(ui, flower);
+            }
+            ui.label(format!("{} / {} bytes", self.downloaded, FILE_LEN));
+            ui.label(&self.status);
+        });
+    }
+}
+
+fn main() -> eframe::Result {
+    eframe::run_native(
+        "flowync egui download",
+        eframe::NativeOptions::default(),
+        Box::new(|cc| {
+            Ok(Box::new(App {
+                ctx: cc.egui_ctx.clone(),
+                flower: None,
+                downloaded: 0,
+                status: String::new(),
+            }))
+        }),
+    )
+}
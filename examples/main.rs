@@ -13,9 +13,12 @@ fn main() {
         move || {
             for i in 0..10 {
                 // // Send current value through channel, will block the spawned thread
-                // until the option value successfully being polled in the main thread.
-                handle.send(i);
-                // or handle.send_async(i).await; can be used from any multithreaded async runtime,
+                // until the option value successfully being polled in the main thread,
+                // fails if the flower is canceled or dropped in the meantime.
+                if handle.send(i).is_err() {
+                    break;
+                }
+                // or handle.send_async(i).await, can be used from any multithreaded async runtime,
 
                 // // Return error if the job is failure, for example:
                 // if i >= 3 {
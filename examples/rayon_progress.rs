@@ -0,0 +1,38 @@
+// cargo run --example rayon_progress --features rayon
+use flowync::{Flower, ParallelProgressExt};
+use rayon::prelude::*;
+use std::{io::Write, time::Duration};
+
+fn main() {
+    let data: Vec<u64> = (0..20_000_000).collect();
+    let flower: Flower<(), u64> = Flower::spawn(1, move |handle| {
+        let sum = data
+            .par_iter()
+            .flower_progress(&handle, data.len())
+            .map(|x| x % 7)
+            .sum();
+        if handle.should_cancel() {
+            return handle.cancel_ack();
+        }
+        handle.ok(sum);
+    });
+
+    let mut exit = false;
+    while !exit {
+        if let Some(p) = flower.progress() {
+            print!("\r{:>3.0}%", p * 100.0);
+            let _ = std::io::stdout().flush();
+        }
+        flower.then(
+            |_| (),
+            |result| {
+                match result {
+                    Ok(sum) => println!("\rdone, sum: {}", sum),
+                    Err(e) => println!("\r{}", e),
+                }
+                exit = true;
+            },
+        );
+        std::thread::sleep(Duration::from_millis(10));
+    }
+}
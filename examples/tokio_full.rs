@@ -19,13 +19,14 @@ async fn main() {
         this.activate();
         async move {
             let id = this.id();
-            let result =
-                Ok::<String, Error>(format!("the flower with id: {} is flowing", id).into());
+            let result = Ok::<String, Error>(format!("the flower with id: {} is flowing", id));
 
             match result {
                 Ok(value) => {
                     // Send current flower progress.
-                    this.send_async(value).await;
+                    if let Err(e) = this.send_async(value).await {
+                        return this.err(e.to_string());
+                    }
                 }
                 Err(e) => {
                     // Return error immediately if something not right, for example:
@@ -37,7 +38,7 @@ async fn main() {
             // Check if the current flower should be canceled
             if this.should_cancel() {
                 let value = format!("canceling the flower with id: {}", id);
-                this.send_async(value).await;
+                let _ = this.send_async(value).await;
                 return this.err(format!("the flower with id: {} canceled", id));
             }
 
@@ -33,7 +33,9 @@ fn main() {
                 match result {
                     Ok(value) => {
                         // Send current flower progress.
-                        this.send(value);
+                        if let Err(e) = this.send(value) {
+                            return this.err(e.to_string());
+                        }
                     }
                     Err(e) => {
                         // Return error immediately if something not right, for example:
@@ -45,7 +47,7 @@ fn main() {
                 // Check if the current flower should be canceled
                 if this.should_cancel() {
                     let value = format!("canceling the flower with id: {}", id);
-                    this.send(value);
+                    let _ = this.send(value);
                     return this.err(format!("the flower with id: {} canceled", id));
                 }
 
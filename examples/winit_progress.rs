@@ -0,0 +1,123 @@
+// cargo run --example winit_progress --features winit
+//
+// The event loop only waits for events, a wake-up sent by the flower redraws the bar.
+use flowync::{Flower, FlowerPoll};
+use std::{num::NonZeroU32, rc::Rc, thread, time::Duration};
+use winit::{
+    application::ApplicationHandler,
+    dpi::LogicalSize,
+    event::WindowEvent,
+    event_loop::{ActiveEventLoop, EventLoop},
+    window::{Window, WindowId},
+};
+
+const BACKGROUND: u32 = 0x0020_2020;
+const BAR: u32 = 0x0040_a0f0;
+const DONE: u32 = 0x0040_c060;
+
+// The user event of the loop, sent by the flower.
+#[derive(Debug, Clone)]
+struct Wake;
+
+struct Surface {
+    window: Rc<Window>,
+    surface: softbuffer::Surface<Rc<Window>, Rc<Window>>,
+}
+
+struct App {
+    flower: Flower<u32, ()>,
+    percent: u32,
+    done: bool,
+    surface: Option<Surface>,
+}
+
+impl App {
+    fn redraw(&mut self) {
+        let Surface { window, surface } = match &mut self.surface {
+            Some(surface) => surface,
+            None => return,
+        };
+        let size = window.inner_size();
+        let (width, height) = match (NonZeroU32::new(size.width), NonZeroU32::new(size.height)) {
+            (Some(width), Some(height)) => (width, height),
+            _ => return,
+        };
+        surface.resize(width, height).expect("resize the surface");
+        let mut buffer = surface.buffer_mut().expect("get the buffer");
+        let filled = size.width * self.percent / 100;
+        let color = if self.done { DONE } else { BAR };
+        for (i, pixel) in buffer.iter_mut().enumerate() {
+            let x = i as u32 % size.width;
+            *pixel = if x < filled { color } else { BACKGROUND };
+        }
+        buffer.present().expect("present the buffer");
+        window.set_title(&format!("winit progress: {}%", self.percent));
+    }
+}
+
+impl ApplicationHandler<Wake> for App {
+    fn resumed(&mut self, event_loop: &ActiveEventLoop) {
+        let attributes = Window::default_attributes()
+            .with_title("winit progress")
+            .with_inner_size(LogicalSize::new(400.0, 40.0));
+        let window = Rc::new(
+            event_loop
+                .create_window(attributes)
+                .expect("create a window"),
+        );
+        let context = softbuffer::Context::new(window.clone()).expect("create a context");
+        let surface = softbuffer::Surface::new(&context, window.clone()).expect("create a surface");
+        self.surface = Some(Surface { window, surface });
+    }
+
+    fn user_event(&mut self, _event_loop: &ActiveEventLoop, _event: Wake) {
+        // One wake-up for everything sent since the last poll.
+        loop {
+            match self.flower.poll() {
+                FlowerPoll::Idle => break,
+                FlowerPoll::Channel(percent) => self.percent = percent,
+                FlowerPoll::Done(_) => self.done = true,
+            }
+        }
+        if let Some(surface) = &self.surface {
+            surface.window.request_redraw();
+        }
+    }
+
+    fn window_event(&mut self, event_loop: &ActiveEventLoop, _id: WindowId, event: WindowEvent) {
+        match event {
+            WindowEvent::CloseRequested => {
+                self.flower.cancel();
+                event_loop.exit();
+            }
+            WindowEvent::RedrawRequested => self.redraw(),
+            _ => (),
+        }
+    }
+}
+
+fn main() -> Result<(), winit::error::EventLoopError> {
+    let event_loop = EventLoop::<Wake>::with_user_event().build()?;
+    let flower: Flower<u32, ()> = Flower::new(1);
+    // Before the worker starts, so its first value wakes the loop.
+    flower.notify_event_loop(event_loop.create_proxy(), Wake);
+    let handle = flower.handle();
+    handle.activate();
+    thread::spawn(move || {
+        for percent in 1..=100 {
+            thread::sleep(Duration::from_millis(30));
+            if handle.send(percent).is_err() {
+                return;
+            }
+        }
+        handle.ok(());
+    });
+
+    let mut app = App {
+        flower,
+        percent: 0,
+        done: false,
+        surface: None,
+    };
+    event_loop.run_app(&mut app)
+}
@@ -0,0 +1,427 @@
+#![deny(unsafe_code)]
+use core::{
+    fmt::{self, Debug, Formatter},
+    future::poll_fn,
+    sync::atomic::{AtomicBool, AtomicUsize, Ordering},
+    task::{Poll, Waker},
+};
+use std::{sync::Arc, thread};
+
+use crate::{flower::waker_key, sync::Mutex};
+
+struct Channel<SOME, OK, ERR> {
+    value: Option<SOME>,
+    result: Option<Result<OK, ERR>>,
+    // The result was set since the last `handle`, even if already taken.
+    done: bool,
+    // Number of values sent and consumed so far.
+    sent: u64,
+    received: u64,
+    // One waker per suspended `send_async`, keyed by `waker_key`.
+    sender_wakers: Vec<(usize, Waker)>,
+    consumer_waker: Option<Waker>,
+    closed: bool,
+}
+
+impl<SOME, OK, ERR> Channel<SOME, OK, ERR> {
+    fn wake_senders(&mut self) {
+        for (_, waker) in self.sender_wakers.drain(..) {
+            waker.wake();
+        }
+    }
+
+    fn wake_consumer(&mut self) {
+        if let Some(waker) = self.consumer_waker.take() {
+            waker.wake();
+        }
+    }
+
+    // Replace the waker registered with `key`, unless it already wakes the same task.
+    fn register_sender(&mut self, key: usize, waker: &Waker) {
+        match self
+            .sender_wakers
+            .iter_mut()
+            .find(|(registered, _)| *registered == key)
+        {
+            Some((_, registered)) => {
+                if !registered.will_wake(waker) {
+                    *registered = waker.clone();
+                }
+            }
+            None => self.sender_wakers.push((key, waker.clone())),
+        }
+    }
+
+    fn unregister_sender(&mut self, key: usize) {
+        self.sender_wakers
+            .retain(|(registered, _)| *registered != key);
+    }
+}
+
+// Forgets the waker of a `send_async` future once it's done or dropped.
+struct SenderKey<'a, SOME, OK, ERR> {
+    channel: &'a Mutex<Channel<SOME, OK, ERR>>,
+    key: usize,
+}
+
+impl<SOME, OK, ERR> Drop for SenderKey<'_, SOME, OK, ERR> {
+    fn drop(&mut self) {
+        self.channel.lock().unregister_sender(self.key);
+    }
+}
+
+struct AsyncFlowerState<SOME, OK, ERR> {
+    // Flags are stored with `Release` and loaded with `Acquire`, same as `FlowerState`.
+    activated: AtomicBool,
+    canceled: AtomicBool,
+    handles: AtomicUsize,
+    channel: Mutex<Channel<SOME, OK, ERR>>,
+    err_from_string: fn(String) -> ERR,
+}
+
+/// A fully asynchronous flower, both the channel and the result are waker based.
+///
+/// `AsyncFlowerHandle::send_async` suspends until the value is consumed by `next` or `result`,
+/// nothing ever blocks a thread, which suits pure async services without a frame loop.
+///
+/// # Example
+///
+/// ```
+/// use flowync::AsyncFlower;
+///
+/// #[tokio::main]
+/// async fn main() {
+///     let flower: AsyncFlower<u32, String> = AsyncFlower::new(1);
+///     let handle = flower.handle();
+///     handle.activate();
+///     tokio::spawn(async move {
+///         for i in 0..3 {
+///             handle.send_async(i).await;
+///         }
+///         handle.ok("done".into());
+///     });
+///
+///     while let Some(value) = flower.next().await {
+///         println!("{}", value);
+///     }
+///     assert_eq!(flower.result().await, Ok("done".to_string()));
+/// }
+/// ```
+pub struct AsyncFlower<SOME, OK, ERR = String>
+where
+    SOME: Send,
+    OK: Send,
+    ERR: Send,
+{
+    state: Arc<AsyncFlowerState<SOME, OK, ERR>>,
+    id: usize,
+}
+
+impl<SOME, OK, ERR> AsyncFlower<SOME, OK, ERR>
+where
+    SOME: Send,
+    OK: Send,
+    ERR: Send + From<String>,
+{
+    pub fn new(id: usize) -> Self {
+        Self {
+            state: Arc::new(AsyncFlowerState {
+                activated: AtomicBool::new(false),
+                canceled: AtomicBool::new(false),
+                handles: AtomicUsize::new(0),
+                channel: Mutex::new(Channel {
+                    value: None,
+                    result: None,
+                    done: false,
+                    sent: 0,
+                    received: 0,
+                    sender_wakers: Vec::new(),
+                    consumer_waker: None,
+                    closed: false,
+                }),
+                err_from_string: From::from,
+            }),
+            id,
+        }
+    }
+}
+
+impl<SOME, OK, ERR> AsyncFlower<SOME, OK, ERR>
+where
+    SOME: Send,
+    OK: Send,
+    ERR: Send,
+{
+    /// Get ID of the flower.
+    pub fn id(&self) -> usize {
+        self.id
+    }
+
+    /// Get handle of the flower.
+    ///
+    /// A canceled flower stays canceled, see `reset_cancel`.
+    pub fn handle(&self) -> AsyncFlowerHandle<SOME, OK, ERR> {
+        self.state.channel.lock().done = false;
+        self.state.handles.fetch_add(1, Ordering::Relaxed);
+        AsyncFlowerHandle {
+            state: Clone::clone(&self.state),
+            id: self.id,
+        }
+    }
+
+    /// Cancel the flower, the worker can check it with `AsyncFlowerHandle::should_cancel`.
+    ///
+    /// Suspended `send_async` calls return, their values are taken back, and `next`
+    /// or `result` are woken.
+    pub fn cancel(&self) {
+        self.state.canceled.store(true, Ordering::Release);
+        let mut channel = self.state.channel.lock();
+        channel.wake_senders();
+        channel.wake_consumer();
+    }
+
+    /// Clear the cancelation so the flower can be run again.
+    pub fn reset_cancel(&self) {
+        self.state.canceled.store(false, Ordering::Release);
+    }
+
+    /// Check if the flower is canceled
+    pub fn is_canceled(&self) -> bool {
+        self.state.canceled.load(Ordering::Acquire)
+    }
+
+    /// Check if the flower is active
+    pub fn is_active(&self) -> bool {
+        self.state.activated.load(Ordering::Acquire)
+    }
+
+    /// Get the next channel value, `None` once the result is ready.
+    ///
+    /// The result is left in place for `result`.
+    pub async fn next(&self) -> Option<SOME> {
+        poll_fn(|cx| {
+            let mut channel = self.state.channel.lock();
+            if let Some(value) = channel.value.take() {
+                channel.received += 1;
+                channel.wake_senders();
+                Poll::Ready(Some(value))
+            } else if channel.result.is_some() {
+                Poll::Ready(None)
+            } else {
+                channel.consumer_waker = Some(cx.waker().clone());
+                Poll::Pending
+            }
+        })
+        .await
+    }
+
+    /// Get the result, channel values sent in the meantime are discarded.
+    pub async fn result(&self) -> Result<OK, ERR> {
+        poll_fn(|cx| {
+            let mut channel = self.state.channel.lock();
+            if channel.value.take().is_some() {
+                channel.received += 1;
+                channel.wake_senders();
+            }
+            match channel.result.take() {
+                Some(result) => {
+                    self.state.activated.store(false, Ordering::Release);
+                    Poll::Ready(result)
+                }
+                None => {
+                    channel.consumer_waker = Some(cx.waker().clone());
+                    Poll::Pending
+                }
+            }
+        })
+        .await
+    }
+}
+
+impl<SOME, OK, ERR> Drop for AsyncFlower<SOME, OK, ERR>
+where
+    SOME: Send,
+    OK: Send,
+    ERR: Send,
+{
+    fn drop(&mut self) {
+        // Nobody is left to consume, release the suspended senders.
+        let mut channel = self.state.channel.lock();
+        channel.closed = true;
+        channel.wake_senders();
+    }
+}
+
+impl<SOME, OK, ERR> Debug for AsyncFlower<SOME, OK, ERR>
+where
+    SOME: Send,
+    OK: Send,
+    ERR: Send,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("AsyncFlower")
+            .field("id", &self.id)
+            .field("activated", &self.state.activated)
+            .field("canceled", &self.state.canceled)
+            .finish()
+    }
+}
+
+/// A handle of the `AsyncFlower`, used from the async worker side.
+pub struct AsyncFlowerHandle<SOME, OK, ERR = String>
+where
+    SOME: Send,
+    OK: Send,
+    ERR: Send,
+{
+    state: Arc<AsyncFlowerState<SOME, OK, ERR>>,
+    id: usize,
+}
+
+impl<SOME, OK, ERR> AsyncFlowerHandle<SOME, OK, ERR>
+where
+    SOME: Send,
+    OK: Send,
+    ERR: Send,
+{
+    /// Get ID of the flower.
+    pub fn id(&self) -> usize {
+        self.id
+    }
+
+    /// Activate the flower
+    pub fn activate(&self) {
+        self.state.activated.store(true, Ordering::Release);
+    }
+
+    /// Check if the flower is active
+    pub fn is_active(&self) -> bool {
+        self.state.activated.load(Ordering::Acquire)
+    }
+
+    /// Check if the flower should be canceled
+    pub fn should_cancel(&self) -> bool {
+        self.state.canceled.load(Ordering::Acquire)
+    }
+
+    /// Send current progress value, suspends until the value is consumed.
+    ///
+    /// Returns immediately if the flower is dropped or canceled, a value which
+    /// wasn't consumed yet is taken back on cancelation.
+    pub async fn send_async(&self, value: SOME) {
+        let mut value = Some(value);
+        let mut ticket = None;
+        let sender = SenderKey {
+            channel: &self.state.channel,
+            key: waker_key(),
+        };
+        poll_fn(|cx| {
+            let mut channel = sender.channel.lock();
+            if channel.closed {
+                return Poll::Ready(());
+            }
+            if self.should_cancel() {
+                if ticket.is_some_and(|ticket| channel.received < ticket) {
+                    // Still in the slot, the last one sent.
+                    channel.value = None;
+                    channel.sent -= 1;
+                    channel.wake_senders();
+                }
+                return Poll::Ready(());
+            }
+            match ticket {
+                None if channel.value.is_some() => {
+                    // Another handle's value is pending.
+                    channel.register_sender(sender.key, cx.waker());
+                    return Poll::Pending;
+                }
+                None => {
+                    channel.value = value.take();
+                    channel.sent += 1;
+                    ticket = Some(channel.sent);
+                    channel.wake_consumer();
+                }
+                Some(_) => (),
+            }
+            if ticket.is_some_and(|ticket| channel.received >= ticket) {
+                Poll::Ready(())
+            } else {
+                channel.register_sender(sender.key, cx.waker());
+                Poll::Pending
+            }
+        })
+        .await
+    }
+
+    fn set_result(&self, result: Result<OK, ERR>) {
+        let mut channel = self.state.channel.lock();
+        channel.result = Some(result);
+        channel.done = true;
+        channel.wake_consumer();
+    }
+
+    /// Contains the success value for the result.
+    pub fn ok(&self, value: OK) {
+        self.set_result(Ok(value));
+    }
+
+    /// Contains the error value for the result.
+    pub fn err(&self, value: impl Into<ERR>) {
+        self.set_result(Err(value.into()));
+    }
+}
+
+impl<SOME, OK, ERR> Clone for AsyncFlowerHandle<SOME, OK, ERR>
+where
+    SOME: Send,
+    OK: Send,
+    ERR: Send,
+{
+    fn clone(&self) -> Self {
+        self.state.handles.fetch_add(1, Ordering::Relaxed);
+        Self {
+            state: Clone::clone(&self.state),
+            id: self.id,
+        }
+    }
+}
+
+impl<SOME, OK, ERR> Drop for AsyncFlowerHandle<SOME, OK, ERR>
+where
+    SOME: Send,
+    OK: Send,
+    ERR: Send,
+{
+    fn drop(&mut self) {
+        let last = self.state.handles.fetch_sub(1, Ordering::AcqRel) == 1;
+        if self.state.channel.lock().done {
+            return;
+        }
+        if thread::panicking() {
+            self.err((self.state.err_from_string)(format!(
+                "the flower handle with id: {} error, the thread panicked maybe?",
+                self.id
+            )));
+        } else if last && self.state.activated.load(Ordering::Acquire) {
+            self.err((self.state.err_from_string)(format!(
+                "flower handle(s) dropped without result, id: {}",
+                self.id
+            )));
+        }
+    }
+}
+
+impl<SOME, OK, ERR> Debug for AsyncFlowerHandle<SOME, OK, ERR>
+where
+    SOME: Send,
+    OK: Send,
+    ERR: Send,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("AsyncFlowerHandle")
+            .field("id", &self.id)
+            .field("activated", &self.state.activated)
+            .field("canceled", &self.state.canceled)
+            .finish()
+    }
+}
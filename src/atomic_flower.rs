@@ -0,0 +1,278 @@
+#![deny(unsafe_code)]
+use crate::{Flower, FlowerHandle, FlowerLifecycle, FlowerPoll, ResetWhileActive};
+use core::fmt::{self, Debug, Formatter};
+use crossbeam_utils::atomic::AtomicCell;
+use std::sync::Arc;
+
+/// A flower for `Copy` progress values whose channel is a single atomic slot,
+/// the handle stores the latest value and the consumer takes it without locking.
+///
+/// Activation, cancelation and the result go through a regular [`Flower`],
+/// see [`AtomicFlower::inner`]. The slot is lock-free if
+/// `AtomicCell::<Option<SOME>>::is_lock_free()`, e.g. for `u32`, `NonZeroU64` or small enums,
+/// bigger values use the striped sequence locks of `crossbeam-utils`.
+/// Nothing waits on the slot, poll the flower from a frame loop.
+///
+/// # Quick Example:
+///
+///```
+///use flowync::AtomicFlower;
+///
+///let flower: AtomicFlower<u32, String> = AtomicFlower::new(1);
+///std::thread::spawn({
+///    let handle = flower.handle();
+///    handle.activate();
+///    move || {
+///        for i in 1..=100 {
+///            handle.send_latest(i);
+///        }
+///        handle.ok("done".into());
+///    }
+///});
+///
+///let mut last = 0;
+///let mut exit = false;
+///while !exit {
+///    flower.then(
+///        |value| if let Some(i) = value { assert!(i > last); last = i },
+///        |result| { assert_eq!(result.unwrap(), "done"); exit = true },
+///    );
+///}
+///assert_eq!(last, 100);
+///```
+pub struct AtomicFlower<SOME, OK, ERR = String, ID = usize>
+where
+    SOME: Copy + Send,
+    OK: Send,
+    ERR: Send,
+    ID: Send + Sync,
+{
+    flower: Flower<(), OK, ERR, ID>,
+    slot: Arc<AtomicCell<Option<SOME>>>,
+}
+
+/// The worker side of an [`AtomicFlower`].
+pub struct AtomicFlowerHandle<SOME, OK, ERR = String, ID = usize>
+where
+    SOME: Copy + Send,
+    OK: Send,
+    ERR: Send,
+    ID: Send + Sync,
+{
+    handle: FlowerHandle<(), OK, ERR, ID>,
+    slot: Arc<AtomicCell<Option<SOME>>>,
+}
+
+impl<SOME, OK, ERR, ID> AtomicFlower<SOME, OK, ERR, ID>
+where
+    SOME: Copy + Send,
+    OK: Send,
+    ERR: Send + From<String>,
+    ID: Clone + Debug + Send + Sync,
+{
+    pub fn new(id: ID) -> Self {
+        Self {
+            flower: Flower::new(id),
+            slot: Arc::new(AtomicCell::new(None)),
+        }
+    }
+}
+
+impl<SOME, OK, ERR, ID> AtomicFlower<SOME, OK, ERR, ID>
+where
+    SOME: Copy + Send,
+    OK: Send,
+    ERR: Send,
+    ID: Send + Sync,
+{
+    /// The flower behind the activation, cancelation and the result.
+    pub fn inner(&self) -> &Flower<(), OK, ERR, ID> {
+        &self.flower
+    }
+
+    pub fn id(&self) -> &ID {
+        self.flower.id()
+    }
+
+    /// Get handle of the flower, see `Flower::handle`.
+    pub fn handle(&self) -> AtomicFlowerHandle<SOME, OK, ERR, ID> {
+        AtomicFlowerHandle {
+            handle: self.flower.handle(),
+            slot: self.slot.clone(),
+        }
+    }
+
+    /// Cancel the current flower, see `Flower::cancel`.
+    pub fn cancel(&self) {
+        self.flower.cancel()
+    }
+
+    pub fn is_canceled(&self) -> bool {
+        self.flower.is_canceled()
+    }
+
+    pub fn is_active(&self) -> bool {
+        self.flower.is_active()
+    }
+
+    pub fn result_is_ready(&self) -> bool {
+        self.flower.result_is_ready()
+    }
+
+    /// Check if a value is waiting in the slot.
+    pub fn channel_is_present(&self) -> bool {
+        self.slot.load().is_some()
+    }
+
+    pub fn state(&self) -> FlowerLifecycle {
+        self.flower.state()
+    }
+
+    /// Take the value in the slot, if any.
+    pub fn latest(&self) -> Option<SOME> {
+        self.slot.take()
+    }
+
+    /// Reset the flower for a new run and empty the slot, see `Flower::reset`.
+    pub fn reset(&self) -> Result<(), ResetWhileActive> {
+        self.flower.reset()?;
+        self.slot.store(None);
+        Ok(())
+    }
+
+    /// Process the flower, same as `Flower::then`.
+    pub fn then(&self, c: impl FnOnce(Option<SOME>), r: impl FnOnce(Result<OK, ERR>)) {
+        match self.poll() {
+            FlowerPoll::Idle => c(None),
+            FlowerPoll::Channel(value) => c(Some(value)),
+            FlowerPoll::Done(result) => r(result),
+        }
+    }
+
+    /// Poll the flower once, the value in the slot comes before the result.
+    ///
+    /// Only locks once the result is ready.
+    pub fn poll(&self) -> FlowerPoll<SOME, OK, ERR> {
+        // Checked first, a value stored before the result can't be missed.
+        let done = self.flower.result_is_ready();
+        if let Some(value) = self.slot.take() {
+            return FlowerPoll::Channel(value);
+        }
+        if !done {
+            return FlowerPoll::Idle;
+        }
+        match self.flower.poll() {
+            FlowerPoll::Done(result) => FlowerPoll::Done(result),
+            _ => FlowerPoll::Idle,
+        }
+    }
+
+    /// Try to get the result, the value in the slot is discarded, see `Flower::try_result`.
+    pub fn try_result(&self) -> Option<Result<OK, ERR>> {
+        let result = self.flower.try_result()?;
+        self.slot.store(None);
+        Some(result)
+    }
+}
+
+impl<SOME, OK, ERR, ID> AtomicFlowerHandle<SOME, OK, ERR, ID>
+where
+    SOME: Copy + Send,
+    OK: Send,
+    ERR: Send,
+    ID: Send + Sync,
+{
+    /// The handle of the inner flower, e.g. for `checkpoint` or `set_on_cancel`.
+    pub fn inner(&self) -> &FlowerHandle<(), OK, ERR, ID> {
+        &self.handle
+    }
+
+    pub fn id(&self) -> &ID {
+        self.handle.id()
+    }
+
+    /// Activate the flower, see `FlowerHandle::activate`.
+    pub fn activate(&self) {
+        self.handle.activate()
+    }
+
+    pub fn is_active(&self) -> bool {
+        self.handle.is_active()
+    }
+
+    /// Check if the current flower should be canceled, see `FlowerHandle::should_cancel`.
+    pub fn should_cancel(&self) -> bool {
+        self.handle.should_cancel()
+    }
+
+    /// Store the value in the slot overwriting any value which isn't polled yet,
+    /// a single atomic store which never blocks.
+    ///
+    /// Ignored if the handle is stale. Unlike `FlowerHandle::send_latest` it doesn't count
+    /// as activity for `is_stalled` nor wake up anything.
+    pub fn send_latest(&self, value: SOME) {
+        if !self.handle.is_stale() {
+            self.slot.store(Some(value));
+        }
+    }
+
+    /// Set the current progress, see `FlowerHandle::set_progress`.
+    pub fn set_progress(&self, p: f32) {
+        self.handle.set_progress(p)
+    }
+
+    /// Contains the success value for the result.
+    pub fn ok(&self, value: OK) {
+        self.handle.ok(value)
+    }
+
+    /// Contains the error value for the result.
+    pub fn err(&self, value: impl Into<ERR>) {
+        self.handle.err(value)
+    }
+}
+
+impl<SOME, OK, ERR, ID> Clone for AtomicFlowerHandle<SOME, OK, ERR, ID>
+where
+    SOME: Copy + Send,
+    OK: Send,
+    ERR: Send,
+    ID: Send + Sync,
+{
+    fn clone(&self) -> Self {
+        Self {
+            handle: self.handle.clone(),
+            slot: self.slot.clone(),
+        }
+    }
+}
+
+impl<SOME, OK, ERR, ID> Debug for AtomicFlower<SOME, OK, ERR, ID>
+where
+    SOME: Copy + Debug + Send,
+    OK: Debug + Send,
+    ERR: Debug + Send,
+    ID: Debug + Send + Sync,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("AtomicFlower")
+            .field("flower", &self.flower)
+            .field("slot", &self.slot.load())
+            .finish()
+    }
+}
+
+impl<SOME, OK, ERR, ID> Debug for AtomicFlowerHandle<SOME, OK, ERR, ID>
+where
+    SOME: Copy + Debug + Send,
+    OK: Debug + Send,
+    ERR: Debug + Send,
+    ID: Debug + Send + Sync,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("AtomicFlowerHandle")
+            .field("handle", &self.handle)
+            .field("slot", &self.slot.load())
+            .finish()
+    }
+}
@@ -0,0 +1,189 @@
+use crate::Flower;
+use core::fmt::Debug;
+use std::{borrow::Cow, time::Duration};
+
+/// What a full buffered channel does with a newly sent value.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Backpressure {
+    /// Wait until the flower polls a value and frees a slot (default).
+    #[default]
+    Block,
+    /// Evict the oldest queued value to make room, never waits.
+    DropOldest,
+    /// Discard the newly sent value, never waits.
+    DropNewest,
+}
+
+/// What happens when a worker panics, see [`FlowerBuilder::panic_policy`].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PanicPolicy {
+    /// Deliver an error result with the panic message (default).
+    #[default]
+    Report,
+    /// Resume the panic on the consumer thread the next time it polls the flower,
+    /// e.g. in `then`, `poll` or `wait`, so tests fail loudly.
+    Propagate,
+    /// Deactivate the flower without a result.
+    Ignore,
+}
+
+/// Configure how a flower should be constructed.
+///
+/// # Quick Example:
+///
+///```
+///use flowync::{Backpressure, Flower, FlowerBuilder};
+///
+///let flower: Flower<u32, String> = FlowerBuilder::new(1)
+///    .capacity(16)
+///    .backpressure(Backpressure::DropOldest)
+///    .build();
+///```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct FlowerBuilder<ID = usize> {
+    pub(crate) id: ID,
+    pub(crate) buffered: bool,
+    pub(crate) capacity: Option<usize>,
+    pub(crate) backpressure: Backpressure,
+    pub(crate) cancel_on_drop: bool,
+    pub(crate) deadline: Option<Duration>,
+    pub(crate) hard_deadline: bool,
+    pub(crate) label: Option<Cow<'static, str>>,
+    pub(crate) panic_policy: PanicPolicy,
+}
+
+impl<ID> FlowerBuilder<ID> {
+    /// Start with the defaults of `Flower::new`, the builder is plain data
+    /// and can be kept around e.g. in a config struct to build more flowers.
+    pub fn new(id: ID) -> Self {
+        Self {
+            id,
+            buffered: false,
+            capacity: None,
+            backpressure: Backpressure::default(),
+            cancel_on_drop: false,
+            deadline: None,
+            hard_deadline: false,
+            label: None,
+            panic_policy: PanicPolicy::default(),
+        }
+    }
+
+    /// Queue channel values instead of waiting for each one to be polled.
+    pub fn buffered(mut self, buffered: bool) -> Self {
+        self.buffered = buffered;
+        self
+    }
+
+    /// Bound the buffered channel to `n` queued values, implies `buffered(true)`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `n` is zero.
+    pub fn capacity(mut self, n: usize) -> Self {
+        assert!(n > 0, "flower channel capacity must be greater than zero");
+        self.buffered = true;
+        self.capacity = Some(n);
+        self
+    }
+
+    /// Set what a full bounded channel does with a newly sent value.
+    pub fn backpressure(mut self, backpressure: Backpressure) -> Self {
+        self.backpressure = backpressure;
+        self
+    }
+
+    /// Cancel the flower once its last clone is dropped, see `Flower::cancel_on_drop`.
+    pub fn cancel_on_drop(mut self, cancel_on_drop: bool) -> Self {
+        self.cancel_on_drop = cancel_on_drop;
+        self
+    }
+
+    /// Set a deadline measured from each activation of the flower,
+    /// `FlowerHandle::should_cancel` returns true once it passed.
+    pub fn deadline(mut self, deadline: Duration) -> Self {
+        self.deadline = Some(deadline);
+        self
+    }
+
+    /// Set what happens when a worker panics, in the spawn helpers or holding a handle.
+    pub fn panic_policy(mut self, panic_policy: PanicPolicy) -> Self {
+        self.panic_policy = panic_policy;
+        self
+    }
+
+    /// Set a label for the flower, shown along with the id in the `Debug` and `Display` output
+    /// and in the errors of dropped handles, see `Flower::label`.
+    pub fn label(mut self, label: impl Into<Cow<'static, str>>) -> Self {
+        self.label = Some(label.into());
+        self
+    }
+
+    /// Once the deadline passed without a result, `then` and `poll` deliver
+    /// `Err("deadline exceeded")` even if the worker ignores the cancelation.
+    /// The blocking `wait`, `recv`, `iter`, `select_wait`, `join_all` and
+    /// `FlowerPool::collect_results` wake up at the deadline for it.
+    pub fn hard_deadline(mut self, hard_deadline: bool) -> Self {
+        self.hard_deadline = hard_deadline;
+        self
+    }
+
+    /// Build the flower.
+    pub fn build<SOME, OK, ERR>(&self) -> Flower<SOME, OK, ERR, ID>
+    where
+        SOME: Send,
+        OK: Send,
+        ERR: Send + From<String>,
+        ID: Clone + Debug + Send + Sync,
+    {
+        Flower::from_builder(self, From::from)
+    }
+
+    /// Build a flower whose error type has no `From<String>`, the errors the flower makes
+    /// itself (dropped handles, panics, `cancel_ack`, the hard deadline...) come from
+    /// `err_from_string` and their message.
+    ///
+    ///```
+    ///use flowync::{Flower, FlowerBuilder};
+    ///
+    ///#[derive(Debug, PartialEq)]
+    ///enum MyError {
+    ///    Timeout,
+    ///    Internal(String),
+    ///}
+    ///
+    ///let flower: Flower<(), (), MyError> = FlowerBuilder::new(1).build_with_err(MyError::Internal);
+    ///let handle = flower.handle();
+    ///handle.activate();
+    ///handle.err(MyError::Timeout);
+    ///assert_eq!(flower.wait(), Err(MyError::Timeout));
+    ///```
+    pub fn build_with_err<SOME, OK, ERR>(
+        &self,
+        err_from_string: fn(String) -> ERR,
+    ) -> Flower<SOME, OK, ERR, ID>
+    where
+        SOME: Send,
+        OK: Send,
+        ERR: Send,
+        ID: Clone + Debug + Send + Sync,
+    {
+        Flower::from_builder(self, err_from_string)
+    }
+
+    /// Build a broadcast flower, every clone of the flower is a consumer with its own cursor
+    /// and observes each channel value and the result, e.g. with `then` or `poll`.
+    ///
+    /// A sender is released once all the consumers took its value, a clone only reads
+    /// the values sent after it was created. The result isn't taken, every clone observes it once
+    /// and the flower is deactivated when all of them did.
+    pub fn build_broadcast<SOME, OK, ERR>(&self) -> Flower<SOME, OK, ERR, ID>
+    where
+        SOME: Send + Clone,
+        OK: Send + Clone,
+        ERR: Send + Clone + From<String>,
+        ID: Clone + Debug + Send + Sync,
+    {
+        Flower::from_broadcast_builder(self)
+    }
+}
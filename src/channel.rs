@@ -0,0 +1,162 @@
+use std::collections::VecDeque;
+
+#[derive(Debug)]
+struct Entry<SOME> {
+    ticket: u64,
+    // Given by `send_tagged`, 0 for the other sends.
+    tag: u32,
+    value: SOME,
+    // Consumers which still have to read the value, always 1 unless broadcast.
+    readers: usize,
+}
+
+/// The channel values of a flower in FIFO order.
+///
+/// Each value is tagged with a ticket, so its sender can tell when that value
+/// in particular is gone even with several senders waiting at once.
+#[derive(Debug)]
+pub(crate) struct Channel<SOME> {
+    queue: VecDeque<Entry<SOME>>,
+    next_ticket: u64,
+    readers: usize,
+}
+
+impl<SOME> Channel<SOME> {
+    pub(crate) fn new() -> Self {
+        Self {
+            queue: VecDeque::new(),
+            next_ticket: 0,
+            readers: 1,
+        }
+    }
+
+    /// Push the value, returns its ticket.
+    pub(crate) fn push_back(&mut self, value: SOME) -> u64 {
+        self.push_tagged(0, value)
+    }
+
+    /// Push the value along with its tag, returns its ticket.
+    pub(crate) fn push_tagged(&mut self, tag: u32, value: SOME) -> u64 {
+        let ticket = self.next_ticket;
+        self.next_ticket = self.next_ticket.wrapping_add(1);
+        self.queue.push_back(Entry {
+            ticket,
+            tag,
+            value,
+            readers: self.readers,
+        });
+        ticket
+    }
+
+    pub(crate) fn pop_front(&mut self) -> Option<SOME> {
+        self.queue.pop_front().map(|entry| entry.value)
+    }
+
+    pub(crate) fn pop_front_tagged(&mut self) -> Option<(u32, SOME)> {
+        self.queue.pop_front().map(|entry| (entry.tag, entry.value))
+    }
+
+    pub(crate) fn pop_back(&mut self) -> Option<SOME> {
+        self.queue.pop_back().map(|entry| entry.value)
+    }
+
+    /// Check if the value of the ticket is still queued.
+    pub(crate) fn contains(&self, ticket: u64) -> bool {
+        self.queue.iter().any(|entry| entry.ticket == ticket)
+    }
+
+    /// Remove the value of the ticket if it's still queued.
+    pub(crate) fn remove(&mut self, ticket: u64) -> Option<SOME> {
+        let i = self.queue.iter().position(|entry| entry.ticket == ticket)?;
+        self.queue.remove(i).map(|entry| entry.value)
+    }
+
+    /// Take all the values out, tickets keep counting so they're never confused.
+    pub(crate) fn take(&mut self) -> Vec<SOME> {
+        self.queue.drain(..).map(|entry| entry.value).collect()
+    }
+
+    pub(crate) fn clear(&mut self) {
+        self.queue.clear();
+    }
+
+    pub(crate) fn len(&self) -> usize {
+        self.queue.len()
+    }
+
+    pub(crate) fn is_empty(&self) -> bool {
+        self.queue.is_empty()
+    }
+
+    /// Register a broadcast consumer, returns its cursor.
+    ///
+    /// Only values pushed from now on are read by the new consumer.
+    pub(crate) fn subscribe(&mut self) -> u64 {
+        self.readers += 1;
+        self.next_ticket
+    }
+
+    /// Unregister a broadcast consumer, the values it didn't read yet are released.
+    ///
+    /// Returns true if any value was removed.
+    pub(crate) fn unsubscribe(&mut self, cursor: u64) -> bool {
+        self.readers -= 1;
+        let len = self.queue.len();
+        for entry in self.queue.iter_mut().filter(|entry| entry.ticket >= cursor) {
+            entry.readers -= 1;
+        }
+        self.queue.retain(|entry| entry.readers > 0);
+        self.queue.len() != len
+    }
+
+    /// Move the cursor of a broadcast consumer past every queued value, the values
+    /// are removed once every consumer read or skipped them.
+    ///
+    /// Returns true if any value was removed.
+    pub(crate) fn skip(&mut self, cursor: &mut u64) -> bool {
+        let len = self.queue.len();
+        for entry in self
+            .queue
+            .iter_mut()
+            .filter(|entry| entry.ticket >= *cursor)
+        {
+            entry.readers -= 1;
+        }
+        self.queue.retain(|entry| entry.readers > 0);
+        *cursor = self.next_ticket;
+        self.queue.len() != len
+    }
+
+    /// Get the next value from the cursor without reading it, 0 for the front value.
+    pub(crate) fn peek(&self, cursor: u64) -> Option<&SOME> {
+        self.queue
+            .iter()
+            .find(|entry| entry.ticket >= cursor)
+            .map(|entry| &entry.value)
+    }
+
+    /// Read the next value after the cursor of a broadcast consumer,
+    /// the value is removed once every consumer read it.
+    ///
+    /// Returns the value with its tag and whether it was removed.
+    pub(crate) fn read(
+        &mut self,
+        cursor: &mut u64,
+        clone: fn(&SOME) -> SOME,
+    ) -> Option<((u32, SOME), bool)> {
+        let i = self
+            .queue
+            .iter()
+            .position(|entry| entry.ticket >= *cursor)?;
+        let entry = &mut self.queue[i];
+        *cursor = entry.ticket.wrapping_add(1);
+        entry.readers -= 1;
+        if entry.readers == 0 {
+            self.queue
+                .remove(i)
+                .map(|entry| ((entry.tag, entry.value), true))
+        } else {
+            Some(((entry.tag, clone(&entry.value)), false))
+        }
+    }
+}
@@ -0,0 +1,26 @@
+use crate::error::Failed;
+use core::fmt::Display;
+
+/// Fail a job with some context in one go, for the `map_err` of a worker.
+///
+/// # Quick Example:
+///
+///```
+///use flowync::{ContextExt, Failed, Flower, FlowerHandle};
+///
+///fn sync_playlist(handle: &FlowerHandle<u32, String>) -> Result<(), Failed> {
+///    let n: u32 = "4x".parse().map_err(|e| handle.fail_ctx("while syncing playlist 42", e))?;
+///    handle.ok(format!("{} songs", n));
+///    Ok(())
+///}
+///
+///let flower: Flower<u32, String> = Flower::spawn(1, |handle| sync_playlist(&handle).unwrap_or(()));
+///assert_eq!(
+///    flower.wait(),
+///    Err("while syncing playlist 42: invalid digit found in string".to_string())
+///);
+///```
+pub trait ContextExt {
+    /// Set the result to the error "context: err", returns the `Failed` marker.
+    fn fail_ctx(&self, context: impl Display, err: impl Display) -> Failed;
+}
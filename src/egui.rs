@@ -0,0 +1,62 @@
+//! Repaint an [`egui`](https://docs.rs/egui) UI when a flower has news, and show its progress.
+use crate::Flower;
+use ::egui::{Context, ProgressBar, Response, Ui};
+use core::time::Duration;
+
+// How often `show_progress` repaints while the job runs, `set_progress` doesn't notify.
+const PROGRESS_REPAINT: Duration = Duration::from_millis(50);
+
+impl<SOME, OK, ERR, ID> Flower<SOME, OK, ERR, ID>
+where
+    SOME: Send,
+    OK: Send,
+    ERR: Send,
+    ID: Send + Sync,
+{
+    /// Request a repaint of the egui context on every `send`, `ok` and `err`, so the values
+    /// show up without waiting for the next input event.
+    ///
+    /// Installed as the notifier of the flower, see `Flower::set_notifier`.
+    pub fn attach_egui_ctx(&self, ctx: Context) {
+        self.set_notifier(move || ctx.request_repaint());
+    }
+}
+
+/// Show a progress bar with the value of `Flower::progress`, animated while
+/// the worker didn't report any progress yet.
+///
+/// Repaints regularly while the job runs, the progress set by the worker isn't notified.
+///
+/// # Quick Example:
+///
+///```
+///use flowync::{egui::show_progress, Flower};
+///
+///let flower: Flower<(), ()> = Flower::new(1);
+///let handle = flower.handle();
+///handle.activate();
+///handle.set_progress(0.25);
+///
+///let ctx = ::egui::Context::default();
+///let mut output = ctx.run_ui(Default::default(), |ui| {
+///    show_progress(ui, &flower);
+///});
+///output.textures_delta.clear();
+///```
+pub fn show_progress<SOME, OK, ERR, ID>(ui: &mut Ui, flower: &Flower<SOME, OK, ERR, ID>) -> Response
+where
+    SOME: Send,
+    OK: Send,
+    ERR: Send,
+    ID: Send + Sync,
+{
+    let running = flower.is_active() && !flower.result_is_ready();
+    if running {
+        ui.ctx().request_repaint_after(PROGRESS_REPAINT);
+    }
+    let bar = match flower.progress() {
+        Some(progress) => ProgressBar::new(progress).show_percentage(),
+        None => ProgressBar::new(0.0).animate(running),
+    };
+    ui.add(bar)
+}
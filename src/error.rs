@@ -0,0 +1,167 @@
+use core::fmt::{self, Debug, Display, Formatter};
+
+/// An error returned from [`FlowerHandle::send_timeout`](crate::FlowerHandle::send_timeout),
+/// contains the value that could not be delivered in time.
+#[derive(PartialEq, Eq, Clone, Copy)]
+pub struct SendTimeoutError<T>(pub T);
+
+impl<T> SendTimeoutError<T> {
+    /// Get back the undelivered value.
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+}
+
+impl<T> Debug for SendTimeoutError<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SendTimeoutError").finish_non_exhaustive()
+    }
+}
+
+impl<T> Display for SendTimeoutError<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str("timed out waiting for the channel value to be polled")
+    }
+}
+
+impl<T> std::error::Error for SendTimeoutError<T> {}
+
+/// Why [`FlowerHandle::send`](crate::FlowerHandle::send) gave up on a value.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+pub enum SendErrorReason {
+    /// The flower is canceled.
+    Canceled,
+    /// Every clone of the flower is dropped.
+    ConsumerDropped,
+    /// The flower has no channel, see `Flower::result_only`.
+    NoChannel,
+}
+
+/// An error returned from [`FlowerHandle::send`](crate::FlowerHandle::send)
+/// and `send_async`, contains the value that was not delivered.
+#[derive(PartialEq, Eq, Clone, Copy)]
+pub struct SendError<T> {
+    pub(crate) value: T,
+    pub(crate) reason: SendErrorReason,
+}
+
+impl<T> SendError<T> {
+    /// Get back the undelivered value.
+    pub fn into_inner(self) -> T {
+        self.value
+    }
+
+    /// Get the reason the value wasn't delivered.
+    pub fn reason(&self) -> SendErrorReason {
+        self.reason
+    }
+}
+
+impl<T> Debug for SendError<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SendError")
+            .field("reason", &self.reason)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<T> Display for SendError<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self.reason {
+            SendErrorReason::Canceled => f.write_str("the flower is canceled"),
+            SendErrorReason::ConsumerDropped => f.write_str("the flower is dropped"),
+            SendErrorReason::NoChannel => f.write_str("the flower has no channel"),
+        }
+    }
+}
+
+impl<T> std::error::Error for SendError<T> {}
+
+/// An error returned from [`Flower::reset`](crate::Flower::reset)
+/// when the flower is still active and its result isn't ready.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct ResetWhileActive;
+
+impl Display for ResetWhileActive {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str("can't reset the flower while it's still active")
+    }
+}
+
+impl std::error::Error for ResetWhileActive {}
+
+/// An error returned from [`FlowerHandle::try_ok`](crate::FlowerHandle::try_ok) and `try_err`
+/// when a result was already submitted, the first one is kept.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct ResultAlreadySet;
+
+impl Display for ResultAlreadySet {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str("the result of the flower is already set")
+    }
+}
+
+impl std::error::Error for ResultAlreadySet {}
+
+/// Returned from [`ContextExt::fail_ctx`](crate::ContextExt::fail_ctx) once the error result
+/// is set, converts into `()` so the worker can `return` it like `handle.err(..)`.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct Failed;
+
+impl Display for Failed {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str("the job failed, the error is set as the result")
+    }
+}
+
+impl std::error::Error for Failed {}
+
+impl From<Failed> for () {
+    fn from(_: Failed) {}
+}
+
+/// What caused the error of a [`FlowerError`].
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+pub enum ErrorKind {
+    /// Set by the worker with `err` and friends.
+    Custom,
+    /// The worker panicked.
+    Panicked,
+    /// The cancelation was acknowledged with `cancel_ack` or the hard deadline passed.
+    Canceled,
+    /// Every handle was dropped without a result.
+    HandleDropped,
+}
+
+impl ErrorKind {
+    pub(crate) fn from_u8(kind: u8) -> Self {
+        match kind {
+            1 => Self::Panicked,
+            2 => Self::Canceled,
+            3 => Self::HandleDropped,
+            _ => Self::Custom,
+        }
+    }
+}
+
+/// The error of a flower result along with what caused it,
+/// see [`Flower::then_structured`](crate::Flower::then_structured).
+///
+/// `ID` is the id type of the flower, see `FlowerBuilder::new`.
+#[derive(Debug, PartialEq, Eq, Clone, Hash)]
+pub struct FlowerError<ID = usize> {
+    /// ID of the flower.
+    pub id: ID,
+    /// What caused the error.
+    pub kind: ErrorKind,
+    /// The error formatted with `Display`.
+    pub message: String,
+}
+
+impl<ID> Display for FlowerError<ID> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.message)
+    }
+}
+
+impl<ID: Debug> std::error::Error for FlowerError<ID> {}
@@ -0,0 +1,95 @@
+/// A snapshot of what [`Flower::poll`](crate::Flower::poll) found.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FlowerPoll<SOME, OK, ERR = String> {
+    /// Nothing is available yet.
+    Idle,
+    /// A channel value has been received.
+    Channel(SOME),
+    /// The result has been received, the flower is deactivated.
+    Done(Result<OK, ERR>),
+}
+
+/// An event of the flower, a channel value or the final result.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FlowerEvent<SOME, OK, ERR = String> {
+    /// A channel value has been received.
+    Channel(SOME),
+    /// The result has been received, no more events will follow.
+    Done(Result<OK, ERR>),
+}
+
+impl<SOME, OK, ERR> FlowerPoll<SOME, OK, ERR> {
+    /// The event found by the poll, `None` if it was idle.
+    pub fn into_event(self) -> Option<FlowerEvent<SOME, OK, ERR>> {
+        match self {
+            Self::Idle => None,
+            Self::Channel(value) => Some(FlowerEvent::Channel(value)),
+            Self::Done(result) => Some(FlowerEvent::Done(result)),
+        }
+    }
+}
+
+impl<SOME, OK, ERR> FlowerEvent<SOME, OK, ERR> {
+    /// Check if this is the result, no more events follow it.
+    pub fn is_done(&self) -> bool {
+        matches!(self, Self::Done(_))
+    }
+
+    /// Convert the channel value with `f`.
+    pub fn map_channel<T>(self, f: impl FnOnce(SOME) -> T) -> FlowerEvent<T, OK, ERR> {
+        match self {
+            Self::Channel(value) => FlowerEvent::Channel(f(value)),
+            Self::Done(result) => FlowerEvent::Done(result),
+        }
+    }
+
+    /// Convert the success value of the result with `f`.
+    pub fn map_ok<U>(self, f: impl FnOnce(OK) -> U) -> FlowerEvent<SOME, U, ERR> {
+        match self {
+            Self::Channel(value) => FlowerEvent::Channel(value),
+            Self::Done(result) => FlowerEvent::Done(result.map(f)),
+        }
+    }
+}
+
+impl<SOME, OK, ERR> From<Result<OK, ERR>> for FlowerEvent<SOME, OK, ERR> {
+    fn from(result: Result<OK, ERR>) -> Self {
+        Self::Done(result)
+    }
+}
+
+impl<SOME, OK, ERR> From<FlowerEvent<SOME, OK, ERR>> for FlowerPoll<SOME, OK, ERR> {
+    fn from(event: FlowerEvent<SOME, OK, ERR>) -> Self {
+        match event {
+            FlowerEvent::Channel(value) => Self::Channel(value),
+            FlowerEvent::Done(result) => Self::Done(result),
+        }
+    }
+}
+
+/// A consistent snapshot of the flower lifecycle, see [`Flower::state`](crate::Flower::state).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum FlowerLifecycle {
+    /// Not activated, nothing is pending.
+    Idle,
+    /// Activated, waiting for the worker.
+    Running,
+    /// A channel value is waiting to be polled, a sender may be blocked on it.
+    ChannelPending,
+    /// The result is waiting to be polled.
+    ResultReady,
+    /// Canceled, waiting for the worker to acknowledge.
+    Canceled,
+}
+
+impl core::fmt::Display for FlowerLifecycle {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(match self {
+            Self::Idle => "idle",
+            Self::Running => "running",
+            Self::ChannelPending => "channel pending",
+            Self::ResultReady => "result ready",
+            Self::Canceled => "canceled",
+        })
+    }
+}
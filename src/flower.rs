@@ -0,0 +1,3733 @@
+#![deny(unsafe_code)]
+use core::{
+    any::Any,
+    cell::Cell,
+    clone::Clone,
+    fmt::{self, Debug, Display, Formatter},
+    future::{Future, IntoFuture},
+    hash::{Hash, Hasher},
+    pin::Pin,
+    sync::atomic::{AtomicBool, AtomicU32, AtomicU64, AtomicU8, AtomicUsize, Ordering},
+    task::{Context, Poll, Waker},
+};
+use std::{
+    borrow::Cow,
+    error::Error,
+    panic::{self, AssertUnwindSafe},
+    sync::{mpsc, Arc, Weak},
+    thread,
+    time::Duration,
+};
+
+use crate::{
+    channel::Channel,
+    sync::{earliest, CachePadded, Condvar, Instant, Mutex, MutexGuard},
+    Backpressure, ContextExt, ErrorKind, Failed, FlowerBuilder, FlowerError, FlowerEvent,
+    FlowerLifecycle, FlowerPoll, Notifier, PanicPolicy, ResetWhileActive, ResultAlreadySet,
+    SendError, SendErrorReason, SendTimeoutError,
+};
+
+thread_local! {
+    // The state of the flower whose worker runs in `FlowerHandle::catch_panic` on this thread,
+    // its handles leave reporting the panic to it.
+    static CATCHING: Cell<usize> = const { Cell::new(0) };
+}
+
+// Drop the tag of the channel value, most consumers don't use it.
+fn untagged<SOME, OK, ERR>(poll: FlowerPoll<(u32, SOME), OK, ERR>) -> FlowerPoll<SOME, OK, ERR> {
+    match poll {
+        FlowerPoll::Idle => FlowerPoll::Idle,
+        FlowerPoll::Channel((_, value)) => FlowerPoll::Channel(value),
+        FlowerPoll::Done(result) => FlowerPoll::Done(result),
+    }
+}
+
+/// Get the message of a panic payload, "Box<dyn Any>" if it isn't a string like std does.
+pub(crate) fn panic_message(payload: Box<dyn Any + Send>) -> String {
+    match payload.downcast::<String>() {
+        Ok(message) => *message,
+        Err(payload) => match payload.downcast::<&'static str>() {
+            Ok(message) => message.to_string(),
+            Err(_) => "Box<dyn Any>".to_string(),
+        },
+    }
+}
+
+// The id of the next `Flower::new_auto`.
+static NEXT_ID: AtomicUsize = AtomicUsize::new(1);
+
+// The key of the next consumer registering wakers, see `Flower::poll_or_register`.
+static NEXT_WAKER_KEY: AtomicUsize = AtomicUsize::new(0);
+
+pub(crate) fn waker_key() -> usize {
+    NEXT_WAKER_KEY.fetch_add(1, Ordering::Relaxed)
+}
+
+/// Get the id issued by the last `Flower::new_auto`, 0 if none was issued yet.
+pub fn last_issued_id() -> usize {
+    NEXT_ID.load(Ordering::Relaxed).wrapping_sub(1)
+}
+
+/// Bits of the progress value before the first `set_progress`, a NaN which is never stored.
+const NO_PROGRESS: u32 = u32::MAX;
+
+// The channel, guarded by `FlowerState::mtx`.
+type Locked<'a, SOME> = MutexGuard<'a, Channel<SOME>>;
+
+// The success and error values of the result, guarded by `FlowerState::result`.
+type ResultSlot<'a, OK, ERR> = MutexGuard<'a, (Option<OK>, Option<ERR>)>;
+
+pub(crate) type SharedNotifier = Arc<dyn Notifier + Send + Sync>;
+
+// Polled by the consumers every frame and by the workers between items, but only written
+// a few times per run, kept apart from the lines the worker writes on every send.
+struct RunFlags {
+    activated: AtomicBool,
+    result_ready: AtomicBool,
+    canceled: AtomicBool,
+}
+
+// Written by the worker on every send or progress report.
+struct Activity {
+    channel_present: AtomicBool,
+    progress: AtomicU32,
+    // Last time the worker activated, sent something, reported progress or set the result.
+    last_activity: AtomicU64,
+}
+
+struct FlowerState<SOME, OK, ERR, ID>
+where
+    SOME: Send,
+    OK: Send,
+    ERR: Send,
+    ID: Send + Sync,
+{
+    id: ID,
+    // Formats the id for the error messages, so only the constructors need `ID: Debug`.
+    id_string: fn(&ID) -> String,
+    label: Mutex<Option<Cow<'static, str>>>,
+    // `mtx` guards the channel and `result` the result, the flags mirror them so the fast paths
+    // can skip locking. A pending result doesn't contend with the senders, `mtx` is always
+    // taken first when both are locked. Flags are stored with `Release` once the data they announce is written,
+    // or before another thread is expected to act on them, and loaded with `Acquire`.
+    // Counters and stamps carry no data along and stay `Relaxed`, the ones read together
+    // with the channel or the result are only updated under `mtx`.
+    flags: CachePadded<RunFlags>,
+    activity: CachePadded<Activity>,
+    // A result was submitted since the last activation or handle taken, later ones are rejected.
+    result_set: AtomicBool,
+    mtx: Mutex<Channel<SOME>>,
+    result: Mutex<(Option<OK>, Option<ERR>)>,
+    cvar: Condvar,
+    #[cfg(feature = "park")]
+    parker: crate::sync::Parker,
+    consumer_cvar: Condvar,
+    // Wakers of the futures, streams and selects waiting for the flower, by their key.
+    consumer_wakers: Mutex<Vec<(usize, Waker)>>,
+    // Wakers of the async senders waiting for their value to be polled
+    // and whether any is registered.
+    awaiting: (Mutex<Vec<Waker>>, AtomicBool),
+    paused: AtomicBool,
+    attempt: AtomicU32,
+    dropped: AtomicU64,
+    epoch: Instant,
+    // Nanos since `epoch` plus one, zero if not recorded.
+    started: AtomicU64,
+    finished: AtomicU64,
+    // Last value accepted by `send_throttled`, shared by every handle.
+    last_throttled: AtomicU64,
+    deadline: AtomicU64,
+    deadline_after: Option<Duration>,
+    hard_deadline: AtomicBool,
+    // Every clone of the flower is dropped, nothing polls the channel anymore.
+    consumer_gone: AtomicBool,
+    pause_cvar: Condvar,
+    // Number of `advance` calls not yet consumed by a `checkpoint`, only changed under `mtx`.
+    advances: AtomicUsize,
+    cancel_reason: Mutex<Option<String>>,
+    cancel_acked: AtomicBool,
+    error_kind: AtomicU8,
+    cancel_waker: Mutex<Option<Waker>>,
+    consumers: AtomicUsize,
+    handles: AtomicUsize,
+    // The run handles belong to, a new one starts on `reset` or `reset_cancel`.
+    generation: AtomicU64,
+    on_complete: Mutex<Option<Arc<dyn Fn() + Send + Sync>>>,
+    on_cancel: Mutex<Option<Box<dyn FnOnce() + Send>>>,
+    notifier: Mutex<Option<SharedNotifier>>,
+    // A wake notifier was notified and the flower wasn't polled since, see `set_wake_notifier`.
+    wake_pending: Arc<AtomicBool>,
+    cancel_on_drop: AtomicBool,
+    err_from_string: fn(String) -> ERR,
+    buffered: bool,
+    capacity: usize,
+    backpressure: Backpressure,
+    panic_policy: PanicPolicy,
+    // The payload of a worker panic under `PanicPolicy::Propagate`, until a poll resumes it.
+    panic_pending: AtomicBool,
+    panic_payload: Mutex<Option<Box<dyn Any + Send>>>,
+    broadcast: Option<Broadcast<SOME, OK, ERR>>,
+    bridge: Mutex<Bridge<SOME, OK, ERR>>,
+    // Number of results set so far and how many broadcast consumers observed the last one.
+    results: AtomicU64,
+    observed: AtomicUsize,
+    #[cfg(feature = "stats")]
+    stats: crate::stats::Stats,
+    #[cfg(feature = "tracing")]
+    span: tracing::Span,
+    #[cfg(feature = "tracing")]
+    debug_value: std::sync::OnceLock<fn(&SOME, &mut Formatter<'_>) -> fmt::Result>,
+}
+
+/// Receivers fed by the handles directly, see `Flower::channel_receiver` and `Flower::to_tokio`.
+struct Bridge<SOME, OK, ERR> {
+    channel: Option<ChannelSender<SOME>>,
+    result: Option<ResultSender<OK, ERR>>,
+}
+
+enum ChannelSender<SOME> {
+    Std(mpsc::Sender<SOME>),
+    #[cfg(feature = "tokio")]
+    Tokio(tokio::sync::mpsc::Sender<SOME>),
+}
+
+impl<SOME> Clone for ChannelSender<SOME> {
+    fn clone(&self) -> Self {
+        match self {
+            Self::Std(sender) => Self::Std(sender.clone()),
+            #[cfg(feature = "tokio")]
+            Self::Tokio(sender) => Self::Tokio(sender.clone()),
+        }
+    }
+}
+
+enum ResultSender<OK, ERR> {
+    Std(mpsc::Sender<Result<OK, ERR>>),
+    #[cfg(feature = "tokio")]
+    Tokio(tokio::sync::oneshot::Sender<Result<OK, ERR>>),
+}
+
+impl<OK, ERR> ResultSender<OK, ERR> {
+    // A clone for the next result, a oneshot sender is used up by the first one.
+    fn reusable(&self) -> Option<Self> {
+        match self {
+            Self::Std(sender) => Some(Self::Std(sender.clone())),
+            #[cfg(feature = "tokio")]
+            Self::Tokio(_) => None,
+        }
+    }
+}
+
+/// How values are cloned for the consumers of a broadcast flower.
+struct Broadcast<SOME, OK, ERR> {
+    clone_some: fn(&SOME) -> SOME,
+    clone_ok: fn(&OK) -> OK,
+    clone_err: fn(&ERR) -> ERR,
+}
+
+impl<SOME, OK, ERR, ID> Debug for FlowerState<SOME, OK, ERR, ID>
+where
+    SOME: Debug + Send,
+    OK: Debug + Send,
+    ERR: Debug + Send,
+    ID: Debug + Send + Sync,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("FlowerState")
+            .field("id", &self.id)
+            .field("name", &self.label())
+            .field("result_ready", &self.flags.result_ready)
+            .field("channel_present", &self.activity.channel_present)
+            .field("mtx", &self.mtx)
+            .field("result", &self.result)
+            .field("cvar", &self.cvar)
+            .field("consumer_cvar", &self.consumer_cvar)
+            .field("consumer_wakers", &self.consumer_wakers)
+            .field("awaiting", &self.awaiting)
+            .field("paused", &self.paused)
+            .field("progress", &self.activity.progress)
+            .field("attempt", &self.attempt)
+            .field("dropped", &self.dropped)
+            .field("epoch", &self.epoch)
+            .field("started", &self.started)
+            .field("finished", &self.finished)
+            .field("deadline", &self.deadline)
+            .field("deadline_after", &self.deadline_after)
+            .field("hard_deadline", &self.hard_deadline)
+            .field("pause_cvar", &self.pause_cvar)
+            .field("canceled", &self.flags.canceled)
+            .field("cancel_reason", &self.cancel_reason)
+            .field("cancel_acked", &self.cancel_acked)
+            .field("error_kind", &self.error_kind())
+            .field("cancel_waker", &self.cancel_waker)
+            .field("consumers", &self.consumers)
+            .field("handles", &self.handles)
+            .field("generation", &self.generation)
+            .field("cancel_on_drop", &self.cancel_on_drop)
+            .field("activated", &self.flags.activated)
+            .field("broadcast", &self.broadcast.is_some())
+            .field("results", &self.results)
+            .field("observed", &self.observed)
+            .finish()
+    }
+}
+
+impl<SOME, OK, ERR, ID> FlowerState<SOME, OK, ERR, ID>
+where
+    SOME: Send,
+    OK: Send,
+    ERR: Send,
+    ID: Send + Sync,
+{
+    // Whether a blocked sender should give up waiting for its value to be polled.
+    fn senders_released(&self) -> bool {
+        self.flags.canceled.load(Ordering::Acquire) || self.consumer_gone.load(Ordering::Acquire)
+    }
+
+    // Take back the value of a released sender if it's still queued.
+    fn withdraw(&self, channel: &mut Channel<SOME>, ticket: u64) -> Option<SOME> {
+        let value = channel.remove(ticket)?;
+        self.activity
+            .channel_present
+            .store(!channel.is_empty(), Ordering::Release);
+        Some(value)
+    }
+
+    // False for `Flower::result_only`, whose values are rejected before touching the channel.
+    fn has_channel(&self) -> bool {
+        self.capacity > 0
+    }
+
+    fn send_error(&self, value: SOME) -> SendError<SOME> {
+        let reason = if !self.has_channel() {
+            SendErrorReason::NoChannel
+        } else if self.flags.canceled.load(Ordering::Acquire) {
+            SendErrorReason::Canceled
+        } else {
+            SendErrorReason::ConsumerDropped
+        };
+        SendError { value, reason }
+    }
+
+    fn id_string(&self) -> String {
+        (self.id_string)(&self.id)
+    }
+
+    fn label(&self) -> Option<Cow<'static, str>> {
+        self.label.lock().clone()
+    }
+
+    // Appended to the errors of dropped handles, so they can be told apart in logs.
+    fn labeled(&self, message: String) -> String {
+        match self.label() {
+            Some(label) => format!("{} (label: {:?})", message, label),
+            None => message,
+        }
+    }
+
+    fn lifecycle(&self) -> FlowerLifecycle {
+        let mtx = self.lock();
+        self.lifecycle_with(!mtx.is_empty())
+    }
+
+    // Same order as `lifecycle`, from the flags only.
+    fn lifecycle_unlocked(&self) -> FlowerLifecycle {
+        self.lifecycle_with(self.activity.channel_present.load(Ordering::Acquire))
+    }
+
+    fn lifecycle_with(&self, channel_pending: bool) -> FlowerLifecycle {
+        if self.flags.result_ready.load(Ordering::Acquire) {
+            FlowerLifecycle::ResultReady
+        } else if channel_pending {
+            FlowerLifecycle::ChannelPending
+        } else if self.flags.canceled.load(Ordering::Acquire) {
+            FlowerLifecycle::Canceled
+        } else if self.flags.activated.load(Ordering::Acquire) {
+            FlowerLifecycle::Running
+        } else {
+            FlowerLifecycle::Idle
+        }
+    }
+
+    // `flower#42 "thumbnailer" [running]`, shared by the flower and its handles.
+    fn display(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "flower#{}", self.id_string())?;
+        if let Some(label) = self.label() {
+            write!(f, " {:?}", label)?;
+        }
+        write!(f, " [{}]", self.lifecycle())
+    }
+
+    #[cfg(feature = "tracing")]
+    fn traced<'a>(&'a self, value: &'a SOME) -> crate::trace::TracedValue<'a, SOME> {
+        crate::trace::TracedValue(self.debug_value.get(), value)
+    }
+
+    fn now(&self) -> u64 {
+        self.epoch.elapsed().as_nanos() as u64 + 1
+    }
+
+    fn stamp(&self, instant: Instant) -> u64 {
+        instant.saturating_duration_since(self.epoch).as_nanos() as u64 + 1
+    }
+
+    // Block a sender until it's notified, the time is added to the stats.
+    fn wait_sender<'a>(&'a self, mtx: Locked<'a, SOME>) -> Locked<'a, SOME> {
+        self.block_sender(mtx, None)
+    }
+
+    // Same as `wait_sender` but gives up after `timeout`.
+    fn wait_sender_timeout<'a>(
+        &'a self,
+        mtx: Locked<'a, SOME>,
+        timeout: Duration,
+    ) -> Locked<'a, SOME> {
+        self.block_sender(mtx, Some(timeout))
+    }
+
+    fn block_sender<'a>(
+        &'a self,
+        mtx: Locked<'a, SOME>,
+        timeout: Option<Duration>,
+    ) -> Locked<'a, SOME> {
+        #[cfg(feature = "stats")]
+        let since = Instant::now();
+        #[cfg(feature = "park")]
+        let mtx = self.parker.wait(&self.cvar, mtx, timeout, || self.lock());
+        #[cfg(not(feature = "park"))]
+        let mtx = match timeout {
+            None => self.cvar.wait(mtx),
+            Some(timeout) => self.cvar.wait_timeout(mtx, timeout),
+        };
+        #[cfg(feature = "stats")]
+        self.stats.blocked_since(since);
+        mtx
+    }
+
+    // Wake the senders blocked in `wait_sender`.
+    fn notify_senders(&self) {
+        #[cfg(feature = "park")]
+        self.parker.notify_all(&self.cvar);
+        #[cfg(not(feature = "park"))]
+        self.cvar.notify_all();
+    }
+
+    // Record the worker activity for `Flower::time_since_last_activity`.
+    fn touch(&self) {
+        self.activity
+            .last_activity
+            .store(self.now(), Ordering::Relaxed);
+    }
+
+    fn deadline_passed(&self) -> bool {
+        match self.deadline.load(Ordering::Relaxed) {
+            0 => false,
+            deadline => self.now() >= deadline,
+        }
+    }
+
+    /// Lock the channel, a poisoned one is cleared and the result replaced by an error.
+    fn lock(&self) -> Locked<'_, SOME> {
+        self.mtx
+            .lock_checked()
+            .unwrap_or_else(|mtx| self.recover(mtx))
+    }
+
+    // Same as `lock` but `None` if a worker holds the lock.
+    fn try_lock(&self) -> Option<Locked<'_, SOME>> {
+        Some(
+            self.mtx
+                .try_lock_checked()?
+                .unwrap_or_else(|mtx| self.recover(mtx)),
+        )
+    }
+
+    fn lock_if(&self, blocking: bool) -> Option<Locked<'_, SOME>> {
+        if blocking {
+            Some(self.lock())
+        } else {
+            self.try_lock()
+        }
+    }
+
+    fn recover<'a>(&self, mut mtx: Locked<'a, SOME>) -> Locked<'a, SOME> {
+        mtx.clear();
+        self.activity
+            .channel_present
+            .store(false, Ordering::Release);
+        self.poison(&mut self.lock_result());
+        mtx
+    }
+
+    /// Lock the result, a poisoned one is replaced by an error.
+    fn lock_result(&self) -> ResultSlot<'_, OK, ERR> {
+        self.result.lock_checked().unwrap_or_else(|mut result| {
+            self.poison(&mut result);
+            result
+        })
+    }
+
+    fn lock_result_if(&self, blocking: bool) -> Option<ResultSlot<'_, OK, ERR>> {
+        let result = if blocking {
+            self.result.lock_checked()
+        } else {
+            self.result.try_lock_checked()?
+        };
+        Some(result.unwrap_or_else(|mut result| {
+            self.poison(&mut result);
+            result
+        }))
+    }
+
+    fn poison(&self, result: &mut (Option<OK>, Option<ERR>)) {
+        result.0 = None;
+        result.1 = Some((self.err_from_string)("flower state poisoned".into()));
+        self.error_kind
+            .store(ErrorKind::Panicked as u8, Ordering::Relaxed);
+        // Final like an acknowledged cancelation until the flower is reset.
+        self.cancel_acked.store(true, Ordering::Release);
+        self.flags.result_ready.store(true, Ordering::Release);
+    }
+
+    fn fail(&self, kind: ErrorKind, message: String) {
+        self.set_result(Err((self.err_from_string)(message)), kind);
+    }
+
+    // The kind of the last error result.
+    fn error_kind(&self) -> ErrorKind {
+        ErrorKind::from_u8(self.error_kind.load(Ordering::Relaxed))
+    }
+
+    fn instant(&self, stamp: u64) -> Option<Instant> {
+        match stamp {
+            0 => None,
+            nanos => Some(self.epoch + Duration::from_nanos(nanos - 1)),
+        }
+    }
+
+    /// Store the result unless one was already submitted since the activation or a cancelation
+    /// has been acknowledged, then notify the flower and call the `on_complete` callback
+    /// once per result. An error of the `Canceled` kind acknowledges the cancelation.
+    ///
+    /// Returns `false` if the result is rejected.
+    fn set_result(&self, _result: Result<OK, ERR>, kind: ErrorKind) -> bool {
+        if self.cancel_acked.load(Ordering::Acquire) || self.result_set.swap(true, Ordering::AcqRel)
+        {
+            flower_event!(self, TRACE, "result discarded");
+            return false;
+        }
+        match (&_result, kind) {
+            (Ok(_), _) => flower_event!(self, DEBUG, "ok"),
+            (Err(_), ErrorKind::Canceled) => flower_event!(self, DEBUG, "cancel acknowledged"),
+            (Err(_), _) => flower_event!(self, DEBUG, ?kind, "err"),
+        }
+        self.touch();
+        let _result = match self.forward_result(_result, kind) {
+            Some(result) => result,
+            None => return true,
+        };
+        let completed = self.store_result(&mut self.lock_result(), _result, kind);
+        if let Some(completed) = completed {
+            self.result_stored(completed);
+        }
+        true
+    }
+
+    // The locked part of `set_result`, `None` if a cancelation has been acknowledged,
+    // whether it's the first result otherwise.
+    fn store_result(
+        &self,
+        slot: &mut (Option<OK>, Option<ERR>),
+        result: Result<OK, ERR>,
+        kind: ErrorKind,
+    ) -> Option<bool> {
+        if self.cancel_acked.load(Ordering::Acquire) {
+            return None;
+        }
+        let (ok, error) = slot;
+        match result {
+            Ok(value) => {
+                *ok = Some(value);
+                *error = None;
+            }
+            Err(value) => {
+                *error = Some(value);
+                *ok = None;
+                self.error_kind.store(kind as u8, Ordering::Relaxed);
+            }
+        }
+        self.cancel_acked
+            .store(kind == ErrorKind::Canceled, Ordering::Release);
+        self.finished.store(self.now(), Ordering::Relaxed);
+        self.results.fetch_add(1, Ordering::Relaxed);
+        self.observed.store(0, Ordering::Relaxed);
+        Some(!self.flags.result_ready.swap(true, Ordering::AcqRel))
+    }
+
+    // Push the result into the receiver of `Flower::result_receiver` if any,
+    // the result is given back if it isn't there or already dropped.
+    fn forward_result(&self, result: Result<OK, ERR>, kind: ErrorKind) -> Option<Result<OK, ERR>> {
+        let sender = {
+            let mut bridge = self.bridge.lock();
+            // No value follows the result, the channel receiver sees the disconnection.
+            bridge.channel = None;
+            let sender = match bridge.result.take() {
+                Some(sender) => sender,
+                None => return Some(result),
+            };
+            bridge.result = sender.reusable();
+            sender
+        };
+        if self.cancel_acked.load(Ordering::Acquire) {
+            return None;
+        }
+        let sent = match sender {
+            ResultSender::Std(sender) => sender.send(result).map_err(|e| e.0),
+            #[cfg(feature = "tokio")]
+            ResultSender::Tokio(sender) => sender.send(result),
+        };
+        if let Err(result) = sent {
+            return Some(result);
+        }
+        self.cancel_acked
+            .store(kind == ErrorKind::Canceled, Ordering::Release);
+        self.finished.store(self.now(), Ordering::Relaxed);
+        self.flags.activated.store(false, Ordering::Release);
+        self.result_stored(true);
+        None
+    }
+
+    // The sender of the bridged receiver if any.
+    fn bridged(&self) -> Option<ChannelSender<SOME>> {
+        self.bridge.lock().channel.clone()
+    }
+
+    // Push the value into the bridged receiver, a full tokio one blocks the thread
+    // until there's room, the flower is canceled or `deadline` passed.
+    fn forward(
+        &self,
+        sender: &ChannelSender<SOME>,
+        value: SOME,
+        _deadline: Option<Instant>,
+    ) -> Result<(), SendError<SOME>> {
+        if self.flags.canceled.load(Ordering::Acquire) {
+            return Err(self.send_error(value));
+        }
+        match sender {
+            ChannelSender::Std(sender) => {
+                sender
+                    .send(value)
+                    .map_err(|mpsc::SendError(value)| SendError {
+                        value,
+                        reason: SendErrorReason::ConsumerDropped,
+                    })
+            }
+            #[cfg(feature = "tokio")]
+            ChannelSender::Tokio(sender) => {
+                match crate::sync::block_on(self.reserve(sender), _deadline) {
+                    Some(Ok(permit)) => {
+                        permit.send(value);
+                        Ok(())
+                    }
+                    Some(Err(reason)) => Err(SendError { value, reason }),
+                    // Only `send_timeout` has a deadline, the reason isn't reported.
+                    None => Err(SendError {
+                        value,
+                        reason: SendErrorReason::Canceled,
+                    }),
+                }
+            }
+        }
+    }
+
+    // Same as `forward` without blocking the thread.
+    async fn forward_async(
+        &self,
+        sender: &ChannelSender<SOME>,
+        value: SOME,
+    ) -> Result<(), SendError<SOME>> {
+        match sender {
+            #[cfg(feature = "tokio")]
+            ChannelSender::Tokio(sender) => {
+                if self.flags.canceled.load(Ordering::Acquire) {
+                    return Err(self.send_error(value));
+                }
+                match self.reserve(sender).await {
+                    Ok(permit) => {
+                        permit.send(value);
+                        Ok(())
+                    }
+                    Err(reason) => Err(SendError { value, reason }),
+                }
+            }
+            sender => self.forward(sender, value, None),
+        }
+    }
+
+    // Same as `forward` but the value is dropped if a tokio receiver is full.
+    fn forward_latest(&self, sender: &ChannelSender<SOME>, value: SOME) {
+        match sender {
+            #[cfg(feature = "tokio")]
+            ChannelSender::Tokio(sender) => {
+                if !self.flags.canceled.load(Ordering::Acquire) {
+                    let _ = sender.try_send(value);
+                }
+            }
+            sender => {
+                let _ = self.forward(sender, value, None);
+            }
+        }
+    }
+
+    // Wait for room in the tokio receiver, gives up once the flower is canceled.
+    #[cfg(feature = "tokio")]
+    async fn reserve<'a>(
+        &self,
+        sender: &'a tokio::sync::mpsc::Sender<SOME>,
+    ) -> Result<tokio::sync::mpsc::Permit<'a, SOME>, SendErrorReason> {
+        let mut reserve = core::pin::pin!(sender.reserve());
+        let mut canceled = CancelSuspender { state: self };
+        core::future::poll_fn(|cx| {
+            if let Poll::Ready(permit) = reserve.as_mut().poll(cx) {
+                return Poll::Ready(permit.map_err(|_| SendErrorReason::ConsumerDropped));
+            }
+            match Pin::new(&mut canceled).poll(cx) {
+                Poll::Ready(()) => Poll::Ready(Err(SendErrorReason::Canceled)),
+                Poll::Pending => Poll::Pending,
+            }
+        })
+        .await
+    }
+
+    // The unlocked part of `set_result`.
+    fn result_stored(&self, completed: bool) {
+        self.result_stored_if(completed, true);
+    }
+
+    // Same as `result_stored`, without waiting for the channel lock unless `blocking`.
+    // Consumers blocked elsewhere then wake up at the deadline anyway.
+    fn result_stored_if(&self, completed: bool, blocking: bool) {
+        {
+            // Consumers check the result flags under the channel lock before waiting.
+            let _mtx = self.lock_if(blocking);
+            self.notify_consumer();
+        }
+        if let Some(n) = self.notifier() {
+            n.notify();
+        }
+        if completed {
+            let on_complete = self.on_complete.lock().clone();
+            if let Some(f) = on_complete {
+                f();
+            }
+        }
+    }
+
+    fn notifier(&self) -> Option<SharedNotifier> {
+        self.notifier.lock().clone()
+    }
+
+    /// Wake whoever is waiting on the flower side.
+    fn wake_sender(&self) {
+        let awaiting = &self.awaiting;
+        if awaiting.1.load(Ordering::Acquire) {
+            let mut wakers = awaiting.0.lock();
+            awaiting.1.store(false, Ordering::Release);
+            for waker in wakers.drain(..) {
+                waker.wake();
+            }
+        }
+        self.notify_senders();
+    }
+
+    // A worker panic which isn't reported as an error, the first result is final as usual.
+    fn panicked(&self, payload: Box<dyn Any + Send>) {
+        if self.result_set.swap(true, Ordering::AcqRel) {
+            return;
+        }
+        if self.panic_policy == PanicPolicy::Propagate {
+            *self.panic_payload.lock() = Some(payload);
+            self.panic_pending.store(true, Ordering::Release);
+        }
+        self.flags.activated.store(false, Ordering::Release);
+        {
+            let _mtx = self.lock();
+            self.notify_consumer();
+        }
+        if let Some(n) = self.notifier() {
+            n.notify();
+        }
+    }
+
+    // When the hard deadline of the running job passes, `None` without one or once the result is ready.
+    fn hard_deadline_at(&self) -> Option<Instant> {
+        if self.broadcast.is_some()
+            || !self.hard_deadline.load(Ordering::Relaxed)
+            || !self.flags.activated.load(Ordering::Acquire)
+            || self.flags.result_ready.load(Ordering::Acquire)
+        {
+            return None;
+        }
+        self.instant(self.deadline.load(Ordering::Relaxed))
+    }
+
+    // Store the "deadline exceeded" error once the hard deadline passed,
+    // `None` if not `blocking` and the result lock is held.
+    fn enforce_hard_deadline(&self, blocking: bool) -> Option<()> {
+        if self.hard_deadline_at().is_none() || !self.deadline_passed() {
+            return Some(());
+        }
+        let mut result = self.lock_result_if(blocking)?;
+        // Claimed under the lock, a late result from the worker won't overwrite it.
+        if !self.result_set.swap(true, Ordering::AcqRel) {
+            let error = (self.err_from_string)("deadline exceeded".into());
+            let completed = self.store_result(&mut result, Err(error), ErrorKind::Canceled);
+            drop(result);
+            match completed {
+                Some(completed) => self.result_stored_if(completed, blocking),
+                None => self.result_set.store(false, Ordering::Release),
+            }
+        }
+        Some(())
+    }
+
+    // Wait for a notification of the consumers until `until`, or the hard deadline
+    // whose error is stored then, so the caller sees the result when checking again.
+    fn wait_consumer<'a>(
+        &'a self,
+        mtx: Locked<'a, SOME>,
+        until: Option<Instant>,
+    ) -> Locked<'a, SOME> {
+        let mtx = match earliest(until, self.hard_deadline_at()) {
+            None => return self.consumer_cvar.wait(mtx),
+            Some(until) => {
+                let timeout = until.saturating_duration_since(Instant::now());
+                self.consumer_cvar.wait_timeout(mtx, timeout)
+            }
+        };
+        if self.hard_deadline_at().is_none() || !self.deadline_passed() {
+            return mtx;
+        }
+        drop(mtx);
+        self.enforce_hard_deadline(true);
+        self.lock()
+    }
+
+    // The result is ready or a propagated panic is waiting to be resumed by a poll.
+    // Clear the pending wake of `set_wake_notifier`, returns whether it was pending.
+    fn take_wake_pending(&self) -> bool {
+        self.wake_pending.load(Ordering::Relaxed) && self.wake_pending.swap(false, Ordering::SeqCst)
+    }
+
+    fn result_is_pending(&self) -> bool {
+        self.flags.result_ready.load(Ordering::Acquire)
+            || self.panic_pending.load(Ordering::Acquire)
+    }
+
+    // Resume a propagated worker panic on the consumer thread.
+    fn resume_panic(&self) {
+        if self.panic_pending.swap(false, Ordering::AcqRel) {
+            if let Some(payload) = self.panic_payload.lock().take() {
+                panic::resume_unwind(payload);
+            }
+        }
+    }
+
+    fn notify_consumer(&self) {
+        self.consumer_cvar.notify_all();
+        let wakers = std::mem::take(&mut *self.consumer_wakers.lock());
+        for (_, waker) in wakers {
+            waker.wake();
+        }
+    }
+
+    // Replace the waker registered with `key`, unless it already wakes the same task.
+    fn register_consumer(&self, key: usize, waker: &Waker) {
+        let mut wakers = self.consumer_wakers.lock();
+        match wakers.iter_mut().find(|(registered, _)| *registered == key) {
+            Some((_, registered)) => {
+                if !registered.will_wake(waker) {
+                    *registered = waker.clone();
+                }
+            }
+            None => wakers.push((key, waker.clone())),
+        }
+    }
+
+    fn unregister_consumer(&self, key: usize) {
+        self.consumer_wakers
+            .lock()
+            .retain(|(registered, _)| *registered != key);
+    }
+}
+
+impl<SOME, OK, ERR, ID> Drop for FlowerState<SOME, OK, ERR, ID>
+where
+    SOME: Send,
+    OK: Send,
+    ERR: Send,
+    ID: Send + Sync,
+{
+    fn drop(&mut self) {}
+}
+
+/// Flow loosely and gracefully.
+///
+/// Where:
+///
+/// SOME = type of sender (channel) value, `()` if the worker only returns a result,
+/// see [`SimpleFlower`]
+///
+/// OK = type of Ok value of the Result (Result<'OK', 'ERR'>)
+///
+/// ERR = type of Err value of the Result (Result<'OK', 'ERR'>, String by default).
+/// A panicked flower handle reports its error through `From<String>`.
+///
+/// ID = type of the flower id (usize by default), its `Debug` output is used in the error messages.
+///
+/// # Quick Example:
+///
+///```
+///use flowync::Flower;
+///
+///type TestFlower = Flower<u32, String>;
+///
+///fn _main() {
+///    let flower: TestFlower = Flower::new(1);
+///    std::thread::spawn({
+///        let handle = flower.handle();
+///        // Activate
+///        handle.activate();
+///        move || {
+///            for i in 0..10 {
+///                // // Send current value through channel, will block the spawned thread
+///                // until the option value successfully being polled in the main thread,
+///                // fails if the flower is canceled or dropped in the meantime.
+///                if handle.send(i).is_err() {
+///                    break;
+///                }
+///                // or handle.send_async(i).await, can be used from any multithreaded async runtime,
+///                
+///                // // Return error if the job is failure, for example:
+///                // if i >= 3 {
+///                //    return handle.err("Err");
+///                // }
+///            }
+///            // And return ok if the job successfully completed.
+///            return handle.ok("Ok".to_string());
+///        }
+///    });
+///
+///    loop {
+///        // Instead of polling the mutex over and over, check if the flower is_active()
+///        // and will deactivate itself if the result value successfully received.
+///        // Note: this fn is non-blocking (won't block the current thread).
+///        if flower.is_active() {
+///            // another logic goes here...
+///            // e.g:
+///            // notify_loading_fn();
+///
+///            let finished = flower.then_finished(|channel| {
+///                // poll channel
+///                if let Some(value) = channel {
+///                    println!("{}", value);
+///                }
+///            },
+///            |result| {
+///                // match result
+///                match result {
+///                    Ok(value) => println!("{}", value),
+///                    Err(err_msg) => println!("{}", err_msg),
+///                }
+///            });
+///
+///            // exit if completed
+///            if finished {
+///                break;
+///            }
+///        }
+///    }
+///}
+/// ```
+///
+/// A worker which sends nothing doesn't need a channel type:
+///
+///```
+///use flowync::SimpleFlower;
+///
+///let flower: SimpleFlower<String> = SimpleFlower::spawn(1, |handle| handle.ok("Ok".into()));
+///assert_eq!(flower.wait().unwrap(), "Ok");
+///```
+pub struct Flower<SOME, OK, ERR = String, ID = usize>
+where
+    SOME: Send,
+    OK: Send,
+    ERR: Send,
+    ID: Send + Sync,
+{
+    state: Arc<FlowerState<SOME, OK, ERR, ID>>,
+    // Broadcast only, the next value to read and the number of results observed by this clone.
+    cursor: AtomicU64,
+    observed: AtomicU64,
+}
+
+/// A flower which only delivers a result, its channel type is `()`.
+pub type SimpleFlower<OK, ERR = String, ID = usize> = Flower<(), OK, ERR, ID>;
+
+/// The handle of a [`SimpleFlower`].
+pub type SimpleFlowerHandle<OK, ERR = String, ID = usize> = FlowerHandle<(), OK, ERR, ID>;
+
+impl<SOME, OK, ERR, ID> Flower<SOME, OK, ERR, ID>
+where
+    SOME: Send,
+    OK: Send,
+    ERR: Send + From<String>,
+    ID: Clone + Debug + Send + Sync,
+{
+    pub fn new(id: ID) -> Self {
+        Self::from_builder(&FlowerBuilder::new(id), From::from)
+    }
+
+    /// Create a flower with buffered channel.
+    ///
+    /// Channel values are queued, `send` and `send_async` never wait for them to be polled
+    /// and each `then` call pops one value, the result is delivered after the queue drained.
+    ///
+    /// Queued values are dropped along with the flower state.
+    pub fn new_buffered(id: ID) -> Self {
+        Self::from_builder(&FlowerBuilder::new(id).buffered(true), From::from)
+    }
+
+    /// Create a flower and run `f` on a new thread named "flower-{id}",
+    /// the flower is activated before `f` runs.
+    ///
+    /// A panic in `f` is caught and reported as the error "worker panicked: {message}".
+    ///
+    /// # Quick Example:
+    ///
+    ///```
+    ///use flowync::Flower;
+    ///
+    ///let flower: Flower<u32, String> = Flower::spawn(1, |handle| {
+    ///    let _ = handle.send(1);
+    ///    handle.ok("Ok".to_string());
+    ///});
+    ///# let _ = flower.iter().count();
+    ///# assert_eq!(flower.wait(), Ok("Ok".to_string()));
+    ///```
+    pub fn spawn(id: ID, f: impl FnOnce(FlowerHandle<SOME, OK, ERR, ID>) + Send + 'static) -> Self
+    where
+        SOME: 'static,
+        OK: 'static,
+        ERR: 'static,
+        ID: 'static,
+    {
+        let flower = Self::new(id);
+        flower.start(f);
+        flower
+    }
+
+    /// Same as `spawn`, the returned result of `f` is set with `ok` or `err`.
+    pub fn spawn_result(
+        id: ID,
+        f: impl FnOnce(&FlowerHandle<SOME, OK, ERR, ID>) -> Result<OK, ERR> + Send + 'static,
+    ) -> Self
+    where
+        SOME: 'static,
+        OK: 'static,
+        ERR: 'static,
+        ID: 'static,
+    {
+        Self::spawn(id, move |handle| match f(&handle) {
+            Ok(value) => handle.ok(value),
+            Err(value) => handle.err(value),
+        })
+    }
+
+    /// Same as `spawn_result`, but `f` is tried up to `attempts` times.
+    ///
+    /// `f` gets the current attempt starting at 1, also available from `FlowerHandle::attempt`.
+    /// After a failed attempt the worker sleeps the backoff, doubled after each attempt,
+    /// a cancelation during the sleep aborts with `cancel_ack`.
+    /// The last error is annotated with the number of attempts.
+    pub fn spawn_with_retry<E: Display>(
+        id: ID,
+        attempts: u32,
+        backoff: Duration,
+        f: impl Fn(FlowerHandle<SOME, OK, ERR, ID>, u32) -> Result<OK, E> + Send + 'static,
+    ) -> Self
+    where
+        SOME: 'static,
+        OK: 'static,
+        ERR: 'static,
+        ID: 'static,
+    {
+        Self::spawn(id, move |handle| {
+            let mut backoff = backoff;
+            for attempt in 1..=attempts.max(1) {
+                handle.state.attempt.store(attempt, Ordering::Relaxed);
+                match f(handle.clone(), attempt) {
+                    Ok(value) => return handle.ok(value),
+                    Err(e) if attempt >= attempts => {
+                        return handle.err(format!("{} (after {} attempts)", e, attempt))
+                    }
+                    Err(_) => (),
+                }
+                if !handle.sleep_unless_canceled(backoff) {
+                    return handle.cancel_ack();
+                }
+                backoff = backoff.saturating_mul(2);
+            }
+        })
+    }
+}
+
+// The errors made by the flower itself, e.g. for dropped handles, come from `err_from_string`.
+impl<SOME, OK, ERR, ID> Flower<SOME, OK, ERR, ID>
+where
+    SOME: Send,
+    OK: Send,
+    ERR: Send,
+    ID: Clone + Debug + Send + Sync,
+{
+    // Activate the flower and run `f` on a new thread, the body of `spawn`.
+    pub(crate) fn start(&self, f: impl FnOnce(FlowerHandle<SOME, OK, ERR, ID>) + Send + 'static)
+    where
+        SOME: 'static,
+        OK: 'static,
+        ERR: 'static,
+        ID: 'static,
+    {
+        let handle = self.handle();
+        handle.activate();
+        let worker = handle.clone();
+        thread::Builder::new()
+            .name(format!("flower-{}", self.state.id_string()))
+            .spawn(move || handle.catch_panic(|| f(worker)))
+            .expect("failed to spawn thread");
+    }
+
+    pub(crate) fn from_builder(
+        builder: &FlowerBuilder<ID>,
+        err_from_string: fn(String) -> ERR,
+    ) -> Self {
+        Self::with_broadcast(builder, None, err_from_string)
+    }
+
+    fn with_broadcast(
+        builder: &FlowerBuilder<ID>,
+        broadcast: Option<Broadcast<SOME, OK, ERR>>,
+        err_from_string: fn(String) -> ERR,
+    ) -> Self {
+        Self {
+            state: Arc::new(FlowerState {
+                id: builder.id.clone(),
+                id_string: |id| format!("{:?}", id),
+                label: Mutex::new(builder.label.clone()),
+                flags: CachePadded::new(RunFlags {
+                    activated: AtomicBool::new(false),
+                    result_ready: AtomicBool::new(false),
+                    canceled: AtomicBool::new(false),
+                }),
+                activity: CachePadded::new(Activity {
+                    channel_present: AtomicBool::new(false),
+                    progress: AtomicU32::new(NO_PROGRESS),
+                    last_activity: AtomicU64::new(0),
+                }),
+                result_set: AtomicBool::new(false),
+                mtx: Mutex::new(Channel::new()),
+                result: Mutex::new((None, None)),
+                cvar: Condvar::new(),
+                #[cfg(feature = "park")]
+                parker: crate::sync::Parker::new(),
+                consumer_cvar: Condvar::new(),
+                consumer_wakers: Mutex::new(Vec::new()),
+                awaiting: (Mutex::new(Vec::new()), AtomicBool::new(false)),
+                paused: AtomicBool::new(false),
+                attempt: AtomicU32::new(0),
+                dropped: AtomicU64::new(0),
+                epoch: Instant::now(),
+                started: AtomicU64::new(0),
+                last_throttled: AtomicU64::new(0),
+                finished: AtomicU64::new(0),
+                deadline: AtomicU64::new(0),
+                deadline_after: builder.deadline,
+                hard_deadline: AtomicBool::new(builder.hard_deadline),
+                consumer_gone: AtomicBool::new(false),
+                pause_cvar: Condvar::new(),
+                advances: AtomicUsize::new(0),
+                cancel_reason: Mutex::new(None),
+                cancel_acked: AtomicBool::new(false),
+                error_kind: AtomicU8::new(ErrorKind::Custom as u8),
+                cancel_waker: Mutex::new(None),
+                consumers: AtomicUsize::new(1),
+                handles: AtomicUsize::new(0),
+                generation: AtomicU64::new(0),
+                on_complete: Mutex::new(None),
+                on_cancel: Mutex::new(None),
+                notifier: Mutex::new(None),
+                wake_pending: Arc::new(AtomicBool::new(false)),
+                cancel_on_drop: AtomicBool::new(builder.cancel_on_drop),
+                err_from_string,
+                buffered: builder.buffered,
+                capacity: builder.capacity.unwrap_or(usize::MAX),
+                backpressure: builder.backpressure,
+                panic_policy: builder.panic_policy,
+                panic_pending: AtomicBool::new(false),
+                panic_payload: Mutex::new(None),
+                broadcast,
+                bridge: Mutex::new(Bridge {
+                    channel: None,
+                    result: None,
+                }),
+                results: AtomicU64::new(0),
+                observed: AtomicUsize::new(0),
+                #[cfg(feature = "stats")]
+                stats: Default::default(),
+                #[cfg(feature = "tracing")]
+                span: tracing::info_span!(
+                    "flower",
+                    id = ?builder.id,
+                    label = builder.label.as_deref()
+                ),
+                #[cfg(feature = "tracing")]
+                debug_value: std::sync::OnceLock::new(),
+            }),
+            cursor: AtomicU64::new(0),
+            observed: AtomicU64::new(0),
+        }
+    }
+}
+
+impl<SOME, OK, ERR> Flower<SOME, OK, ERR>
+where
+    SOME: Send,
+    OK: Send,
+    ERR: Send + From<String>,
+{
+    /// Create a flower with the next id of a process wide counter, starting at 1.
+    ///
+    /// The counter wraps around to 0 after `usize::MAX` ids, see also [`last_issued_id`].
+    pub fn new_auto() -> Self {
+        Self::new(NEXT_ID.fetch_add(1, Ordering::Relaxed))
+    }
+
+    /// Same as `new_auto`, the name is shown along with the id in the `Debug` output.
+    pub fn new_auto_named(name: &str) -> Self {
+        FlowerBuilder::new(NEXT_ID.fetch_add(1, Ordering::Relaxed))
+            .label(name.to_owned())
+            .build()
+    }
+}
+
+impl Flower<(), ()> {
+    /// Start configuring a flower, same as `FlowerBuilder::new`.
+    ///
+    /// The types of the flower are picked at `build`, `Flower::new(id)` is the shortcut
+    /// for the defaults.
+    ///
+    ///```
+    ///use flowync::Flower;
+    ///
+    ///let flower: Flower<u32, String> = Flower::builder(1)
+    ///    .capacity(16)
+    ///    .cancel_on_drop(true)
+    ///    .label("sync")
+    ///    .build();
+    ///assert_eq!(flower.label().as_deref(), Some("sync"));
+    ///```
+    pub fn builder<ID>(id: ID) -> FlowerBuilder<ID> {
+        FlowerBuilder::new(id)
+    }
+}
+
+impl<OK, ERR, ID> Flower<(), OK, ERR, ID>
+where
+    OK: Send,
+    ERR: Send + From<String>,
+    ID: Clone + Debug + Send + Sync,
+{
+    /// Create a flower for a worker which never sends, only its result is polled.
+    ///
+    /// The flower has no channel, `send` and `send_async` fail right away with
+    /// `SendErrorReason::NoChannel` and `send_latest` drops the value, no sender ever waits.
+    ///
+    ///```
+    ///use flowync::SimpleFlower;
+    ///
+    ///let flower: SimpleFlower<u64> = SimpleFlower::result_only(1);
+    ///let handle = flower.handle();
+    ///handle.activate();
+    ///std::thread::spawn(move || handle.ok((1..=10).sum()));
+    ///
+    ///assert_eq!(flower.wait().unwrap(), 55);
+    ///```
+    pub fn result_only(id: ID) -> Self {
+        Self::from_builder(
+            &FlowerBuilder {
+                buffered: true,
+                capacity: Some(0),
+                ..FlowerBuilder::new(id)
+            },
+            From::from,
+        )
+    }
+}
+
+impl<SOME, OK, ERR, ID> Flower<SOME, OK, ERR, ID>
+where
+    SOME: Send,
+    OK: Send,
+    ERR: Send + Display,
+    ID: Clone + Send + Sync,
+{
+    /// Process the flower like `then`, but the error is a [`FlowerError`],
+    /// its kind tells a panic, a cancelation or dropped handles from a failure of the worker.
+    pub fn then_structured(
+        &self,
+        c: impl FnOnce(Option<SOME>),
+        r: impl FnOnce(Result<OK, FlowerError<ID>>),
+    ) {
+        self.then(c, |result| {
+            r(result.map_err(|e| FlowerError {
+                id: self.state.id.clone(),
+                kind: self.state.error_kind(),
+                message: e.to_string(),
+            }))
+        })
+    }
+}
+
+impl<SOME, OK, ERR, ID> Flower<SOME, OK, ERR, ID>
+where
+    SOME: Send + Clone,
+    OK: Send + Clone,
+    ERR: Send + Clone + From<String>,
+    ID: Clone + Debug + Send + Sync,
+{
+    pub(crate) fn from_broadcast_builder(builder: &FlowerBuilder<ID>) -> Self {
+        Self::with_broadcast(
+            builder,
+            Some(Broadcast {
+                clone_some: Clone::clone,
+                clone_ok: Clone::clone,
+                clone_err: Clone::clone,
+            }),
+            From::from,
+        )
+    }
+}
+
+impl<SOME, OK, ERR, ID> Flower<SOME, OK, ERR, ID>
+where
+    SOME: Send,
+    OK: Send,
+    ERR: Send,
+    ID: Send + Sync,
+{
+    /// Get ID of the flower.
+    pub fn id(&self) -> &ID {
+        &self.state.id
+    }
+
+    /// Check if both are the same flower (or clones of it), whatever their ids are.
+    pub fn same_flower(&self, other: &Flower<SOME, OK, ERR, ID>) -> bool {
+        Arc::ptr_eq(&self.state, &other.state)
+    }
+
+    /// Format the channel values with `Debug` in the `send` and `send_async` trace events,
+    /// they're shown as `..` otherwise.
+    #[cfg(feature = "tracing")]
+    pub fn trace_values(&self)
+    where
+        SOME: Debug,
+    {
+        let _ = self.state.debug_value.set(|value, f| Debug::fmt(value, f));
+    }
+
+    /// Get the label of the flower, see `FlowerBuilder::label` and `new_auto_named`.
+    pub fn label(&self) -> Option<Cow<'static, str>> {
+        self.state.label()
+    }
+
+    /// Set or replace the label of the flower, shared by its clones and handles.
+    pub fn set_label(&self, label: impl Into<Cow<'static, str>>) {
+        let label = label.into();
+        #[cfg(feature = "tracing")]
+        self.state.span.record("label", &*label);
+        *self.state.label.lock() = Some(label);
+    }
+
+    /// Get handle of the flower.
+    ///
+    /// A canceled flower stays canceled, see `reset_cancel`.
+    pub fn handle(&self) -> FlowerHandle<SOME, OK, ERR, ID> {
+        self.state.handles.fetch_add(1, Ordering::Relaxed);
+        FlowerHandle {
+            state: Clone::clone(&self.state),
+            generation: self.state.generation.load(Ordering::Acquire),
+        }
+    }
+
+    /// Clear the cancelation and its reason so the flower can be run again,
+    /// a new run starts if it was canceled, see `generation`.
+    pub fn reset_cancel(&self) {
+        if self.state.flags.canceled.swap(false, Ordering::AcqRel) {
+            self.state.generation.fetch_add(1, Ordering::AcqRel);
+        }
+        self.state.cancel_reason.lock().take();
+    }
+
+    /// Get the current run of the flower, bumped by `reset` and `reset_cancel`.
+    ///
+    /// Handles of an older run are stale, their results and values are discarded
+    /// and `should_cancel` is true for them, so a late worker can't clobber the new run.
+    pub fn generation(&self) -> u64 {
+        self.state.generation.load(Ordering::Acquire)
+    }
+
+    /// Get a weak reference to the flower, which neither keeps its state alive
+    /// nor counts as a consumer, e.g. for cancel on drop.
+    pub fn downgrade(&self) -> WeakFlower<SOME, OK, ERR, ID> {
+        WeakFlower {
+            state: Arc::downgrade(&self.state),
+        }
+    }
+
+    /// Activate the flower and run `f` on a thread of the scope, so the worker can borrow
+    /// from the stack, see [`std::thread::scope`].
+    ///
+    /// A panic in `f` is reported through the flower handle as usual,
+    /// a previous cancelation is cleared with `reset_cancel` first.
+    pub fn scoped_spawn<'scope>(
+        &self,
+        scope: &'scope thread::Scope<'scope, '_>,
+        f: impl FnOnce(FlowerHandle<SOME, OK, ERR, ID>) + Send + 'scope,
+    ) -> thread::ScopedJoinHandle<'scope, ()>
+    where
+        SOME: 'scope,
+        OK: 'scope,
+        ERR: 'scope,
+        ID: 'scope,
+    {
+        self.reset_cancel();
+        let handle = self.handle();
+        handle.activate();
+        scope.spawn(move || f(handle))
+    }
+
+    /// Get the number of live handles of the flower.
+    pub fn handle_count(&self) -> usize {
+        self.state.handles.load(Ordering::Acquire)
+    }
+
+    /// Check if any handle of the flower is still alive.
+    ///
+    /// e.g. `is_active() && !has_handle() && !result_is_ready()` means the worker disappeared.
+    pub fn has_handle(&self) -> bool {
+        self.handle_count() > 0
+    }
+
+    /// Set a callback to be called from the flower handle once the result is ready,
+    /// replaces the previous one if any.
+    ///
+    /// The callback runs on the worker thread without holding the internal mutex,
+    /// e.g. to schedule a redraw or post an event to the UI thread.
+    pub fn set_on_complete(&self, f: impl Fn() + Send + Sync + 'static) {
+        *self.state.on_complete.lock() = Some(Arc::new(f));
+    }
+
+    /// Remove the `on_complete` callback.
+    pub fn clear_on_complete(&self) {
+        self.state.on_complete.lock().take();
+    }
+
+    /// Set a notifier to be notified from the flower handle on every
+    /// `send`, `send_async`, `ok` and `err` and on a propagated worker panic,
+    /// replaces the previous one if any.
+    ///
+    /// Any `Fn()` closure is a notifier, see [`Notifier`].
+    pub fn set_notifier(&self, n: impl Notifier + Send + Sync + 'static) {
+        *self.state.notifier.lock() = Some(Arc::new(n));
+    }
+
+    /// Same as `set_notifier` but the notifier is only notified once until the flower
+    /// is polled again, e.g. for an event loop whose wake-ups are queued like user events.
+    ///
+    /// Poll until `Idle` on each wake-up, the values sent meanwhile don't notify again.
+    pub fn set_wake_notifier(&self, n: impl Notifier + Send + Sync + 'static) {
+        let pending = self.state.wake_pending.clone();
+        pending.store(false, Ordering::SeqCst);
+        self.set_notifier(move || {
+            if !pending.swap(true, Ordering::SeqCst) {
+                n.notify();
+            }
+        });
+    }
+
+    /// Remove the notifier.
+    pub fn clear_notifier(&self) {
+        self.state.notifier.lock().take();
+    }
+
+    // Swap the notifier, e.g. to put back the one of the user after `collect_results`.
+    pub(crate) fn replace_notifier(&self, n: Option<SharedNotifier>) -> Option<SharedNotifier> {
+        std::mem::replace(&mut *self.state.notifier.lock(), n)
+    }
+
+    /// Cancel current flower handle.
+    ///
+    /// will do nothing if not explicitly configured.
+    pub fn cancel(&self) {
+        flower_event!(self.state, DEBUG, "cancel");
+        {
+            // Set under the lock, so a blocked sender can't miss the wake up.
+            let _mtx = self.state.lock();
+            self.state.flags.canceled.store(true, Ordering::Release);
+            self.state.pause_cvar.notify_all();
+        }
+        // Blocked senders give up on their values.
+        self.wake_sender();
+        if let Some(waker) = self.state.cancel_waker.lock().take() {
+            waker.wake();
+        }
+        // Taken after the flag is set, see `FlowerHandle::set_on_cancel`.
+        let on_cancel = self.state.on_cancel.lock().take();
+        if let Some(f) = on_cancel {
+            f();
+        }
+    }
+
+    /// Ask the worker to pause, the worker has to cooperate with
+    /// `FlowerHandle::should_pause` or `FlowerHandle::wait_if_paused`.
+    pub fn pause(&self) {
+        self.state.paused.store(true, Ordering::Release);
+    }
+
+    /// Resume the paused worker.
+    pub fn resume(&self) {
+        self.state.paused.store(false, Ordering::Release);
+        let _mtx = self.state.lock();
+        self.state.pause_cvar.notify_all();
+    }
+
+    /// Check if the flower is paused
+    pub fn is_paused(&self) -> bool {
+        self.state.paused.load(Ordering::Acquire)
+    }
+
+    /// Let the worker continue from its `FlowerHandle::checkpoint`.
+    ///
+    /// Calling it before the worker reaches the checkpoint lets that checkpoint pass right away.
+    pub fn advance(&self) {
+        let _mtx = self.state.lock();
+        self.state.advances.fetch_add(1, Ordering::Relaxed);
+        self.state.pause_cvar.notify_all();
+    }
+
+    /// Cancel the flower once its last clone is dropped (disabled by default),
+    /// a sender waiting in `send` or `send_async` is woken so it can observe `should_cancel`.
+    pub fn cancel_on_drop(&self, cancel_on_drop: bool) {
+        self.state
+            .cancel_on_drop
+            .store(cancel_on_drop, Ordering::Relaxed);
+    }
+
+    /// Cancel current flower handle with a reason.
+    ///
+    /// The reason can be acknowledged from the handle with `cancel_ack`.
+    pub fn cancel_with(&self, reason: impl Into<String>) {
+        *self.state.cancel_reason.lock() = Some(reason.into());
+        self.cancel();
+    }
+
+    /// Cancel current flower handle and block until the worker sets the result,
+    /// or until `timeout` elapsed.
+    ///
+    /// A sender waiting in `send` or `send_async` is woken so it can observe `should_cancel`,
+    /// channel values are discarded meanwhile and the result is left to be polled.
+    ///
+    /// Returns `false` if the worker is still running after `timeout`.
+    pub fn cancel_and_wait(&self, timeout: Duration) -> bool {
+        let deadline = Instant::now() + timeout;
+        self.cancel();
+        self.wake_sender();
+        let mut mtx = self.state.lock();
+        loop {
+            if self.state.result_is_pending() {
+                return true;
+            } else if !mtx.is_empty() {
+                let discarded = mtx.take();
+                self.state
+                    .activity
+                    .channel_present
+                    .store(false, Ordering::Release);
+                drop(mtx);
+                self.wake_sender();
+                drop(discarded);
+                mtx = self.state.lock();
+            } else {
+                if Instant::now() >= deadline {
+                    return false;
+                }
+                mtx = self.state.wait_consumer(mtx, Some(deadline));
+            }
+        }
+    }
+
+    /// Reset the flower so it can be reused for another job.
+    ///
+    /// Clears every flag, discards any channel value and result left behind
+    /// and wakes a stale sender if any.
+    ///
+    /// Resetting while a worker is still running is a logic error, in that case
+    /// (active and the result isn't ready) nothing is reset and `ResetWhileActive` is returned.
+    pub fn reset(&self) -> Result<(), ResetWhileActive> {
+        let discarded = {
+            let mut mtx = self.state.lock();
+            if self.state.flags.activated.load(Ordering::Acquire)
+                && !self.state.flags.result_ready.load(Ordering::Acquire)
+            {
+                return Err(ResetWhileActive);
+            }
+            self.state.flags.activated.store(false, Ordering::Release);
+            self.state
+                .flags
+                .result_ready
+                .store(false, Ordering::Release);
+            self.state.result_set.store(false, Ordering::Release);
+            self.state
+                .activity
+                .channel_present
+                .store(false, Ordering::Release);
+            self.state.flags.canceled.store(false, Ordering::Release);
+            self.state.paused.store(false, Ordering::Release);
+            self.state.advances.store(0, Ordering::Relaxed);
+            self.state
+                .activity
+                .progress
+                .store(NO_PROGRESS, Ordering::Relaxed);
+            self.state.attempt.store(0, Ordering::Relaxed);
+            self.state.dropped.store(0, Ordering::Relaxed);
+            self.state.started.store(0, Ordering::Relaxed);
+            self.state
+                .activity
+                .last_activity
+                .store(0, Ordering::Relaxed);
+            self.state.last_throttled.store(0, Ordering::Relaxed);
+            self.state.deadline.store(0, Ordering::Relaxed);
+            self.state.finished.store(0, Ordering::Relaxed);
+            self.state.cancel_acked.store(false, Ordering::Release);
+            self.state.cancel_reason.lock().take();
+            self.state.on_cancel.lock().take();
+            self.state.panic_pending.store(false, Ordering::Release);
+            self.state.panic_payload.lock().take();
+            self.state.generation.fetch_add(1, Ordering::AcqRel);
+            let mut result = self.state.lock_result();
+            (mtx.take(), result.0.take(), result.1.take())
+        };
+        self.wake_sender();
+        drop(discarded);
+        Ok(())
+    }
+
+    /// Check if the flower is canceled
+    pub fn is_canceled(&self) -> bool {
+        self.state.flags.canceled.load(Ordering::Acquire)
+    }
+
+    /// Check if the result is an acknowledged cancelation, set with `FlowerHandle::cancel_ack`
+    /// or by a passed hard deadline, rather than a result of the worker.
+    ///
+    /// Unlike `is_canceled` it isn't cleared by taking a new handle, it stays true
+    /// after the result is delivered until the flower is reset.
+    pub fn result_is_canceled(&self) -> bool {
+        self.state.cancel_acked.load(Ordering::Acquire)
+            && self.state.error_kind() == ErrorKind::Canceled
+    }
+
+    /// Check if the current flower is active
+    pub fn is_active(&self) -> bool {
+        self.state.flags.activated.load(Ordering::Acquire)
+    }
+
+    /// Check if result value of the flower is ready
+    pub fn result_is_ready(&self) -> bool {
+        self.state.flags.result_ready.load(Ordering::Acquire)
+    }
+
+    // The result or a propagated worker panic is waiting for a poll.
+    pub(crate) fn result_is_pending(&self) -> bool {
+        self.state.result_is_pending()
+    }
+
+    // When a poll stores the "deadline exceeded" error, for the callers parking on the flower.
+    pub(crate) fn hard_deadline_at(&self) -> Option<Instant> {
+        self.state.hard_deadline_at()
+    }
+
+    /// Check if channel value of the flower is present
+    pub fn channel_is_present(&self) -> bool {
+        self.state.activity.channel_present.load(Ordering::Acquire)
+    }
+
+    /// Get the lifecycle state of the flower.
+    ///
+    /// Derived from the flags under the internal mutex so the snapshot is consistent,
+    /// `ResultReady` takes precedence over `ChannelPending`, then `Canceled`, then `Running`.
+    pub fn state(&self) -> FlowerLifecycle {
+        self.state.lifecycle()
+    }
+
+    // Like `state` without the mutex, for the snapshots of a pool.
+    pub(crate) fn state_unlocked(&self) -> FlowerLifecycle {
+        self.state.lifecycle_unlocked()
+    }
+
+    /// Get the progress set by `FlowerHandle::set_progress`, `None` until it's first set.
+    ///
+    /// Lock-free, cheap enough to be called every frame.
+    pub fn progress(&self) -> Option<f32> {
+        match self.state.activity.progress.load(Ordering::Relaxed) {
+            NO_PROGRESS => None,
+            bits => Some(f32::from_bits(bits)),
+        }
+    }
+
+    /// Set the deadline of the flower, `None` to remove it.
+    ///
+    /// `FlowerHandle::should_cancel` returns true once it passed, see also `FlowerBuilder::hard_deadline`.
+    pub fn set_deadline(&self, deadline: Option<Instant>) {
+        let stamp = deadline.map_or(0, |deadline| self.state.stamp(deadline));
+        self.state.deadline.store(stamp, Ordering::Relaxed);
+    }
+
+    /// Set whether the deadline is hard, see `FlowerBuilder::hard_deadline`.
+    pub fn set_hard_deadline(&self, hard_deadline: bool) {
+        self.state
+            .hard_deadline
+            .store(hard_deadline, Ordering::Relaxed);
+    }
+
+    /// Get the current attempt of `spawn_with_retry` starting at 1, 0 otherwise.
+    pub fn attempt(&self) -> u32 {
+        self.state.attempt.load(Ordering::Relaxed)
+    }
+
+    /// Get the instant when the flower was activated.
+    pub fn started_at(&self) -> Option<Instant> {
+        self.state
+            .instant(self.state.started.load(Ordering::Relaxed))
+    }
+
+    /// Get the time since the worker last activated the flower, sent a value,
+    /// reported progress or set the result, lock-free.
+    ///
+    /// Returns `None` if the flower was never activated.
+    pub fn time_since_last_activity(&self) -> Option<Duration> {
+        self.state
+            .instant(self.state.activity.last_activity.load(Ordering::Relaxed))
+            .map(|last| last.elapsed())
+    }
+
+    /// Check if the flower is still running but the worker didn't report anything
+    /// for at least `threshold`, see `time_since_last_activity`.
+    pub fn is_stalled(&self, threshold: Duration) -> bool {
+        self.is_active()
+            && !self.result_is_ready()
+            && self
+                .time_since_last_activity()
+                .is_some_and(|idle| idle >= threshold)
+    }
+
+    /// Get the elapsed time since the flower was activated while running,
+    /// or the total duration once the result is set, lock-free.
+    pub fn elapsed(&self) -> Option<Duration> {
+        let started = self.started_at()?;
+        match self
+            .state
+            .instant(self.state.finished.load(Ordering::Relaxed))
+        {
+            Some(finished) => Some(finished.saturating_duration_since(started)),
+            None => Some(started.elapsed()),
+        }
+    }
+
+    /// Take the most recent channel value, older ones are dropped.
+    pub fn latest(&self) -> Option<SOME> {
+        let value = {
+            let mut mtx = self.state.lock();
+            let value = mtx.pop_back()?;
+            let stale = mtx.len() as u64;
+            mtx.clear();
+            self.state.dropped.fetch_add(stale, Ordering::Relaxed);
+            stat!(self.state, delivered);
+            stat!(self.state, dropped, stale);
+            self.state
+                .activity
+                .channel_present
+                .store(false, Ordering::Release);
+            value
+        };
+        self.wake_sender();
+        Some(value)
+    }
+
+    // Take the front channel value like `poll`, but never the result.
+    pub(crate) fn take_channel(&self) -> Option<SOME> {
+        if !self.state.activity.channel_present.load(Ordering::Acquire) {
+            return None;
+        }
+        let value = {
+            let mut mtx = self.state.lock();
+            let value = mtx.pop_front();
+            self.state
+                .activity
+                .channel_present
+                .store(!mtx.is_empty(), Ordering::Release);
+            value?
+        };
+        stat!(self.state, delivered);
+        self.wake_sender();
+        Some(value)
+    }
+
+    /// Get the number of channel values dropped before being polled,
+    /// overwritten by `FlowerHandle::send_latest`, refused by `send_throttled` or skipped by `latest`.
+    pub fn dropped_count(&self) -> u64 {
+        self.state.dropped.load(Ordering::Relaxed)
+    }
+
+    /// Get a snapshot of the counters of the flower, they're only cleared by `reset_stats`.
+    ///
+    /// # Quick Example:
+    ///
+    ///```
+    ///use flowync::Flower;
+    ///
+    ///let flower: Flower<u32, ()> = Flower::new_buffered(1);
+    ///let handle = flower.handle();
+    ///handle.activate();
+    ///flower.then(|_| (), |_| ());
+    ///handle.send(1).unwrap();
+    ///flower.then(|_| (), |_| ());
+    ///let stats = flower.stats();
+    ///assert_eq!((stats.sends, stats.delivered, stats.empty_polls), (1, 1, 1));
+    ///```
+    #[cfg(feature = "stats")]
+    pub fn stats(&self) -> crate::FlowerStats {
+        self.state.stats.snapshot()
+    }
+
+    /// Clear the counters of `stats`.
+    #[cfg(feature = "stats")]
+    pub fn reset_stats(&self) {
+        self.state.stats.reset();
+    }
+
+    /// Get the number of channel values waiting to be polled.
+    pub fn channel_len(&self) -> usize {
+        self.state.lock().len()
+    }
+
+    /// Inspect the channel value the next `then` or `poll` would take, by reference.
+    ///
+    /// Nothing is consumed, the sender stays blocked until the value is taken by `then`, `poll`
+    /// or another consuming call. `f` runs with the flower locked, so it must not call into the flower.
+    pub fn peek_channel(&self, f: impl FnOnce(Option<&SOME>)) {
+        let mtx = self.state.lock();
+        f(mtx.peek(self.cursor.load(Ordering::Relaxed)))
+    }
+
+    /// Get a `std::sync::mpsc::Receiver` the handles send the channel values to from now on,
+    /// instead of the channel of the flower, without extra threads.
+    ///
+    /// Sending never blocks then and fails once the receiver is dropped. The receiver
+    /// is disconnected once the result is set or another receiver is requested,
+    /// values already in the channel are left to `then` and `poll`.
+    pub fn channel_receiver(&self) -> mpsc::Receiver<SOME> {
+        let (sender, receiver) = mpsc::channel();
+        self.state.bridge.lock().channel = Some(ChannelSender::Std(sender));
+        receiver
+    }
+
+    /// Get a `std::sync::mpsc::Receiver` the results are sent to from now on,
+    /// the flower is deactivated once its result is sent. See `channel_receiver`.
+    ///
+    /// The result isn't kept by the flower then, unless the receiver is dropped.
+    pub fn result_receiver(&self) -> mpsc::Receiver<Result<OK, ERR>> {
+        let (sender, receiver) = mpsc::channel();
+        self.state.bridge.lock().result = Some(ResultSender::Std(sender));
+        receiver
+    }
+
+    /// Get tokio receivers the handles send the channel values and the result to
+    /// from now on, instead of the channel of the flower, see `channel_receiver`.
+    ///
+    /// The channel receiver is bounded by the capacity of a buffered flower, one otherwise,
+    /// `send` and `send_async` wait for room in it so the backpressure still reaches the worker,
+    /// until the flower is canceled. Blocking `send` shouldn't be called from within the runtime,
+    /// use `send_async` there. `recv` returns `None` once the result is set. Once the receivers
+    /// are dropped sending fails instead of blocking and the result is kept by the flower.
+    #[cfg(feature = "tokio")]
+    pub fn to_tokio(
+        &self,
+    ) -> (
+        tokio::sync::mpsc::Receiver<SOME>,
+        tokio::sync::oneshot::Receiver<Result<OK, ERR>>,
+    ) {
+        let capacity = if self.state.buffered {
+            self.state
+                .capacity
+                .clamp(1, tokio::sync::Semaphore::MAX_PERMITS)
+        } else {
+            1
+        };
+        let (sender, receiver) = tokio::sync::mpsc::channel(capacity);
+        let (result_sender, result_receiver) = tokio::sync::oneshot::channel();
+        let mut bridge = self.state.bridge.lock();
+        bridge.channel = Some(ChannelSender::Tokio(sender));
+        bridge.result = Some(ResultSender::Tokio(result_sender));
+        (receiver, result_receiver)
+    }
+
+    /// Get a clone of the channel value the next `then` or `poll` would take, see `peek_channel`.
+    pub fn clone_channel(&self) -> Option<SOME>
+    where
+        SOME: Clone,
+    {
+        let mut value = None;
+        self.peek_channel(|some| value = some.cloned());
+        value
+    }
+
+    /// Process the flower
+    ///
+    /// Where:
+    ///
+    /// c =  channel,  r = result
+    ///
+    /// SOME = type of sender (channel) value
+    ///
+    /// OK = type of Ok value of the Result (Result<'OK', 'ERR'>)
+    ///
+    /// ERR = type of Err value of the Result (Result<'OK', 'ERR'>, String by default)
+    pub fn then(&self, c: impl FnOnce(Option<SOME>), r: impl FnOnce(Result<OK, ERR>)) {
+        match self.poll() {
+            FlowerPoll::Idle => c(None),
+            FlowerPoll::Channel(value) => c(Some(value)),
+            FlowerPoll::Done(result) => r(result),
+        }
+    }
+
+    /// Process the flower like `then`, returns whether the result was given to `r` in this call,
+    /// so a consumer loop can `break` on it without an exit flag.
+    pub fn then_finished(
+        &self,
+        c: impl FnOnce(Option<SOME>),
+        r: impl FnOnce(Result<OK, ERR>),
+    ) -> bool {
+        match self.poll() {
+            FlowerPoll::Idle => c(None),
+            FlowerPoll::Channel(value) => c(Some(value)),
+            FlowerPoll::Done(result) => {
+                r(result);
+                return true;
+            }
+        }
+        false
+    }
+
+    /// Process the flower, same as `then` but every queued channel value is given to `c`
+    /// and the result to `r` if it's ready too, all in one call.
+    ///
+    /// So the last progress value and the result show up in the same frame.
+    /// Values sent while draining are left for the next call, a fast sender can't keep it looping.
+    pub fn then_drain(&self, mut c: impl FnMut(SOME), r: impl FnOnce(Result<OK, ERR>)) {
+        let mut queued = self.channel_len();
+        loop {
+            if queued == 0 && !self.result_is_ready() {
+                return;
+            }
+            match self.poll() {
+                FlowerPoll::Idle => return,
+                FlowerPoll::Channel(value) => {
+                    c(value);
+                    queued = queued.saturating_sub(1);
+                }
+                FlowerPoll::Done(result) => return r(result),
+            }
+        }
+    }
+
+    /// Process the flower with async closures, same as `then`.
+    ///
+    /// The sender is woken up before the returned future of the closure is awaited,
+    /// so the worker isn't blocked by a slow handler.
+    pub async fn then_async<C, CF, R, RF>(&self, c: C, r: R)
+    where
+        C: FnOnce(Option<SOME>) -> CF,
+        CF: Future<Output = ()>,
+        R: FnOnce(Result<OK, ERR>) -> RF,
+        RF: Future<Output = ()>,
+    {
+        match self.poll() {
+            FlowerPoll::Idle => c(None).await,
+            FlowerPoll::Channel(value) => c(Some(value)).await,
+            FlowerPoll::Done(result) => r(result).await,
+        }
+    }
+
+    /// Poll the flower, same as `then` but returns what has been received.
+    ///
+    /// # Quick Example:
+    ///
+    ///```
+    ///use flowync::{Flower, FlowerPoll};
+    ///
+    ///let flower: Flower<u32, String> = Flower::new(1);
+    ///let handle = flower.handle();
+    ///handle.activate();
+    ///handle.ok("Ok".to_string());
+    ///
+    ///match flower.poll() {
+    ///    FlowerPoll::Channel(value) => println!("{}", value),
+    ///    FlowerPoll::Done(result) => assert_eq!(result, Ok("Ok".to_string())),
+    ///    FlowerPoll::Idle => unreachable!(),
+    ///}
+    ///```
+    pub fn poll(&self) -> FlowerPoll<SOME, OK, ERR> {
+        untagged(self.poll_tagged())
+    }
+
+    /// Process the flower with a single closure, called only if a channel value
+    /// or the result is available, unlike `then` which calls `c(None)` when idle.
+    ///
+    /// Returns whether `f` was called.
+    ///
+    ///```
+    ///use flowync::{Flower, FlowerEvent};
+    ///
+    ///let flower: Flower<u32, String> = Flower::new_buffered(1);
+    ///let handle = flower.handle();
+    ///handle.activate();
+    ///assert!(!flower.on_event(|_| unreachable!()));
+    ///
+    ///handle.send(1).unwrap();
+    ///handle.ok("done".into());
+    ///let mut events = Vec::new();
+    ///while flower.on_event(|event| events.push(event)) {}
+    ///assert_eq!(events, [FlowerEvent::Channel(1), FlowerEvent::Done(Ok("done".into()))]);
+    ///```
+    pub fn on_event(&self, f: impl FnOnce(FlowerEvent<SOME, OK, ERR>)) -> bool {
+        match self.poll().into_event() {
+            Some(event) => {
+                f(event);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Poll the flower once like `poll`, channel values come with the tag they were sent with,
+    /// see `FlowerHandle::send_tagged`.
+    pub fn poll_tagged(&self) -> FlowerPoll<(u32, SOME), OK, ERR> {
+        match self.poll_with(true) {
+            Some(poll) => poll,
+            None => FlowerPoll::Idle,
+        }
+    }
+
+    /// Process the flower like `then`, channel values come with the tag they were sent with,
+    /// 0 unless sent by `FlowerHandle::send_tagged`.
+    ///
+    ///```
+    ///use flowync::Flower;
+    ///
+    ///const BYTES: u32 = 1;
+    ///const LOG: u32 = 2;
+    ///
+    ///let flower: Flower<String, ()> = Flower::new_buffered(1);
+    ///let handle = flower.handle();
+    ///handle.activate();
+    ///handle.send_tagged(BYTES, "512".into()).unwrap();
+    ///handle.send_tagged(LOG, "connected".into()).unwrap();
+    ///
+    ///flower.then_tagged(
+    ///    |value| assert_eq!(value, Some((BYTES, "512".to_string()))),
+    ///    |_| (),
+    ///);
+    ///flower.then_tagged(
+    ///    |value| assert_eq!(value, Some((LOG, "connected".to_string()))),
+    ///    |_| (),
+    ///);
+    ///```
+    pub fn then_tagged(
+        &self,
+        c: impl FnOnce(Option<(u32, SOME)>),
+        r: impl FnOnce(Result<OK, ERR>),
+    ) {
+        match self.poll_tagged() {
+            FlowerPoll::Idle => c(None),
+            FlowerPoll::Channel(value) => c(Some(value)),
+            FlowerPoll::Done(result) => r(result),
+        }
+    }
+
+    /// Block the current thread until a channel value or the result is available
+    /// or `timeout` elapsed, then process the flower like `then`.
+    ///
+    /// Woken up by `send`, `ok` and `err`, so it can be mixed with `then` on the same flower.
+    /// Returns whether a value or the result was given to the closures.
+    pub fn then_wait(
+        &self,
+        timeout: Duration,
+        c: impl FnOnce(Option<SOME>),
+        r: impl FnOnce(Result<OK, ERR>),
+    ) -> bool {
+        let deadline = Instant::now() + timeout;
+        let mut mtx = self.state.lock();
+        while !self.has_news(&mtx) {
+            if Instant::now() >= deadline {
+                break;
+            }
+            mtx = self.state.wait_consumer(mtx, Some(deadline));
+        }
+        drop(mtx);
+        match self.poll() {
+            FlowerPoll::Idle => {
+                c(None);
+                return false;
+            }
+            FlowerPoll::Channel(value) => c(Some(value)),
+            FlowerPoll::Done(result) => r(result),
+        }
+        true
+    }
+
+    /// Block the current thread until a channel value or the result is available,
+    /// for consumer threads without a frame loop.
+    ///
+    /// Values taken meanwhile by `then` on another thread are skipped, `recv` keeps waiting
+    /// for the next one. Blocks until the next run once the result of this one is received,
+    /// and doesn't see the values sent to a receiver of `channel_receiver`.
+    pub fn recv(&self) -> FlowerEvent<SOME, OK, ERR> {
+        loop {
+            if let Some(event) = self.recv_until(None) {
+                return event;
+            }
+        }
+    }
+
+    /// Same as `recv` but gives up after `timeout`, returns `None` if nothing arrived in time.
+    pub fn recv_timeout(&self, timeout: Duration) -> Option<FlowerEvent<SOME, OK, ERR>> {
+        self.recv_until(Some(Instant::now() + timeout))
+    }
+
+    // `None` once the deadline passed.
+    fn recv_until(&self, deadline: Option<Instant>) -> Option<FlowerEvent<SOME, OK, ERR>> {
+        loop {
+            if let Some(event) = self.poll().into_event() {
+                return Some(event);
+            }
+            // Checked under the lock, the senders notify under it.
+            let mut mtx = self.state.lock();
+            while !self.has_news(&mtx) {
+                if deadline.is_some_and(|deadline| Instant::now() >= deadline) {
+                    return None;
+                }
+                mtx = self.state.wait_consumer(mtx, deadline);
+            }
+        }
+    }
+
+    // The result is ready and this clone of a broadcast flower didn't observe it yet.
+    fn result_is_unobserved(&self) -> bool {
+        self.state.flags.result_ready.load(Ordering::Acquire)
+            && (self.state.broadcast.is_none()
+                || self.observed.load(Ordering::Relaxed)
+                    != self.state.results.load(Ordering::Relaxed))
+    }
+
+    // A poll of this clone would get something, checked under the channel lock
+    // before waiting, so a broadcast clone which read everything waits too.
+    fn has_news(&self, channel: &Channel<SOME>) -> bool {
+        channel.peek(self.cursor.load(Ordering::Relaxed)).is_some()
+            || self.result_is_unobserved()
+            || self.state.panic_pending.load(Ordering::Acquire)
+    }
+
+    /// Process the flower like `then`, but never blocks the current thread.
+    ///
+    /// Returns `false` without calling anything if a worker holds the lock
+    /// of the flower, e.g. in the middle of `ok`, so it can be retried on the next frame.
+    pub fn try_then(&self, c: impl FnOnce(Option<SOME>), r: impl FnOnce(Result<OK, ERR>)) -> bool {
+        match self.poll_with(false) {
+            Some(FlowerPoll::Idle) => c(None),
+            Some(FlowerPoll::Channel((_, value))) => c(Some(value)),
+            Some(FlowerPoll::Done(result)) => r(result),
+            None => return false,
+        }
+        true
+    }
+
+    // `None` if `lock` gave up, nothing is changed then.
+    // `None` if not `blocking` and a lock is held.
+    fn poll_with(&self, blocking: bool) -> Option<FlowerPoll<(u32, SOME), OK, ERR>> {
+        let poll = self.poll_locked(blocking);
+        match &poll {
+            Some(FlowerPoll::Channel(_)) => stat!(self.state, delivered),
+            Some(FlowerPoll::Idle) => stat!(self.state, empty_polls),
+            _ => (),
+        }
+        poll
+    }
+
+    // The part of `poll_with` not counted in the stats.
+    //
+    // The pending wake is only cleared once a lock is taken, or when `Idle` is returned
+    // without locking, then before reading so anything sent after this poll wakes again.
+    fn poll_locked(&self, blocking: bool) -> Option<FlowerPoll<(u32, SOME), OK, ERR>> {
+        self.state.resume_panic();
+        if let Some(broadcast) = &self.state.broadcast {
+            return self.poll_broadcast(broadcast, blocking);
+        }
+        self.state.enforce_hard_deadline(blocking)?;
+
+        loop {
+            if self.is_idle() {
+                return Some(FlowerPoll::Idle);
+            }
+            if self.state.activity.channel_present.load(Ordering::Acquire) {
+                let mut mtx = self.state.lock_if(blocking)?;
+                self.state.take_wake_pending();
+                if let Some(value) = mtx.pop_front_tagged() {
+                    // Keep the result behind the queued values of the buffered channel.
+                    self.state
+                        .activity
+                        .channel_present
+                        .store(!mtx.is_empty(), Ordering::Release);
+                    drop(mtx);
+                    self.wake_sender();
+                    return Some(FlowerPoll::Channel(value));
+                }
+                self.state
+                    .activity
+                    .channel_present
+                    .store(false, Ordering::Release);
+            }
+            if !self.state.flags.result_ready.load(Ordering::Acquire) {
+                return Some(FlowerPoll::Idle);
+            }
+
+            // The channel lock isn't held, the senders of the next run aren't held up.
+            let mut result = self.state.lock_result_if(blocking)?;
+            self.state.take_wake_pending();
+            if self.state.activity.channel_present.load(Ordering::Acquire) {
+                // Sent before the wake was cleared, it comes first.
+                continue;
+            }
+            // Idle if taken by another clone meanwhile.
+            return Some(
+                self.take_result(&mut result, |_| true)
+                    .map_or(FlowerPoll::Idle, FlowerPoll::Done),
+            );
+        }
+    }
+
+    // Nothing to poll, checked without locking.
+    fn is_idle(&self) -> bool {
+        let idle = || {
+            !self.state.activity.channel_present.load(Ordering::Acquire)
+                && !self.state.flags.result_ready.load(Ordering::Acquire)
+        };
+        if !idle() {
+            return false;
+        }
+        let wake_pending = self.state.take_wake_pending();
+        if idle() {
+            return true;
+        }
+        // Sent before the wake was cleared, it's put back until a lock is taken.
+        if wake_pending {
+            self.state.wake_pending.store(true, Ordering::SeqCst);
+        }
+        false
+    }
+
+    // Deliver the result to this clone if it's ready and `wanted`, shared by the polls
+    // and `take_ok`/`take_err`. A broadcast flower keeps it for the other clones.
+    fn take_result(
+        &self,
+        result: &mut (Option<OK>, Option<ERR>),
+        wanted: fn(&(Option<OK>, Option<ERR>)) -> bool,
+    ) -> Option<Result<OK, ERR>> {
+        if !self.state.flags.result_ready.load(Ordering::Acquire) || !wanted(result) {
+            return None;
+        }
+        if let Some(broadcast) = &self.state.broadcast {
+            let results = self.state.results.load(Ordering::Relaxed);
+            if self.observed.swap(results, Ordering::Relaxed) == results {
+                return None;
+            }
+            // The flower is deactivated once every consumer observed the result.
+            if self.state.observed.fetch_add(1, Ordering::Relaxed) + 1
+                >= self.state.consumers.load(Ordering::Relaxed)
+            {
+                self.state.flags.activated.store(false, Ordering::Release);
+            }
+            return match result {
+                (Some(value), _) => Some(Ok((broadcast.clone_ok)(value))),
+                (None, Some(value)) => Some(Err((broadcast.clone_err)(value))),
+                (None, None) => None,
+            };
+        }
+        self.state
+            .flags
+            .result_ready
+            .store(false, Ordering::Release);
+        self.state.flags.activated.store(false, Ordering::Release);
+        match (result.0.take(), result.1.take()) {
+            (Some(value), _) => Some(Ok(value)),
+            (None, Some(value)) => Some(Err(value)),
+            (None, None) => None,
+        }
+    }
+
+    // The blocking part of `take_ok` and `take_err`, a propagated panic is resumed
+    // and an expired hard deadline stored first, same as `poll`.
+    fn take_result_blocking(
+        &self,
+        wanted: fn(&(Option<OK>, Option<ERR>)) -> bool,
+    ) -> Option<Result<OK, ERR>> {
+        self.state.resume_panic();
+        self.state.enforce_hard_deadline(true);
+        let mut result = self.state.lock_result();
+        self.take_result(&mut result, wanted)
+    }
+
+    /// Try to get the result, channel values are discarded.
+    ///
+    /// Returns `None` if the result isn't ready yet, no closures are involved.
+    /// A clone of a broadcast flower only skips its own values, the other clones still read them.
+    pub fn try_result(&self) -> Option<Result<OK, ERR>> {
+        if self.state.broadcast.is_some() {
+            let mut mtx = self.state.lock();
+            let mut cursor = self.cursor.load(Ordering::Relaxed);
+            let released = mtx.skip(&mut cursor);
+            self.cursor.store(cursor, Ordering::Relaxed);
+            self.state
+                .activity
+                .channel_present
+                .store(!mtx.is_empty(), Ordering::Release);
+            drop(mtx);
+            if released {
+                self.wake_sender();
+            }
+        } else if self.state.activity.channel_present.load(Ordering::Acquire) {
+            let discarded = {
+                let mut mtx = self.state.lock();
+                self.state
+                    .activity
+                    .channel_present
+                    .store(false, Ordering::Release);
+                mtx.take()
+            };
+            if !discarded.is_empty() {
+                self.wake_sender();
+            }
+        }
+
+        match self.poll() {
+            FlowerPoll::Done(result) => Some(result),
+            _ => None,
+        }
+    }
+
+    /// Take the success value of the result if it's ready, the flower is deactivated.
+    ///
+    /// An error result is left untouched and can still be taken e.g. with `take_err`.
+    /// A propagated panic and the hard deadline are handled like in `poll`.
+    pub fn take_ok(&self) -> Option<OK> {
+        self.take_result_blocking(|result| result.0.is_some())?.ok()
+    }
+
+    /// Take the error value of the result if it's ready, the flower is deactivated.
+    ///
+    /// A success result is left untouched and can still be taken e.g. with `take_ok`.
+    /// A propagated panic and the hard deadline are handled like in `poll`.
+    pub fn take_err(&self) -> Option<ERR> {
+        self.take_result_blocking(|result| result.1.is_some())?
+            .err()
+    }
+
+    /// Block the current thread until the result is ready, channel values are discarded.
+    pub fn wait(&self) -> Result<OK, ERR> {
+        let mut mtx = self.state.lock();
+        loop {
+            if self.has_news(&mtx) {
+                drop(mtx);
+                if let Some(result) = self.try_result() {
+                    return result;
+                }
+                mtx = self.state.lock();
+            } else {
+                mtx = self.state.wait_consumer(mtx, None);
+            }
+        }
+    }
+
+    /// Same as `wait` but gives up after `timeout`, returns `None` if the result isn't ready in time.
+    pub fn wait_timeout(&self, timeout: Duration) -> Option<Result<OK, ERR>> {
+        let deadline = Instant::now() + timeout;
+        let mut mtx = self.state.lock();
+        loop {
+            if self.has_news(&mtx) {
+                drop(mtx);
+                if let Some(result) = self.try_result() {
+                    return Some(result);
+                }
+                mtx = self.state.lock();
+            } else {
+                if Instant::now() >= deadline {
+                    return None;
+                }
+                mtx = self.state.wait_consumer(mtx, Some(deadline));
+            }
+        }
+    }
+
+    /// Consume the flower and get the result out.
+    ///
+    /// Only succeeds if the result is ready and no other clone of the flower exists,
+    /// otherwise the flower is given back.
+    pub fn finalize(self) -> Result<Result<OK, ERR>, Self> {
+        if self.state.consumers.load(Ordering::Acquire) != 1 || !self.result_is_ready() {
+            return Err(self);
+        }
+        self.try_result().ok_or(self)
+    }
+
+    /// Get a blocking iterator over the channel values.
+    ///
+    /// The iterator ends once the result is ready, the result is left in the flower
+    /// so it can be taken later e.g. with `try_result`.
+    pub fn iter(&self) -> FlowerIter<'_, SOME, OK, ERR, ID> {
+        FlowerIter { flower: self }
+    }
+
+    /// Get a future of the result, channel values are discarded.
+    ///
+    /// Dropping the future before it's completed leaves the flower untouched.
+    pub fn await_result(&self) -> FlowerFuture<SOME, OK, ERR, ID> {
+        FlowerFuture {
+            flower: self.clone(),
+            key: waker_key(),
+        }
+    }
+
+    /// Same as `poll` but registers the waker to be woken by the flower handle if nothing is available.
+    ///
+    /// Every consumer has its own `key` from `waker_key`, its waker is kept until
+    /// woken or `unregister_waker`.
+    pub(crate) fn poll_or_register(&self, key: usize, waker: &Waker) -> FlowerPoll<SOME, OK, ERR> {
+        loop {
+            match self.poll() {
+                FlowerPoll::Idle => (),
+                event => return event,
+            }
+            let mtx = self.state.lock();
+            if !self.has_news(&mtx) {
+                self.state.register_consumer(key, waker);
+                return FlowerPoll::Idle;
+            }
+        }
+    }
+
+    // Remove the waker of a dropped consumer.
+    pub(crate) fn unregister_waker(&self, key: usize) {
+        self.state.unregister_consumer(key);
+    }
+
+    fn wake_sender(&self) {
+        self.state.wake_sender();
+    }
+
+    fn poll_broadcast(
+        &self,
+        broadcast: &Broadcast<SOME, OK, ERR>,
+        blocking: bool,
+    ) -> Option<FlowerPoll<(u32, SOME), OK, ERR>> {
+        let mut mtx = self.state.lock_if(blocking)?;
+        // Cleared under the lock the senders push with.
+        self.state.take_wake_pending();
+        let mut cursor = self.cursor.load(Ordering::Relaxed);
+        if let Some((value, released)) = mtx.read(&mut cursor, broadcast.clone_some) {
+            self.cursor.store(cursor, Ordering::Relaxed);
+            self.state
+                .activity
+                .channel_present
+                .store(!mtx.is_empty(), Ordering::Release);
+            drop(mtx);
+            if released {
+                self.wake_sender();
+            }
+            return Some(FlowerPoll::Channel(value));
+        }
+
+        // Locked before observing it, so a failed `try_lock` doesn't skip the result.
+        let mut result = self.state.lock_result_if(blocking)?;
+        drop(mtx);
+        Some(
+            self.take_result(&mut result, |_| true)
+                .map_or(FlowerPoll::Idle, FlowerPoll::Done),
+        )
+    }
+}
+
+/// A short line for logs, e.g. `flower#42 "thumbnailer" [running]`, see `state`.
+impl<SOME, OK, ERR, ID> Display for Flower<SOME, OK, ERR, ID>
+where
+    SOME: Send,
+    OK: Send,
+    ERR: Send,
+    ID: Send + Sync,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        self.state.display(f)
+    }
+}
+
+impl<SOME, OK, ERR, ID> Debug for Flower<SOME, OK, ERR, ID>
+where
+    SOME: Debug + Send,
+    OK: Debug + Send,
+    ERR: Debug + Send,
+    ID: Debug + Send + Sync,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Flower")
+            .field("state", &self.state)
+            .finish()
+    }
+}
+
+impl<SOME, OK, ERR, ID> Clone for Flower<SOME, OK, ERR, ID>
+where
+    SOME: Send,
+    OK: Send,
+    ERR: Send,
+    ID: Send + Sync,
+{
+    fn clone(&self) -> Self {
+        let cursor = if self.state.broadcast.is_some() {
+            self.state.lock().subscribe()
+        } else {
+            0
+        };
+        self.state.consumers.fetch_add(1, Ordering::Relaxed);
+        Self {
+            state: Clone::clone(&self.state),
+            cursor: AtomicU64::new(cursor),
+            observed: AtomicU64::new(self.observed.load(Ordering::Relaxed)),
+        }
+    }
+}
+
+// Flowers given the same id by accident are still told apart by their state.
+impl<SOME, OK, ERR, ID> PartialEq for Flower<SOME, OK, ERR, ID>
+where
+    SOME: Send,
+    OK: Send,
+    ERR: Send,
+    ID: PartialEq + Send + Sync,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.state.id == other.state.id && Arc::ptr_eq(&self.state, &other.state)
+    }
+}
+
+impl<SOME, OK, ERR, ID> Eq for Flower<SOME, OK, ERR, ID>
+where
+    SOME: Send,
+    OK: Send,
+    ERR: Send,
+    ID: Eq + Send + Sync,
+{
+}
+
+impl<SOME, OK, ERR, ID> Hash for Flower<SOME, OK, ERR, ID>
+where
+    SOME: Send,
+    OK: Send,
+    ERR: Send,
+    ID: Hash + Send + Sync,
+{
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.state.id.hash(state);
+        Arc::as_ptr(&self.state).hash(state);
+    }
+}
+
+impl<SOME, OK, ERR, ID> Drop for Flower<SOME, OK, ERR, ID>
+where
+    SOME: Send,
+    OK: Send,
+    ERR: Send,
+    ID: Send + Sync,
+{
+    fn drop(&mut self) {
+        if self.state.broadcast.is_some() {
+            let released = self
+                .state
+                .lock()
+                .unsubscribe(self.cursor.load(Ordering::Relaxed));
+            if released {
+                self.wake_sender();
+            }
+        }
+        let last = self.state.consumers.fetch_sub(1, Ordering::AcqRel) == 1;
+        if last && self.state.cancel_on_drop.load(Ordering::Relaxed) {
+            self.cancel();
+        }
+        if last {
+            {
+                // Set under the lock, so a sender can't miss the wake up.
+                let _mtx = self.state.lock();
+                self.state.consumer_gone.store(true, Ordering::Release);
+            }
+            self.wake_sender();
+            self.state.pause_cvar.notify_all();
+        }
+        if thread::panicking() {
+            self.state.flags.activated.store(false, Ordering::Release)
+        }
+    }
+}
+
+/// A handle for the Flower
+pub struct FlowerHandle<SOME, OK, ERR = String, ID = usize>
+where
+    SOME: Send,
+    OK: Send,
+    ERR: Send,
+    ID: Send + Sync,
+{
+    state: Arc<FlowerState<SOME, OK, ERR, ID>>,
+    generation: u64,
+}
+
+impl<SOME, OK, ERR, ID> FlowerHandle<SOME, OK, ERR, ID>
+where
+    SOME: Send,
+    OK: Send,
+    ERR: Send,
+    ID: Send + Sync,
+{
+    /// Get ID of the flower.
+    pub fn id(&self) -> &ID {
+        &self.state.id
+    }
+
+    /// Check if the handle belongs to an older run of the flower, see `Flower::generation`.
+    pub fn is_stale(&self) -> bool {
+        self.generation != self.state.generation.load(Ordering::Acquire)
+    }
+
+    // Results of a stale handle are discarded.
+    fn set_result(&self, result: Result<OK, ERR>, kind: ErrorKind) -> bool {
+        !self.is_stale() && self.state.set_result(result, kind)
+    }
+
+    fn fail(&self, kind: ErrorKind, message: String) {
+        if !self.is_stale() {
+            self.state.fail(kind, message);
+        }
+    }
+
+    fn stale_error(&self, value: SOME) -> SendError<SOME> {
+        SendError {
+            value,
+            reason: SendErrorReason::Canceled,
+        }
+    }
+
+    /// Activate current flower
+    ///
+    /// Also (re)starts the clock of `Flower::elapsed`.
+    ///
+    /// Does nothing if the handle is stale.
+    pub fn activate(&self) {
+        if self.is_stale() {
+            return;
+        }
+        self.state.result_set.store(false, Ordering::Release);
+        self.state.finished.store(0, Ordering::Relaxed);
+        let now = Instant::now();
+        self.state
+            .started
+            .store(self.state.stamp(now), Ordering::Relaxed);
+        self.state
+            .activity
+            .last_activity
+            .store(self.state.stamp(now), Ordering::Relaxed);
+        if let Some(deadline) = self.state.deadline_after {
+            self.state
+                .deadline
+                .store(self.state.stamp(now + deadline), Ordering::Relaxed);
+        }
+        self.state.flags.activated.store(true, Ordering::Release);
+        flower_event!(self.state, DEBUG, "activate");
+    }
+
+    /// Check if the current flower is active
+    pub fn is_active(&self) -> bool {
+        self.state.flags.activated.load(Ordering::Acquire)
+    }
+
+    /// Check if the current flower should be canceled
+    ///
+    /// Also true once the deadline of the flower passed or the handle is stale.
+    pub fn should_cancel(&self) -> bool {
+        self.state.flags.canceled.load(Ordering::Acquire)
+            || self.state.deadline_passed()
+            || self.is_stale()
+    }
+
+    /// Check if the current flower should be paused
+    pub fn should_pause(&self) -> bool {
+        self.state.paused.load(Ordering::Acquire)
+    }
+
+    /// Check if every clone of the flower is dropped, nothing will poll the channel
+    /// or observe the result anymore so the worker can stop.
+    pub fn consumer_gone(&self) -> bool {
+        self.state.consumer_gone.load(Ordering::Acquire)
+    }
+
+    /// Send the value like `send`, then block the current thread until the consumer calls
+    /// `Flower::advance`, taking the value isn't enough.
+    ///
+    /// Returns `false` if the flower is canceled or dropped before that.
+    pub fn checkpoint(&self, value: SOME) -> bool {
+        if self.send(value).is_err() {
+            return false;
+        }
+        let mut mtx = self.state.lock();
+        while self.state.advances.load(Ordering::Relaxed) == 0 {
+            if self.state.senders_released() {
+                return false;
+            }
+            mtx = self.state.pause_cvar.wait(mtx);
+        }
+        self.state.advances.fetch_sub(1, Ordering::Relaxed);
+        true
+    }
+
+    /// Block the current thread while the flower is paused,
+    /// returns once it's resumed, canceled or the flower is dropped.
+    pub fn wait_if_paused(&self) {
+        let mut mtx = self.state.lock();
+        while self.state.paused.load(Ordering::Acquire) && !self.state.senders_released() {
+            mtx = self.state.pause_cvar.wait(mtx);
+        }
+    }
+
+    /// Get the current attempt of `Flower::spawn_with_retry` starting at 1, 0 otherwise.
+    pub fn attempt(&self) -> u32 {
+        self.state.attempt.load(Ordering::Relaxed)
+    }
+
+    /// Run `f` and catch its panic, which is reported as the error "worker panicked: {message}"
+    /// instead of the generic one from dropping a handle while panicking.
+    ///
+    /// Returns `None` if `f` panicked.
+    pub(crate) fn catch_panic<R>(&self, f: impl FnOnce() -> R) -> Option<R> {
+        let state = Arc::as_ptr(&self.state) as *const () as usize;
+        let catching = CATCHING.with(|c| c.replace(state));
+        #[cfg(feature = "panic-info")]
+        let result = crate::panic_info::capture(|| panic::catch_unwind(AssertUnwindSafe(f)));
+        #[cfg(not(feature = "panic-info"))]
+        let result = panic::catch_unwind(AssertUnwindSafe(f));
+        CATCHING.with(|c| c.set(catching));
+        match result {
+            Ok(value) => Some(value),
+            Err(payload) => {
+                self.err_panic(payload);
+                None
+            }
+        }
+    }
+
+    /// Set the error for a panic caught with [`std::panic::catch_unwind`],
+    /// formatted like "worker panicked: message".
+    ///
+    /// With the `panic-info` feature the location of a panic caught by the spawn helpers
+    /// follows the message, and the backtrace if enabled with `RUST_BACKTRACE`.
+    ///
+    /// Handled according to the `PanicPolicy` of the flower.
+    pub fn err_panic(&self, payload: Box<dyn Any + Send>) {
+        if self.state.panic_policy != PanicPolicy::Report {
+            if !self.is_stale() {
+                self.state.panicked(payload);
+            }
+            return;
+        }
+        #[allow(unused_mut)]
+        let mut message = format!("worker panicked: {}", panic_message(payload));
+        #[cfg(feature = "panic-info")]
+        if let Some(location) = crate::panic_info::take() {
+            message.push_str(&location);
+        }
+        self.fail(ErrorKind::Panicked, message);
+    }
+
+    /// Sleep for the duration, returns false as soon as the flower should be canceled.
+    fn sleep_unless_canceled(&self, duration: Duration) -> bool {
+        let deadline = Instant::now() + duration;
+        let mut mtx = self.state.lock();
+        while !self.should_cancel() {
+            let now = Instant::now();
+            if now >= deadline {
+                return true;
+            }
+            mtx = self.state.pause_cvar.wait_timeout(mtx, deadline - now);
+        }
+        false
+    }
+
+    /// Set the current progress without going through the channel, lock-free and non-blocking.
+    ///
+    /// The value is clamped into `0.0..=1.0`, NaN is stored as `0.0`.
+    pub fn set_progress(&self, p: f32) {
+        let p = if p.is_nan() { 0.0 } else { p.clamp(0.0, 1.0) };
+        self.state
+            .activity
+            .progress
+            .store(p.to_bits(), Ordering::Relaxed);
+        self.state.touch();
+    }
+
+    /// Wait asynchronously until the flower is canceled,
+    /// resolves immediately if it's already canceled.
+    pub fn canceled(&self) -> impl Future<Output = ()> + '_ {
+        CancelSuspender { state: &self.state }
+    }
+
+    /// Set a callback invoked once from `Flower::cancel`, e.g. to close a socket the worker
+    /// is blocked on, it's invoked right away if the flower is already canceled.
+    ///
+    /// The callback runs on the thread of the consumer calling `cancel`,
+    /// it must be quick and must not block. It replaces any previous one
+    /// and is dropped without being called on `Flower::reset`.
+    pub fn set_on_cancel(&self, f: impl FnOnce() + Send + 'static) {
+        let mut on_cancel = self.state.on_cancel.lock();
+        // Checked under the lock, `cancel` sets the flag before taking the callback.
+        if self.state.flags.canceled.load(Ordering::Acquire) || self.is_stale() {
+            drop(on_cancel);
+            f();
+        } else {
+            *on_cancel = Some(Box::new(f));
+        }
+    }
+
+    /// Remove the `on_cancel` callback.
+    pub fn clear_on_cancel(&self) {
+        self.state.on_cancel.lock().take();
+    }
+
+    /// Get the reason of the cancelation if any, see `Flower::cancel_with`.
+    pub fn cancel_reason(&self) -> Option<String> {
+        self.state.cancel_reason.lock().clone()
+    }
+
+    /// Acknowledge the cancelation, the result is set to an error
+    /// "the flower with id: N canceled" followed by the reason if any.
+    ///
+    /// Any later `ok` or `err` won't overwrite the acknowledged cancelation.
+    pub fn cancel_ack(&self) {
+        let msg = match self.cancel_reason() {
+            Some(reason) => format!(
+                "the flower with id: {} canceled: {}",
+                self.state.id_string(),
+                reason
+            ),
+            None => format!("the flower with id: {} canceled", self.state.id_string()),
+        };
+        self.fail(ErrorKind::Canceled, msg);
+    }
+
+    /// Send current progress value
+    ///
+    /// Will block the current thread until the value is polled, unless the channel is buffered.
+    ///
+    /// Values of concurrent senders are delivered in FIFO order, each poll releases only
+    /// the sender of the polled value. Blocked senders return once the flower is canceled
+    /// or dropped (see `consumer_gone`).
+    ///
+    /// Returns a `SendError` holding the value if it couldn't be delivered, its `reason`
+    /// tells whether the flower was canceled or dropped.
+    ///
+    /// On wasm32 it never blocks, the value overwrites any value which isn't polled yet
+    /// like `send_latest`, a full buffered channel drops its oldest value.
+    pub fn send(&self, _value: SOME) -> Result<(), SendError<SOME>> {
+        self.send_tagged(0, _value)
+    }
+
+    /// Send current progress value like `send`, along with a tag the consumer gets
+    /// from `Flower::then_tagged`, e.g. to tell downloaded bytes from log lines.
+    ///
+    /// The tag is stored next to the value, values sent by the other sends have tag 0.
+    /// Receivers from `channel_receiver` or `to_tokio` only get the value.
+    pub fn send_tagged(&self, tag: u32, _value: SOME) -> Result<(), SendError<SOME>> {
+        if self.is_stale() {
+            return Err(self.stale_error(_value));
+        }
+        if !self.state.has_channel() {
+            return Err(self.state.send_error(_value));
+        }
+        flower_event!(self.state, TRACE, value = %self.state.traced(&_value), "send");
+        stat!(self.state, sends);
+        self.state.touch();
+        if let Some(sender) = self.state.bridged() {
+            return self.state.forward(&sender, _value, None);
+        }
+        if cfg!(target_arch = "wasm32") {
+            self.send_nowait(tag, _value);
+            return Ok(());
+        }
+        let notifier = self.state.notifier();
+        let mut mtx = self.state.lock();
+        if self.state.buffered {
+            if self.state.senders_released() {
+                return Err(self.state.send_error(_value));
+            }
+            let mut value = _value;
+            while let Some(rejected) = self.enqueue(&mut mtx, tag, value) {
+                if self.state.senders_released() {
+                    return Err(self.state.send_error(rejected));
+                }
+                value = rejected;
+                mtx = self.state.wait_sender(mtx);
+            }
+            drop(mtx);
+            if let Some(n) = notifier {
+                n.notify();
+            }
+            return Ok(());
+        }
+        let ticket = mtx.push_tagged(tag, _value);
+        self.state
+            .activity
+            .channel_present
+            .store(true, Ordering::Release);
+        self.state.notify_consumer();
+        if let Some(n) = notifier {
+            drop(mtx);
+            n.notify();
+            mtx = self.state.lock();
+        }
+        // Each poll takes one value, only the sender of that value is released.
+        // Wait on the ticket rather than `channel_present`, condvars may wake up spuriously.
+        while mtx.contains(ticket) && !self.state.senders_released() {
+            mtx = self.state.wait_sender(mtx);
+        }
+        match self.state.withdraw(&mut mtx, ticket) {
+            Some(value) => Err(self.state.send_error(value)),
+            None => Ok(()),
+        }
+    }
+
+    /// Send current progress value like `send`, unless another value was accepted
+    /// less than `min_interval` ago by any handle of the flower, the value is dropped then.
+    ///
+    /// Returns whether the value was sent, use `send` to force a final value through.
+    pub fn send_throttled(&self, value: SOME, min_interval: Duration) -> bool {
+        self.state.touch();
+        let now = self.state.now();
+        let last = self.state.last_throttled.load(Ordering::Relaxed);
+        if last != 0 && now.saturating_sub(last) < min_interval.as_nanos() as u64 {
+            self.state.dropped.fetch_add(1, Ordering::Relaxed);
+            stat!(self.state, sends);
+            stat!(self.state, dropped);
+            return false;
+        }
+        // Only one of the racing handles gets the slot.
+        if self
+            .state
+            .last_throttled
+            .compare_exchange(last, now, Ordering::Relaxed, Ordering::Relaxed)
+            .is_err()
+        {
+            self.state.dropped.fetch_add(1, Ordering::Relaxed);
+            stat!(self.state, sends);
+            stat!(self.state, dropped);
+            return false;
+        }
+        self.send(value).is_ok()
+    }
+
+    /// Send current progress value overwriting any value which isn't polled yet, never blocks.
+    ///
+    /// Senders blocked on an overwritten value are woken up and return as if it was polled.
+    pub fn send_latest(&self, _value: SOME) {
+        if self.is_stale() || !self.state.has_channel() {
+            return;
+        }
+        stat!(self.state, sends);
+        self.state.touch();
+        self.replace_latest(0, _value);
+    }
+
+    fn replace_latest(&self, tag: u32, value: SOME) {
+        if let Some(sender) = self.state.bridged() {
+            return self.state.forward_latest(&sender, value);
+        }
+        let notifier = self.state.notifier();
+        {
+            let mut mtx = self.state.lock();
+            let stale = mtx.len() as u64;
+            mtx.clear();
+            mtx.push_tagged(tag, value);
+            self.state.dropped.fetch_add(stale, Ordering::Relaxed);
+            stat!(self.state, dropped, stale);
+            self.state
+                .activity
+                .channel_present
+                .store(true, Ordering::Release);
+            self.state.notify_consumer();
+            if stale > 0 {
+                self.state.wake_sender();
+            }
+        }
+        if let Some(n) = notifier {
+            n.notify();
+        }
+    }
+
+    /// Send current progress value, wait at most `timeout` for it to be polled.
+    ///
+    /// The value is given back inside the error if the flower doesn't poll it in time
+    /// or is canceled in the meantime, a buffered channel only waits for a free slot.
+    ///
+    /// On wasm32 it never waits and always succeeds, same as `send`.
+    pub fn send_timeout(
+        &self,
+        value: SOME,
+        timeout: Duration,
+    ) -> Result<(), SendTimeoutError<SOME>> {
+        if self.is_stale() || !self.state.has_channel() {
+            return Err(SendTimeoutError(value));
+        }
+        flower_event!(self.state, TRACE, value = %self.state.traced(&value), "send_timeout");
+        stat!(self.state, sends);
+        self.state.touch();
+        if let Some(sender) = self.state.bridged() {
+            return self
+                .state
+                .forward(&sender, value, Some(Instant::now() + timeout))
+                .map_err(|e| SendTimeoutError(e.into_inner()));
+        }
+        if cfg!(target_arch = "wasm32") {
+            self.send_nowait(0, value);
+            return Ok(());
+        }
+        let deadline = Instant::now() + timeout;
+        let notifier = self.state.notifier();
+        let mut mtx = self.state.lock();
+        if self.state.buffered {
+            if self.state.senders_released() {
+                return Err(SendTimeoutError(value));
+            }
+            let mut value = value;
+            while let Some(rejected) = self.enqueue(&mut mtx, 0, value) {
+                let now = Instant::now();
+                if now >= deadline || self.state.senders_released() {
+                    flower_event!(self.state, DEBUG, "send_timeout rejected");
+                    return Err(SendTimeoutError(rejected));
+                }
+                value = rejected;
+                mtx = self.state.wait_sender_timeout(mtx, deadline - now);
+            }
+            drop(mtx);
+            if let Some(n) = notifier {
+                n.notify();
+            }
+            return Ok(());
+        }
+        let ticket = mtx.push_back(value);
+        self.state
+            .activity
+            .channel_present
+            .store(true, Ordering::Release);
+        self.state.notify_consumer();
+        if let Some(n) = notifier {
+            drop(mtx);
+            n.notify();
+            mtx = self.state.lock();
+        }
+        while mtx.contains(ticket) && !self.state.senders_released() {
+            let now = Instant::now();
+            if now >= deadline {
+                break;
+            }
+            mtx = self.state.wait_sender_timeout(mtx, deadline - now);
+        }
+        match self.state.withdraw(&mut mtx, ticket) {
+            Some(value) => {
+                flower_event!(self.state, DEBUG, "send_timeout rejected");
+                Err(SendTimeoutError(value))
+            }
+            None => Ok(()),
+        }
+    }
+
+    /// Send current progress value asynchronously.
+    ///
+    /// Will suspend until the value is polled, unless the channel is buffered.
+    /// Returns a `SendError` once the flower is canceled or dropped, same as `send`.
+    ///
+    /// Dropping the future before it completes takes the value back if it isn't polled yet.
+    pub async fn send_async(&self, _value: SOME) -> Result<(), SendError<SOME>> {
+        if self.is_stale() {
+            return Err(self.stale_error(_value));
+        }
+        if !self.state.has_channel() {
+            return Err(self.state.send_error(_value));
+        }
+        flower_event!(self.state, TRACE, value = %self.state.traced(&_value), "send_async");
+        stat!(self.state, sends);
+        self.state.touch();
+        if let Some(sender) = self.state.bridged() {
+            return self.state.forward_async(&sender, _value).await;
+        }
+        let notifier = self.state.notifier();
+        if self.state.buffered {
+            let mut value = _value;
+            loop {
+                {
+                    let mut mtx = self.state.lock();
+                    if self.state.senders_released() {
+                        return Err(self.state.send_error(value));
+                    }
+                    match self.enqueue(&mut mtx, 0, value) {
+                        Some(rejected) if self.state.senders_released() => {
+                            return Err(self.state.send_error(rejected))
+                        }
+                        Some(rejected) => value = rejected,
+                        None => {
+                            drop(mtx);
+                            if let Some(n) = notifier {
+                                n.notify();
+                            }
+                            return Ok(());
+                        }
+                    }
+                }
+                #[cfg(feature = "stats")]
+                let since = Instant::now();
+                AsyncSuspender::new(&self.state, None).await;
+                #[cfg(feature = "stats")]
+                self.state.stats.blocked_since(since);
+            }
+        }
+        let ticket = {
+            let mut mtx = self.state.lock();
+            let ticket = mtx.push_back(_value);
+            self.state
+                .activity
+                .channel_present
+                .store(true, Ordering::Release);
+            self.state.notify_consumer();
+            ticket
+        };
+        if let Some(n) = notifier {
+            n.notify();
+        }
+        #[cfg(feature = "stats")]
+        let since = Instant::now();
+        AsyncSuspender::new(&self.state, Some(ticket)).await;
+        #[cfg(feature = "stats")]
+        self.state.stats.blocked_since(since);
+        match self.state.withdraw(&mut self.state.lock(), ticket) {
+            Some(value) => Err(self.state.send_error(value)),
+            None => Ok(()),
+        }
+    }
+
+    // There are no threads to block on wasm32.
+    fn send_nowait(&self, tag: u32, value: SOME) {
+        if !self.state.buffered {
+            return self.replace_latest(tag, value);
+        }
+        let notifier = self.state.notifier();
+        {
+            let mut mtx = self.state.lock();
+            if let Some(value) = self.enqueue(&mut mtx, tag, value) {
+                mtx.pop_front();
+                stat!(self.state, dropped);
+                self.enqueue(&mut mtx, tag, value);
+            }
+        }
+        if let Some(n) = notifier {
+            n.notify();
+        }
+    }
+
+    /// Push the value into the buffered channel according to the backpressure policy,
+    /// the value is given back if the channel is full and the sender should wait.
+    fn enqueue(&self, channel: &mut Channel<SOME>, tag: u32, value: SOME) -> Option<SOME> {
+        if channel.len() >= self.state.capacity {
+            match self.state.backpressure {
+                Backpressure::Block => return Some(value),
+                Backpressure::DropOldest => {
+                    channel.pop_front();
+                    channel.push_tagged(tag, value);
+                    stat!(self.state, dropped);
+                }
+                Backpressure::DropNewest => {
+                    // Nothing new for the consumer.
+                    stat!(self.state, dropped);
+                    return None;
+                }
+            }
+        } else {
+            channel.push_tagged(tag, value);
+        }
+        self.state
+            .activity
+            .channel_present
+            .store(true, Ordering::Release);
+        self.state.notify_consumer();
+        None
+    }
+
+    /// Contains the success value for the result.
+    ///
+    /// Only the first result since the activation or a handle was taken is delivered, see `try_ok`.
+    pub fn ok(&self, _value: OK) {
+        self.set_result(Ok(_value), ErrorKind::Custom);
+    }
+
+    /// Contains the error value for the result.
+    ///
+    /// Only the first result since the activation or a handle was taken is delivered, see `try_err`.
+    pub fn err(&self, _value: impl Into<ERR>) {
+        self.set_result(Err(_value.into()), ErrorKind::Custom);
+    }
+
+    /// Same as `ok`, but returns `ResultAlreadySet` if the value is discarded because
+    /// a result was already submitted since the activation or a handle was taken,
+    /// or the handle is stale.
+    pub fn try_ok(&self, value: OK) -> Result<(), ResultAlreadySet> {
+        self.set_result(Ok(value), ErrorKind::Custom)
+            .then_some(())
+            .ok_or(ResultAlreadySet)
+    }
+
+    /// Same as `err`, but returns `ResultAlreadySet` if the value is discarded, see `try_ok`.
+    pub fn try_err(&self, value: impl Into<ERR>) -> Result<(), ResultAlreadySet> {
+        self.set_result(Err(value.into()), ErrorKind::Custom)
+            .then_some(())
+            .ok_or(ResultAlreadySet)
+    }
+
+    /// Contains the error value for the result from an error and its `source()` chain,
+    /// formatted like "failed to parse: invalid digit".
+    ///
+    /// A `Box<dyn Error>` can be passed with `handle.err_from(&*e)`.
+    pub fn err_from(&self, e: impl Error) {
+        let mut message = e.to_string();
+        let mut source = e.source();
+        while let Some(e) = source {
+            message.push_str(": ");
+            message.push_str(&e.to_string());
+            source = e.source();
+        }
+        self.fail(ErrorKind::Custom, message);
+    }
+
+    /// Dispatch the result to `ok` or `err_from`.
+    pub fn result<E: Error>(&self, result: Result<OK, E>) {
+        match result {
+            Ok(value) => self.ok(value),
+            Err(e) => self.err_from(e),
+        }
+    }
+
+    /// Contains the error value for the result, formatted like "context: err".
+    pub fn err_with_context(&self, context: impl Display, err: impl Display) {
+        let message = format!("{}: {}", context, err);
+        self.fail(ErrorKind::Custom, message);
+    }
+}
+
+impl<SOME, OK, ERR, ID> ContextExt for FlowerHandle<SOME, OK, ERR, ID>
+where
+    SOME: Send,
+    OK: Send,
+    ERR: Send,
+    ID: Send + Sync,
+{
+    fn fail_ctx(&self, context: impl Display, err: impl Display) -> Failed {
+        self.err_with_context(context, err);
+        Failed
+    }
+}
+
+/// A weak reference to a flower, see [`Flower::downgrade`].
+pub struct WeakFlower<SOME, OK, ERR = String, ID = usize>
+where
+    SOME: Send,
+    OK: Send,
+    ERR: Send,
+    ID: Send + Sync,
+{
+    state: Weak<FlowerState<SOME, OK, ERR, ID>>,
+}
+
+impl<SOME, OK, ERR, ID> WeakFlower<SOME, OK, ERR, ID>
+where
+    SOME: Send,
+    OK: Send,
+    ERR: Send,
+    ID: Send + Sync,
+{
+    /// Get the flower back, `None` once every `Flower` of it is dropped,
+    /// even if its handles are still alive.
+    ///
+    /// A broadcast flower gets a new subscriber, like a clone of it.
+    pub fn upgrade(&self) -> Option<Flower<SOME, OK, ERR, ID>> {
+        let state = self.state.upgrade()?;
+        // Never bring a flower back whose last consumer is gone.
+        state
+            .consumers
+            .fetch_update(Ordering::AcqRel, Ordering::Acquire, |n| {
+                (n > 0).then_some(n + 1)
+            })
+            .ok()?;
+        let cursor = if state.broadcast.is_some() {
+            state.lock().subscribe()
+        } else {
+            0
+        };
+        Some(Flower {
+            state,
+            cursor: AtomicU64::new(cursor),
+            observed: AtomicU64::new(0),
+        })
+    }
+
+    /// Check if any `Flower` of it is still alive.
+    pub fn is_alive(&self) -> bool {
+        self.state
+            .upgrade()
+            .is_some_and(|state| state.consumers.load(Ordering::Acquire) > 0)
+    }
+}
+
+impl<SOME, OK, ERR, ID> Clone for WeakFlower<SOME, OK, ERR, ID>
+where
+    SOME: Send,
+    OK: Send,
+    ERR: Send,
+    ID: Send + Sync,
+{
+    fn clone(&self) -> Self {
+        Self {
+            state: Weak::clone(&self.state),
+        }
+    }
+}
+
+impl<SOME, OK, ERR, ID> Debug for WeakFlower<SOME, OK, ERR, ID>
+where
+    SOME: Send,
+    OK: Send,
+    ERR: Send,
+    ID: Send + Sync,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("WeakFlower")
+            .field("alive", &self.is_alive())
+            .finish()
+    }
+}
+
+/// A blocking iterator over the flower channel values, see [`Flower::iter`].
+pub struct FlowerIter<'a, SOME, OK, ERR = String, ID = usize>
+where
+    SOME: Send,
+    OK: Send,
+    ERR: Send,
+    ID: Send + Sync,
+{
+    flower: &'a Flower<SOME, OK, ERR, ID>,
+}
+
+impl<'a, SOME, OK, ERR, ID> Iterator for FlowerIter<'a, SOME, OK, ERR, ID>
+where
+    SOME: Send,
+    OK: Send,
+    ERR: Send,
+    ID: Send + Sync,
+{
+    type Item = SOME;
+    fn next(&mut self) -> Option<Self::Item> {
+        let state = &self.flower.state;
+        let mut mtx = state.lock();
+        loop {
+            if let Some(value) = mtx.pop_front() {
+                state
+                    .activity
+                    .channel_present
+                    .store(!mtx.is_empty(), Ordering::Release);
+                drop(mtx);
+                self.flower.wake_sender();
+                return Some(value);
+            } else if state.result_is_pending() {
+                return None;
+            }
+            mtx = state.wait_consumer(mtx, None);
+        }
+    }
+}
+
+impl<'a, SOME, OK, ERR, ID> Debug for FlowerIter<'a, SOME, OK, ERR, ID>
+where
+    SOME: Debug + Send,
+    OK: Debug + Send,
+    ERR: Debug + Send,
+    ID: Debug + Send + Sync,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("FlowerIter")
+            .field("flower", &self.flower)
+            .finish()
+    }
+}
+
+/// A future of the flower result, see [`Flower::await_result`].
+pub struct FlowerFuture<SOME, OK, ERR = String, ID = usize>
+where
+    SOME: Send,
+    OK: Send,
+    ERR: Send,
+    ID: Send + Sync,
+{
+    flower: Flower<SOME, OK, ERR, ID>,
+    key: usize,
+}
+
+impl<SOME, OK, ERR, ID> Future for FlowerFuture<SOME, OK, ERR, ID>
+where
+    SOME: Send,
+    OK: Send,
+    ERR: Send,
+    ID: Send + Sync,
+{
+    type Output = Result<OK, ERR>;
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let state = &self.flower.state;
+        loop {
+            if let Some(result) = self.flower.try_result() {
+                return Poll::Ready(result);
+            }
+            let mtx = state.lock();
+            if !self.flower.has_news(&mtx) {
+                state.register_consumer(self.key, cx.waker());
+                return Poll::Pending;
+            }
+        }
+    }
+}
+
+impl<SOME, OK, ERR, ID> IntoFuture for Flower<SOME, OK, ERR, ID>
+where
+    SOME: Send,
+    OK: Send,
+    ERR: Send,
+    ID: Send + Sync,
+{
+    type Output = Result<OK, ERR>;
+    type IntoFuture = FlowerFuture<SOME, OK, ERR, ID>;
+    fn into_future(self) -> Self::IntoFuture {
+        FlowerFuture {
+            flower: self,
+            key: waker_key(),
+        }
+    }
+}
+
+impl<SOME, OK, ERR, ID> Drop for FlowerFuture<SOME, OK, ERR, ID>
+where
+    SOME: Send,
+    OK: Send,
+    ERR: Send,
+    ID: Send + Sync,
+{
+    fn drop(&mut self) {
+        self.flower.unregister_waker(self.key);
+    }
+}
+
+impl<SOME, OK, ERR, ID> Debug for FlowerFuture<SOME, OK, ERR, ID>
+where
+    SOME: Debug + Send,
+    OK: Debug + Send,
+    ERR: Debug + Send,
+    ID: Debug + Send + Sync,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("FlowerFuture")
+            .field("flower", &self.flower)
+            .finish()
+    }
+}
+
+struct CancelSuspender<'a, SOME, OK, ERR, ID>
+where
+    SOME: Send,
+    OK: Send,
+    ERR: Send,
+    ID: Send + Sync,
+{
+    state: &'a FlowerState<SOME, OK, ERR, ID>,
+}
+
+impl<'a, SOME, OK, ERR, ID> Future for CancelSuspender<'a, SOME, OK, ERR, ID>
+where
+    SOME: Send,
+    OK: Send,
+    ERR: Send,
+    ID: Send + Sync,
+{
+    type Output = ();
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let mut mtx = self.state.cancel_waker.lock();
+        if self.state.flags.canceled.load(Ordering::Acquire) {
+            Poll::Ready(())
+        } else {
+            *mtx = Some(cx.waker().clone());
+            Poll::Pending
+        }
+    }
+}
+
+struct AsyncSuspender<'a, SOME, OK, ERR, ID>
+where
+    SOME: Send,
+    OK: Send,
+    ERR: Send,
+    ID: Send + Sync,
+{
+    state: &'a FlowerState<SOME, OK, ERR, ID>,
+    // The value to wait for, until there's room in the buffered channel otherwise.
+    ticket: Option<u64>,
+    // The waker registered by the last pending poll.
+    waker: Option<Waker>,
+    finished: bool,
+}
+
+impl<'a, SOME, OK, ERR, ID> AsyncSuspender<'a, SOME, OK, ERR, ID>
+where
+    SOME: Send,
+    OK: Send,
+    ERR: Send,
+    ID: Send + Sync,
+{
+    fn new(state: &'a FlowerState<SOME, OK, ERR, ID>, ticket: Option<u64>) -> Self {
+        Self {
+            state,
+            ticket,
+            waker: None,
+            finished: false,
+        }
+    }
+}
+
+impl<'a, SOME, OK, ERR, ID> Future for AsyncSuspender<'a, SOME, OK, ERR, ID>
+where
+    SOME: Send,
+    OK: Send,
+    ERR: Send,
+    ID: Send + Sync,
+{
+    type Output = ();
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = self.get_mut();
+        let mtx = this.state.lock();
+        // Checked under the same lock the consumer takes values with,
+        // so the waker is registered before the value can be taken.
+        let pending = !this.state.senders_released()
+            && match this.ticket {
+                Some(ticket) => mtx.contains(ticket),
+                None => mtx.len() >= this.state.capacity,
+            };
+        if !pending {
+            this.finished = true;
+            return Poll::Ready(());
+        }
+        let mut wakers = this.state.awaiting.0.lock();
+        if !wakers.iter().any(|waker| waker.will_wake(cx.waker())) {
+            wakers.push(cx.waker().clone());
+        }
+        this.state.awaiting.1.store(true, Ordering::Release);
+        this.waker = Some(cx.waker().clone());
+        Poll::Pending
+    }
+}
+
+impl<'a, SOME, OK, ERR, ID> Drop for AsyncSuspender<'a, SOME, OK, ERR, ID>
+where
+    SOME: Send,
+    OK: Send,
+    ERR: Send,
+    ID: Send + Sync,
+{
+    // The future was dropped before completion, e.g. its task was aborted:
+    // take back the value which isn't polled yet and forget the registered waker.
+    fn drop(&mut self) {
+        if self.finished {
+            return;
+        }
+        let mut mtx = self.state.lock();
+        let value = self
+            .ticket
+            .and_then(|ticket| self.state.withdraw(&mut mtx, ticket));
+        {
+            let mut wakers = self.state.awaiting.0.lock();
+            if let Some(waker) = self.waker.take() {
+                wakers.retain(|w| !w.will_wake(&waker));
+            }
+            if wakers.is_empty() {
+                self.state.awaiting.1.store(false, Ordering::Release);
+            }
+        }
+        drop(mtx);
+        // Dropped outside of the lock, like any polled value.
+        drop(value);
+        self.state.notify_senders();
+    }
+}
+
+impl<SOME, OK, ERR, ID> Clone for FlowerHandle<SOME, OK, ERR, ID>
+where
+    SOME: Send,
+    OK: Send,
+    ERR: Send,
+    ID: Send + Sync,
+{
+    fn clone(&self) -> Self {
+        self.state.handles.fetch_add(1, Ordering::Relaxed);
+        Self {
+            state: Clone::clone(&self.state),
+            generation: self.generation,
+        }
+    }
+}
+
+impl<SOME, OK, ERR, ID> PartialEq for FlowerHandle<SOME, OK, ERR, ID>
+where
+    SOME: Send,
+    OK: Send,
+    ERR: Send,
+    ID: PartialEq + Send + Sync,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.state.id == other.state.id && Arc::ptr_eq(&self.state, &other.state)
+    }
+}
+
+impl<SOME, OK, ERR, ID> Eq for FlowerHandle<SOME, OK, ERR, ID>
+where
+    SOME: Send,
+    OK: Send,
+    ERR: Send,
+    ID: Eq + Send + Sync,
+{
+}
+
+impl<SOME, OK, ERR, ID> Hash for FlowerHandle<SOME, OK, ERR, ID>
+where
+    SOME: Send,
+    OK: Send,
+    ERR: Send,
+    ID: Hash + Send + Sync,
+{
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.state.id.hash(state);
+        Arc::as_ptr(&self.state).hash(state);
+    }
+}
+
+impl<SOME, OK, ERR, ID> Drop for FlowerHandle<SOME, OK, ERR, ID>
+where
+    SOME: Send,
+    OK: Send,
+    ERR: Send,
+    ID: Send + Sync,
+{
+    fn drop(&mut self) {
+        let last = self.state.handles.fetch_sub(1, Ordering::AcqRel) == 1;
+        // Also once the consumer took it, a panic after `ok` doesn't replace it.
+        if self.state.result_set.load(Ordering::Acquire)
+            || self.state.flags.result_ready.load(Ordering::Acquire)
+        {
+            return;
+        }
+        if thread::panicking() {
+            let state = Arc::as_ptr(&self.state) as *const () as usize;
+            if CATCHING.with(Cell::get) == state {
+                return;
+            }
+            let message = self.state.labeled(format!(
+                "the flower handle with id: {} error, the thread panicked maybe?",
+                self.state.id_string()
+            ));
+            flower_event!(self.state, WARN, "flower handle dropped while panicking");
+            if self.state.panic_policy == PanicPolicy::Report {
+                self.fail(ErrorKind::Panicked, message);
+            } else if !self.is_stale() {
+                self.state.panicked(Box::new(message));
+            }
+        } else if last && self.state.flags.activated.load(Ordering::Acquire) {
+            flower_event!(self.state, WARN, "flower handles dropped without result");
+            self.fail(
+                ErrorKind::HandleDropped,
+                self.state.labeled(format!(
+                    "flower handle(s) dropped without result, id: {}",
+                    self.state.id_string()
+                )),
+            );
+        }
+    }
+}
+
+/// A short line for logs, e.g. `flower#42 "thumbnailer" [running]`, see `state`.
+impl<SOME, OK, ERR, ID> Display for FlowerHandle<SOME, OK, ERR, ID>
+where
+    SOME: Send,
+    OK: Send,
+    ERR: Send,
+    ID: Send + Sync,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        self.state.display(f)
+    }
+}
+
+impl<SOME, OK, ERR, ID> Debug for FlowerHandle<SOME, OK, ERR, ID>
+where
+    SOME: Debug + Send,
+    OK: Debug + Send,
+    ERR: Debug + Send,
+    ID: Debug + Send + Sync,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("FlowerHandle")
+            .field("state", &self.state)
+            .field("generation", &self.generation)
+            .finish()
+    }
+}
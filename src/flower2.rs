@@ -0,0 +1,287 @@
+#![deny(unsafe_code)]
+use crate::{Flower, FlowerHandle, FlowerPoll, SendError};
+use core::fmt::{self, Debug, Formatter};
+
+/// A flower with two independent channels of different types, e.g. thumbnails for one widget
+/// and timing stats for another.
+///
+/// Each channel has its own queue and blocked senders, a pending `A` value doesn't hold up
+/// the `B` values. The activation, cancelation and the result are the ones of the `A` flower,
+/// see [`Flower2::inner`].
+///
+/// # Quick Example:
+///
+///```
+///use flowync::Flower2;
+///
+///let flower: Flower2<String, u32, ()> = Flower2::new(1);
+///std::thread::spawn({
+///    let handle = flower.handle();
+///    handle.activate();
+///    move || {
+///        let _ = handle.send_b(16);
+///        let _ = handle.send_a("frame 1".into());
+///        handle.ok(());
+///    }
+///});
+///
+///let (mut frames, mut timings) = (Vec::new(), Vec::new());
+///let mut exit = false;
+///while !exit {
+///    flower.then(
+///        |frame| frames.extend(frame),
+///        |timing| timings.extend(timing),
+///        |_| exit = true,
+///    );
+///}
+///assert_eq!(frames, ["frame 1"]);
+///assert_eq!(timings, [16]);
+///```
+pub struct Flower2<A, B, OK, ERR = String, ID = usize>
+where
+    A: Send,
+    B: Send,
+    OK: Send,
+    ERR: Send,
+    ID: Send + Sync,
+{
+    a: Flower<A, OK, ERR, ID>,
+    // Its result is never polled.
+    b: Flower<B, (), ERR, ID>,
+}
+
+/// The worker side of a [`Flower2`].
+pub struct Flower2Handle<A, B, OK, ERR = String, ID = usize>
+where
+    A: Send,
+    B: Send,
+    OK: Send,
+    ERR: Send,
+    ID: Send + Sync,
+{
+    a: FlowerHandle<A, OK, ERR, ID>,
+    b: FlowerHandle<B, (), ERR, ID>,
+}
+
+impl<A, B, OK, ERR, ID> Flower2<A, B, OK, ERR, ID>
+where
+    A: Send,
+    B: Send,
+    OK: Send,
+    ERR: Send + From<String>,
+    ID: Clone + Debug + Send + Sync,
+{
+    pub fn new(id: ID) -> Self {
+        Self {
+            a: Flower::new(id.clone()),
+            b: Flower::new(id),
+        }
+    }
+
+    /// Create a flower with buffered channels, see `Flower::new_buffered`.
+    pub fn new_buffered(id: ID) -> Self {
+        Self {
+            a: Flower::new_buffered(id.clone()),
+            b: Flower::new_buffered(id),
+        }
+    }
+}
+
+impl<A, B, OK, ERR, ID> Flower2<A, B, OK, ERR, ID>
+where
+    A: Send,
+    B: Send,
+    OK: Send,
+    ERR: Send,
+    ID: Send + Sync,
+{
+    /// The flower of the `A` channel, which also holds the activation, cancelation and the result.
+    pub fn inner(&self) -> &Flower<A, OK, ERR, ID> {
+        &self.a
+    }
+
+    pub fn id(&self) -> &ID {
+        self.a.id()
+    }
+
+    /// Get handle of the flower, see `Flower::handle`.
+    pub fn handle(&self) -> Flower2Handle<A, B, OK, ERR, ID> {
+        Flower2Handle {
+            a: self.a.handle(),
+            b: self.b.handle(),
+        }
+    }
+
+    /// Cancel the current flower, senders blocked on either channel return.
+    pub fn cancel(&self) {
+        self.a.cancel();
+        self.b.cancel();
+    }
+
+    pub fn is_canceled(&self) -> bool {
+        self.a.is_canceled()
+    }
+
+    pub fn is_active(&self) -> bool {
+        self.a.is_active()
+    }
+
+    pub fn result_is_ready(&self) -> bool {
+        self.a.result_is_ready()
+    }
+
+    /// Process the flower, each channel gets its front value, `None` if it's empty.
+    ///
+    /// The result is given to `r` instead once both channels drained.
+    pub fn then(
+        &self,
+        a: impl FnOnce(Option<A>),
+        b: impl FnOnce(Option<B>),
+        r: impl FnOnce(Result<OK, ERR>),
+    ) {
+        // Checked first, the values sent before the result can't be missed.
+        let done = self.a.result_is_ready();
+        let (value_a, value_b) = (self.a.take_channel(), self.b.take_channel());
+        if !done || value_a.is_some() || value_b.is_some() {
+            a(value_a);
+            b(value_b);
+            return;
+        }
+        match self.a.poll() {
+            FlowerPoll::Done(result) => r(result),
+            FlowerPoll::Channel(value) => {
+                a(Some(value));
+                b(None);
+            }
+            FlowerPoll::Idle => {
+                a(None);
+                b(None);
+            }
+        }
+    }
+
+    /// Try to get the result, the values of both channels are discarded, see `Flower::try_result`.
+    pub fn try_result(&self) -> Option<Result<OK, ERR>> {
+        let result = self.a.try_result()?;
+        while self.b.take_channel().is_some() {}
+        Some(result)
+    }
+}
+
+impl<A, B, OK, ERR, ID> Flower2Handle<A, B, OK, ERR, ID>
+where
+    A: Send,
+    B: Send,
+    OK: Send,
+    ERR: Send,
+    ID: Send + Sync,
+{
+    /// The handle of the `A` flower, e.g. for `checkpoint` or `set_on_cancel`.
+    pub fn inner(&self) -> &FlowerHandle<A, OK, ERR, ID> {
+        &self.a
+    }
+
+    pub fn id(&self) -> &ID {
+        self.a.id()
+    }
+
+    /// Activate the flower, see `FlowerHandle::activate`.
+    pub fn activate(&self) {
+        self.a.activate();
+        self.b.activate();
+    }
+
+    pub fn is_active(&self) -> bool {
+        self.a.is_active()
+    }
+
+    /// Check if the current flower should be canceled, see `FlowerHandle::should_cancel`.
+    pub fn should_cancel(&self) -> bool {
+        self.a.should_cancel()
+    }
+
+    /// Send a value on the `A` channel, see `FlowerHandle::send`.
+    pub fn send_a(&self, value: A) -> Result<(), SendError<A>> {
+        self.a.send(value)
+    }
+
+    /// Send a value on the `B` channel, see `FlowerHandle::send`.
+    pub fn send_b(&self, value: B) -> Result<(), SendError<B>> {
+        self.b.send(value)
+    }
+
+    /// Send a value on the `A` channel, overwriting the one which isn't polled yet.
+    pub fn send_latest_a(&self, value: A) {
+        self.a.send_latest(value)
+    }
+
+    /// Send a value on the `B` channel, overwriting the one which isn't polled yet.
+    pub fn send_latest_b(&self, value: B) {
+        self.b.send_latest(value)
+    }
+
+    /// Contains the success value for the result.
+    pub fn ok(&self, value: OK) {
+        self.a.ok(value);
+        self.close_b();
+    }
+
+    /// Contains the error value for the result.
+    pub fn err(&self, value: impl Into<ERR>) {
+        self.a.err(value);
+        self.close_b();
+    }
+
+    // The `B` flower gets a result too, so its handles aren't reported as dropped without one.
+    fn close_b(&self) {
+        let _ = self.b.try_ok(());
+    }
+}
+
+impl<A, B, OK, ERR, ID> Clone for Flower2Handle<A, B, OK, ERR, ID>
+where
+    A: Send,
+    B: Send,
+    OK: Send,
+    ERR: Send,
+    ID: Send + Sync,
+{
+    fn clone(&self) -> Self {
+        Self {
+            a: self.a.clone(),
+            b: self.b.clone(),
+        }
+    }
+}
+
+impl<A, B, OK, ERR, ID> Debug for Flower2<A, B, OK, ERR, ID>
+where
+    A: Debug + Send,
+    B: Debug + Send,
+    OK: Debug + Send,
+    ERR: Debug + Send,
+    ID: Debug + Send + Sync,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Flower2")
+            .field("a", &self.a)
+            .field("b", &self.b)
+            .finish()
+    }
+}
+
+impl<A, B, OK, ERR, ID> Debug for Flower2Handle<A, B, OK, ERR, ID>
+where
+    A: Debug + Send,
+    B: Debug + Send,
+    OK: Debug + Send,
+    ERR: Debug + Send,
+    ID: Debug + Send + Sync,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Flower2Handle")
+            .field("a", &self.a)
+            .field("b", &self.b)
+            .finish()
+    }
+}
@@ -0,0 +1,199 @@
+//! Drive an [`indicatif`](https://docs.rs/indicatif) progress bar from a flower.
+use crate::{Flower, FlowerEvent};
+use ::indicatif::ProgressBar;
+use core::{
+    fmt::{self, Debug, Formatter},
+    time::Duration,
+};
+
+// How long `drive_to_completion` waits for an event before ticking the bar.
+const TICK: Duration = Duration::from_millis(100);
+
+/// A channel value telling how far the worker is, see [`attach`].
+pub trait ProgressValue {
+    /// The position of the bar.
+    fn position(&self) -> u64;
+
+    /// The length of the bar, `None` leaves the current one.
+    fn length(&self) -> Option<u64> {
+        None
+    }
+}
+
+impl ProgressValue for u64 {
+    fn position(&self) -> u64 {
+        *self
+    }
+}
+
+/// `(position, length)`, for workers learning the length along the way.
+impl ProgressValue for (u64, u64) {
+    fn position(&self) -> u64 {
+        self.0
+    }
+
+    fn length(&self) -> Option<u64> {
+        Some(self.1)
+    }
+}
+
+/// Attach a progress bar to the flower, every channel value moves the bar.
+///
+/// # Quick Example:
+///
+///```
+///use flowync::{indicatif, Flower};
+///use ::indicatif::ProgressBar;
+///
+///let flower: Flower<u64, &str> = Flower::spawn(1, |handle| {
+///    for pos in 1..=10 {
+///        let _ = handle.send(pos);
+///    }
+///    handle.ok("done");
+///});
+///let bar = ProgressBar::hidden();
+///bar.set_length(10);
+///let result = indicatif::attach(&flower, bar.clone()).drive_to_completion();
+///assert_eq!(result, Ok("done"));
+///assert!(bar.is_finished());
+///assert_eq!(bar.position(), 10);
+///```
+pub fn attach<SOME, OK, ERR, ID>(
+    flower: &Flower<SOME, OK, ERR, ID>,
+    bar: ProgressBar,
+) -> FlowerBar<'_, SOME, OK, ERR, ID>
+where
+    SOME: ProgressValue + Send,
+    OK: Send,
+    ERR: Send,
+    ID: Send + Sync,
+{
+    FlowerBar {
+        flower,
+        bar,
+        received: false,
+    }
+}
+
+/// Same as `attach(flower, bar).drive_to_completion()`.
+pub fn drive_to_completion<SOME, OK, ERR, ID>(
+    flower: &Flower<SOME, OK, ERR, ID>,
+    bar: ProgressBar,
+) -> Result<OK, ERR>
+where
+    SOME: ProgressValue + Send,
+    OK: Send,
+    ERR: Send,
+    ID: Send + Sync,
+{
+    attach(flower, bar).drive_to_completion()
+}
+
+/// A progress bar following a flower, see [`attach`].
+pub struct FlowerBar<'a, SOME, OK, ERR = String, ID = usize>
+where
+    SOME: Send,
+    OK: Send,
+    ERR: Send,
+    ID: Send + Sync,
+{
+    flower: &'a Flower<SOME, OK, ERR, ID>,
+    bar: ProgressBar,
+    received: bool,
+}
+
+impl<'a, SOME, OK, ERR, ID> FlowerBar<'a, SOME, OK, ERR, ID>
+where
+    SOME: ProgressValue + Send,
+    OK: Send,
+    ERR: Send,
+    ID: Send + Sync,
+{
+    /// Get the progress bar.
+    pub fn bar(&self) -> &ProgressBar {
+        &self.bar
+    }
+
+    /// Process the flower like `then` and update the bar, never blocks the current thread.
+    ///
+    /// Returns the result once received, the bar is then finished if it's `Ok`
+    /// or abandoned where it stopped if it's `Err`.
+    pub fn update(&mut self) -> Option<Result<OK, ERR>> {
+        let mut done = None;
+        let flower = self.flower;
+        flower.then(
+            |value| match value {
+                Some(value) => self.set(value),
+                None => self.follow_progress(),
+            },
+            |result| done = Some(result),
+        );
+        done.map(|result| self.finish(result))
+    }
+
+    /// Block the current thread until the result is received, updating the bar meanwhile,
+    /// then finish or abandon the bar like `update`.
+    pub fn drive_to_completion(mut self) -> Result<OK, ERR> {
+        loop {
+            match self.flower.recv_timeout(TICK) {
+                Some(FlowerEvent::Channel(value)) => self.set(value),
+                Some(FlowerEvent::Done(result)) => return self.finish(result),
+                None => {
+                    self.follow_progress();
+                    self.bar.tick();
+                }
+            }
+        }
+    }
+
+    fn set(&mut self, value: SOME) {
+        self.received = true;
+        if let Some(len) = value.length() {
+            self.bar.set_length(len);
+        }
+        self.bar.set_position(value.position());
+    }
+
+    // Workers which never send can still move a bar of known length with `set_progress`.
+    fn follow_progress(&self) {
+        if self.received {
+            return;
+        }
+        if let (Some(progress), Some(len)) = (self.flower.progress(), self.bar.length()) {
+            self.bar.set_position((progress as f64 * len as f64) as u64);
+        }
+    }
+
+    fn finish(&self, result: Result<OK, ERR>) -> Result<OK, ERR> {
+        match result {
+            Ok(ok) => {
+                // The worker may finish without telling the length, or without sending at all,
+                // the bar is full anyway.
+                if self.bar.length().is_none() {
+                    self.bar.set_length(self.bar.position());
+                }
+                self.bar.finish();
+                Ok(ok)
+            }
+            Err(err) => {
+                self.bar.abandon();
+                Err(err)
+            }
+        }
+    }
+}
+
+impl<'a, SOME, OK, ERR, ID> Debug for FlowerBar<'a, SOME, OK, ERR, ID>
+where
+    SOME: Debug + Send,
+    OK: Debug + Send,
+    ERR: Debug + Send,
+    ID: Debug + Send + Sync,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("FlowerBar")
+            .field("flower", &self.flower)
+            .field("bar", &self.bar)
+            .finish_non_exhaustive()
+    }
+}
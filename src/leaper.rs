@@ -0,0 +1,388 @@
+#![deny(unsafe_code)]
+use core::{
+    fmt::{self, Debug, Display, Formatter},
+    future::{Future, IntoFuture},
+    pin::Pin,
+    sync::atomic::{AtomicBool, AtomicUsize, Ordering},
+    task::{Context, Poll, Waker},
+};
+use std::{sync::Arc, thread};
+
+use crate::{flower::waker_key, sync::Mutex, ContextExt, Failed};
+
+// The result and the wakers of the pending futures, under the same lock.
+struct Slot<OK, ERR> {
+    result: Option<Result<OK, ERR>>,
+    // One waker per pending `LeaperFuture`, keyed by `waker_key`.
+    wakers: Vec<(usize, Waker)>,
+}
+
+struct LeaperState<OK, ERR> {
+    // Flags are stored with `Release` and loaded with `Acquire`, same as `FlowerState`.
+    activated: AtomicBool,
+    result_ready: AtomicBool,
+    // A result was submitted since the last activation, later ones are rejected.
+    result_set: AtomicBool,
+    canceled: AtomicBool,
+    handles: AtomicUsize,
+    mtx: Mutex<Slot<OK, ERR>>,
+    err_from_string: fn(String) -> ERR,
+}
+
+/// A result only flower without channel, for jobs which have no progress to report.
+///
+/// # Quick Example:
+///
+///```
+///use flowync::Leaper;
+///
+///let leaper: Leaper<u32> = Leaper::new(1);
+///std::thread::spawn({
+///    let handle = leaper.handle();
+///    handle.activate();
+///    move || handle.ok(1)
+///});
+///
+///loop {
+///    if let Some(result) = leaper.try_result() {
+///        assert_eq!(result, Ok(1));
+///        break;
+///    }
+///}
+///```
+pub struct Leaper<OK, ERR = String>
+where
+    OK: Send,
+    ERR: Send,
+{
+    state: Arc<LeaperState<OK, ERR>>,
+    id: usize,
+}
+
+impl<OK, ERR> Leaper<OK, ERR>
+where
+    OK: Send,
+    ERR: Send + From<String>,
+{
+    pub fn new(id: usize) -> Self {
+        Self {
+            state: Arc::new(LeaperState {
+                activated: AtomicBool::new(false),
+                result_ready: AtomicBool::new(false),
+                result_set: AtomicBool::new(false),
+                canceled: AtomicBool::new(false),
+                handles: AtomicUsize::new(0),
+                mtx: Mutex::new(Slot {
+                    result: None,
+                    wakers: Vec::new(),
+                }),
+                err_from_string: From::from,
+            }),
+            id,
+        }
+    }
+}
+
+impl<OK, ERR> Leaper<OK, ERR>
+where
+    OK: Send,
+    ERR: Send,
+{
+    /// Get ID of the leaper.
+    pub fn id(&self) -> usize {
+        self.id
+    }
+
+    /// Get handle of the leaper.
+    ///
+    /// A canceled leaper stays canceled, see `reset_cancel`.
+    pub fn handle(&self) -> LeaperHandle<OK, ERR> {
+        self.state.handles.fetch_add(1, Ordering::Relaxed);
+        LeaperHandle {
+            state: Clone::clone(&self.state),
+            id: self.id,
+        }
+    }
+
+    /// Cancel the leaper, the worker can check it with `LeaperHandle::should_cancel`.
+    pub fn cancel(&self) {
+        self.state.canceled.store(true, Ordering::Release);
+    }
+
+    /// Clear the cancelation so the leaper can be run again.
+    pub fn reset_cancel(&self) {
+        self.state.canceled.store(false, Ordering::Release);
+    }
+
+    /// Check if the leaper is canceled
+    pub fn is_canceled(&self) -> bool {
+        self.state.canceled.load(Ordering::Acquire)
+    }
+
+    /// Check if the leaper is active
+    pub fn is_active(&self) -> bool {
+        self.state.activated.load(Ordering::Acquire)
+    }
+
+    /// Check if the result is ready
+    pub fn result_is_ready(&self) -> bool {
+        self.state.result_ready.load(Ordering::Acquire)
+    }
+
+    /// Try to get the result, the leaper is deactivated once it's taken.
+    ///
+    /// Returns `None` if the result isn't ready yet.
+    pub fn try_result(&self) -> Option<Result<OK, ERR>> {
+        if !self.state.result_ready.load(Ordering::Acquire) {
+            return None;
+        }
+        let result = self.state.mtx.lock().result.take()?;
+        self.state.result_ready.store(false, Ordering::Release);
+        self.state.activated.store(false, Ordering::Release);
+        Some(result)
+    }
+
+    /// Get a future of the result.
+    ///
+    /// Every pending future is woken once the result is set, the first one polled takes it.
+    pub fn await_result(&self) -> LeaperFuture<'_, OK, ERR> {
+        LeaperFuture {
+            leaper: self,
+            key: waker_key(),
+        }
+    }
+}
+
+impl<OK, ERR> Debug for Leaper<OK, ERR>
+where
+    OK: Debug + Send,
+    ERR: Debug + Send,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Leaper")
+            .field("id", &self.id)
+            .field("activated", &self.state.activated)
+            .field("result_ready", &self.state.result_ready)
+            .field("canceled", &self.state.canceled)
+            .finish()
+    }
+}
+
+impl<'a, OK, ERR> IntoFuture for &'a Leaper<OK, ERR>
+where
+    OK: Send,
+    ERR: Send,
+{
+    type Output = Result<OK, ERR>;
+    type IntoFuture = LeaperFuture<'a, OK, ERR>;
+    fn into_future(self) -> Self::IntoFuture {
+        self.await_result()
+    }
+}
+
+/// A future of the leaper result, see [`Leaper::await_result`].
+pub struct LeaperFuture<'a, OK, ERR = String>
+where
+    OK: Send,
+    ERR: Send,
+{
+    leaper: &'a Leaper<OK, ERR>,
+    key: usize,
+}
+
+impl<'a, OK, ERR> Future for LeaperFuture<'a, OK, ERR>
+where
+    OK: Send,
+    ERR: Send,
+{
+    type Output = Result<OK, ERR>;
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let state = &self.leaper.state;
+        let mut mtx = state.mtx.lock();
+        match mtx.result.take() {
+            Some(result) => {
+                state.result_ready.store(false, Ordering::Release);
+                state.activated.store(false, Ordering::Release);
+                Poll::Ready(result)
+            }
+            None => {
+                // Replace the waker of this future, unless it already wakes the same task.
+                let waker = cx.waker();
+                match mtx.wakers.iter_mut().find(|(key, _)| *key == self.key) {
+                    Some((_, registered)) => {
+                        if !registered.will_wake(waker) {
+                            *registered = waker.clone();
+                        }
+                    }
+                    None => mtx.wakers.push((self.key, waker.clone())),
+                }
+                Poll::Pending
+            }
+        }
+    }
+}
+
+impl<'a, OK, ERR> Drop for LeaperFuture<'a, OK, ERR>
+where
+    OK: Send,
+    ERR: Send,
+{
+    fn drop(&mut self) {
+        self.leaper
+            .state
+            .mtx
+            .lock()
+            .wakers
+            .retain(|(key, _)| *key != self.key);
+    }
+}
+
+impl<'a, OK, ERR> Debug for LeaperFuture<'a, OK, ERR>
+where
+    OK: Debug + Send,
+    ERR: Debug + Send,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("LeaperFuture")
+            .field("leaper", &self.leaper)
+            .finish()
+    }
+}
+
+/// A handle of the `Leaper`, used from the worker side.
+pub struct LeaperHandle<OK, ERR = String>
+where
+    OK: Send,
+    ERR: Send,
+{
+    state: Arc<LeaperState<OK, ERR>>,
+    id: usize,
+}
+
+impl<OK, ERR> LeaperHandle<OK, ERR>
+where
+    OK: Send,
+    ERR: Send,
+{
+    /// Get ID of the leaper.
+    pub fn id(&self) -> usize {
+        self.id
+    }
+
+    /// Activate the leaper, a new result can be set from now on.
+    pub fn activate(&self) {
+        self.state.result_set.store(false, Ordering::Release);
+        self.state.activated.store(true, Ordering::Release);
+    }
+
+    /// Check if the leaper is active
+    pub fn is_active(&self) -> bool {
+        self.state.activated.load(Ordering::Acquire)
+    }
+
+    /// Check if the leaper should be canceled
+    pub fn should_cancel(&self) -> bool {
+        self.state.canceled.load(Ordering::Acquire)
+    }
+
+    fn set_result(&self, result: Result<OK, ERR>) {
+        if self.state.result_set.swap(true, Ordering::AcqRel) {
+            return;
+        }
+        let wakers = {
+            let mut mtx = self.state.mtx.lock();
+            mtx.result = Some(result);
+            self.state.result_ready.store(true, Ordering::Release);
+            std::mem::take(&mut mtx.wakers)
+        };
+        for (_, waker) in wakers {
+            waker.wake();
+        }
+    }
+
+    /// Contains the success value for the result.
+    ///
+    /// Only the first result since the activation is delivered, from any clone of the handle.
+    pub fn ok(&self, value: OK) {
+        self.set_result(Ok(value));
+    }
+
+    /// Contains the error value for the result.
+    ///
+    /// Only the first result since the activation is delivered, from any clone of the handle.
+    pub fn err(&self, value: impl Into<ERR>) {
+        self.set_result(Err(value.into()));
+    }
+
+    /// Contains the error value for the result, formatted like "context: err".
+    pub fn err_with_context(&self, context: impl Display, err: impl Display) {
+        self.set_result(Err((self.state.err_from_string)(format!(
+            "{}: {}",
+            context, err
+        ))));
+    }
+}
+
+impl<OK, ERR> ContextExt for LeaperHandle<OK, ERR>
+where
+    OK: Send,
+    ERR: Send,
+{
+    fn fail_ctx(&self, context: impl Display, err: impl Display) -> Failed {
+        self.err_with_context(context, err);
+        Failed
+    }
+}
+
+impl<OK, ERR> Clone for LeaperHandle<OK, ERR>
+where
+    OK: Send,
+    ERR: Send,
+{
+    fn clone(&self) -> Self {
+        self.state.handles.fetch_add(1, Ordering::Relaxed);
+        Self {
+            state: Clone::clone(&self.state),
+            id: self.id,
+        }
+    }
+}
+
+impl<OK, ERR> Drop for LeaperHandle<OK, ERR>
+where
+    OK: Send,
+    ERR: Send,
+{
+    fn drop(&mut self) {
+        let last = self.state.handles.fetch_sub(1, Ordering::AcqRel) == 1;
+        // Also once the consumer took it, a panic after `ok` doesn't replace it.
+        if self.state.result_set.load(Ordering::Acquire) {
+            return;
+        }
+        if thread::panicking() {
+            self.err((self.state.err_from_string)(format!(
+                "the leaper handle with id: {} error, the thread panicked maybe?",
+                self.id
+            )));
+        } else if last && self.state.activated.load(Ordering::Acquire) {
+            self.err((self.state.err_from_string)(format!(
+                "leaper handle(s) dropped without result, id: {}",
+                self.id
+            )));
+        }
+    }
+}
+
+impl<OK, ERR> Debug for LeaperHandle<OK, ERR>
+where
+    OK: Debug + Send,
+    ERR: Debug + Send,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("LeaperHandle")
+            .field("id", &self.id)
+            .field("activated", &self.state.activated)
+            .field("canceled", &self.state.canceled)
+            .finish()
+    }
+}
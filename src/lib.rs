@@ -1,9 +1,75 @@
-#[cfg(feature = "parking-lot")]
-mod parking_lot_mutex;
-#[cfg(feature = "parking-lot")]
-pub use parking_lot_mutex::{Flower, FlowerHandle};
-
-#[cfg(not(feature = "parking-lot"))]
-mod std_mutex;
-#[cfg(not(feature = "parking-lot"))]
-pub use std_mutex::{Flower, FlowerHandle};
+#[macro_use]
+mod stats;
+#[macro_use]
+mod trace;
+mod builder;
+mod channel;
+mod context;
+mod error;
+mod event;
+mod leaper;
+mod map;
+mod mapped;
+mod notifier;
+mod oneshot;
+#[cfg(feature = "panic-info")]
+mod panic_info;
+mod pool;
+mod select;
+pub use builder::{Backpressure, FlowerBuilder, PanicPolicy};
+pub use context::ContextExt;
+pub use error::{
+    ErrorKind, Failed, FlowerError, ResetWhileActive, ResultAlreadySet, SendError, SendErrorReason,
+    SendTimeoutError,
+};
+pub use event::{FlowerEvent, FlowerLifecycle, FlowerPoll};
+pub use leaper::{Leaper, LeaperFuture, LeaperHandle};
+pub use map::{FlowerMap, SpawnPolicy};
+pub use mapped::MappedFlower;
+pub use notifier::Notifier;
+pub use oneshot::OneshotHandle;
+pub use pool::{FlowerPool, FlowerStatus};
+pub use select::{join_all, select, select_wait};
+#[cfg(feature = "stats")]
+pub use stats::FlowerStats;
+
+mod flower;
+mod flower2;
+mod sync;
+pub use flower::{
+    last_issued_id, Flower, FlowerFuture, FlowerHandle, FlowerIter, SimpleFlower,
+    SimpleFlowerHandle, WeakFlower,
+};
+pub use flower2::{Flower2, Flower2Handle};
+
+#[cfg(feature = "async")]
+mod async_flower;
+#[cfg(feature = "async")]
+pub use async_flower::{AsyncFlower, AsyncFlowerHandle};
+
+#[cfg(feature = "atomic")]
+mod atomic_flower;
+#[cfg(feature = "atomic")]
+pub use atomic_flower::{AtomicFlower, AtomicFlowerHandle};
+
+#[cfg(feature = "tokio")]
+mod tokio_spawn;
+
+#[cfg(feature = "cli")]
+pub mod indicatif;
+
+#[cfg(feature = "egui")]
+pub mod egui;
+
+#[cfg(feature = "winit")]
+mod winit_proxy;
+
+#[cfg(feature = "rayon")]
+mod parallel;
+#[cfg(feature = "rayon")]
+pub use parallel::{FlowerProgressIter, ParallelProgressExt};
+
+#[cfg(feature = "stream")]
+mod stream;
+#[cfg(feature = "stream")]
+pub use stream::FlowerStream;
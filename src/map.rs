@@ -0,0 +1,310 @@
+#![deny(unsafe_code)]
+use crate::{Flower, FlowerHandle, FlowerPoll};
+use core::{
+    borrow::Borrow,
+    fmt::{self, Debug, Formatter},
+    hash::Hash,
+};
+use std::collections::{HashMap, VecDeque};
+
+type Job<SOME, OK, ERR> = Box<dyn FnOnce(FlowerHandle<SOME, OK, ERR>) + Send>;
+
+/// What [`FlowerMap::spawn`] does when the key already has a job which didn't deliver
+/// its result yet.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SpawnPolicy {
+    /// Keep the current job, the new one is dropped and `spawn` returns `false` (default).
+    #[default]
+    Reject,
+    /// Cancel the current job and replace its flower, its values and result are discarded.
+    Replace,
+    /// Run the new job once the current one delivered its result, in spawn order.
+    Queue,
+}
+
+struct Entry<SOME, OK, ERR>
+where
+    SOME: Send,
+    OK: Send,
+    ERR: Send,
+{
+    flower: Flower<SOME, OK, ERR>,
+    queue: VecDeque<Job<SOME, OK, ERR>>,
+    // The result of the last job was polled and nothing is queued.
+    finished: bool,
+}
+
+impl<SOME, OK, ERR> Entry<SOME, OK, ERR>
+where
+    SOME: Send,
+    OK: Send,
+    ERR: Send,
+{
+    fn new(flower: Flower<SOME, OK, ERR>) -> Self {
+        Self {
+            flower,
+            queue: VecDeque::new(),
+            finished: false,
+        }
+    }
+}
+
+/// A set of flowers indexed by a key, e.g. one job per file path.
+///
+/// Spawning on a key whose job is still running follows the [`SpawnPolicy`] of the map.
+/// Finished flowers stay in the map until `remove_finished` or `remove`.
+///
+/// # Example
+///
+/// ```
+/// use flowync::{FlowerMap, FlowerPoll, SpawnPolicy};
+///
+/// let mut map: FlowerMap<&str, u32, usize> = FlowerMap::new().spawn_policy(SpawnPolicy::Queue);
+/// for path in ["a.png", "b.png", "a.png"] {
+///     map.spawn(path, move |handle| {
+///         let _ = handle.send(50);
+///         handle.ok(path.len());
+///     });
+/// }
+///
+/// let mut done = 0;
+/// while done < 3 {
+///     map.poll_all(|path, event| match event {
+///         FlowerPoll::Channel(percent) => println!("{}: {}%", path, percent),
+///         FlowerPoll::Done(result) => {
+///             println!("{}: {:?}", path, result);
+///             done += 1;
+///         }
+///         FlowerPoll::Idle => (),
+///     });
+/// }
+/// map.remove_finished();
+/// assert!(map.is_empty());
+/// ```
+pub struct FlowerMap<K, SOME, OK, ERR = String>
+where
+    SOME: Send,
+    OK: Send,
+    ERR: Send,
+{
+    entries: HashMap<K, Entry<SOME, OK, ERR>>,
+    spawn_policy: SpawnPolicy,
+}
+
+impl<K, SOME, OK, ERR> FlowerMap<K, SOME, OK, ERR>
+where
+    K: Hash + Eq + Clone,
+    SOME: Send,
+    OK: Send,
+    ERR: Send,
+{
+    /// Create an empty map which rejects spawning on a busy key.
+    pub fn new() -> Self {
+        Self {
+            entries: HashMap::new(),
+            spawn_policy: SpawnPolicy::default(),
+        }
+    }
+
+    /// What `spawn` does when the key already has a job, see [`SpawnPolicy`].
+    pub fn spawn_policy(mut self, spawn_policy: SpawnPolicy) -> Self {
+        self.spawn_policy = spawn_policy;
+        self
+    }
+
+    /// Insert a flower driven by the caller, returns the previous flower of the key if any.
+    ///
+    /// The jobs queued on the key are dropped.
+    pub fn insert(
+        &mut self,
+        key: K,
+        flower: Flower<SOME, OK, ERR>,
+    ) -> Option<Flower<SOME, OK, ERR>> {
+        self.entries
+            .insert(key, Entry::new(flower))
+            .map(|entry| entry.flower)
+    }
+
+    /// Spawn a job for the key on a new thread with `Flower::spawn`, the flower gets
+    /// an id from `Flower::new_auto`.
+    ///
+    /// Returns `false` if the key is busy and the spawn policy is `Reject`.
+    pub fn spawn(
+        &mut self,
+        key: K,
+        f: impl FnOnce(FlowerHandle<SOME, OK, ERR>) + Send + 'static,
+    ) -> bool
+    where
+        SOME: 'static,
+        OK: 'static,
+        ERR: From<String> + 'static,
+    {
+        match self.entries.get_mut(&key) {
+            Some(entry) if !entry.finished => match self.spawn_policy {
+                SpawnPolicy::Reject => return false,
+                SpawnPolicy::Replace => entry.flower.cancel(),
+                SpawnPolicy::Queue => {
+                    entry.queue.push_back(Box::new(f));
+                    return true;
+                }
+            },
+            _ => (),
+        }
+        let flower = Flower::new_auto();
+        flower.start(f);
+        self.entries.insert(key, Entry::new(flower));
+        true
+    }
+
+    /// Poll every flower once, `on_event` is called with the key of each flower which
+    /// has a channel value or a result. The next queued job of the key starts after a result.
+    pub fn poll_all(&mut self, mut on_event: impl FnMut(&K, FlowerPoll<SOME, OK, ERR>))
+    where
+        SOME: 'static,
+        OK: 'static,
+        ERR: From<String> + 'static,
+    {
+        for (key, entry) in self.entries.iter_mut().filter(|(_, entry)| !entry.finished) {
+            match entry.flower.poll() {
+                FlowerPoll::Idle => (),
+                FlowerPoll::Done(result) => {
+                    on_event(key, FlowerPoll::Done(result));
+                    match entry.queue.pop_front() {
+                        Some(job) => {
+                            entry.flower = Flower::new_auto();
+                            entry.flower.start(job);
+                        }
+                        None => entry.finished = true,
+                    }
+                }
+                event => on_event(key, event),
+            }
+        }
+    }
+
+    /// Get the flower of the key.
+    pub fn get<Q>(&self, key: &Q) -> Option<&Flower<SOME, OK, ERR>>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.entries.get(key).map(|entry| &entry.flower)
+    }
+
+    /// Check if the key has a flower, finished or not.
+    pub fn contains<Q>(&self, key: &Q) -> bool
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.entries.contains_key(key)
+    }
+
+    /// Check if the key has a job which didn't deliver its result yet, or queued ones.
+    pub fn is_busy<Q>(&self, key: &Q) -> bool
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.entries.get(key).is_some_and(|entry| !entry.finished)
+    }
+
+    /// Cancel the flower of the key and drop its queued jobs,
+    /// returns false if the key isn't in the map.
+    pub fn cancel<Q>(&mut self, key: &Q) -> bool
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        match self.entries.get_mut(key) {
+            Some(entry) => {
+                entry.queue.clear();
+                entry.flower.cancel();
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Cancel every flower of the map and drop the queued jobs.
+    pub fn cancel_all(&mut self) {
+        for entry in self.entries.values_mut() {
+            entry.queue.clear();
+            entry.flower.cancel();
+        }
+    }
+
+    /// Remove the flowers whose result was polled, returns how many were removed.
+    pub fn remove_finished(&mut self) -> usize {
+        let len = self.entries.len();
+        self.entries.retain(|_, entry| !entry.finished);
+        len - self.entries.len()
+    }
+
+    /// Remove the flower of the key, its queued jobs are dropped without running.
+    pub fn remove<Q>(&mut self, key: &Q) -> Option<Flower<SOME, OK, ERR>>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.entries.remove(key).map(|entry| entry.flower)
+    }
+
+    /// Iterate over the keys and their flowers in arbitrary order.
+    pub fn iter(&self) -> impl Iterator<Item = (&K, &Flower<SOME, OK, ERR>)> {
+        self.entries.iter().map(|(key, entry)| (key, &entry.flower))
+    }
+
+    /// Get the number of jobs queued behind the running one of the key.
+    pub fn queued_len<Q>(&self, key: &Q) -> usize
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.entries.get(key).map_or(0, |entry| entry.queue.len())
+    }
+
+    /// Get the number of keys in the map.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Check if the map is empty.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+}
+
+impl<K, SOME, OK, ERR> Default for FlowerMap<K, SOME, OK, ERR>
+where
+    K: Hash + Eq + Clone,
+    SOME: Send,
+    OK: Send,
+    ERR: Send,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<K, SOME, OK, ERR> Debug for FlowerMap<K, SOME, OK, ERR>
+where
+    K: Debug,
+    SOME: Debug + Send,
+    OK: Debug + Send,
+    ERR: Debug + Send,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("FlowerMap")
+            .field(
+                "flowers",
+                &self
+                    .entries
+                    .iter()
+                    .map(|(key, entry)| (key, &entry.flower))
+                    .collect::<Vec<_>>(),
+            )
+            .field("spawn_policy", &self.spawn_policy)
+            .finish()
+    }
+}
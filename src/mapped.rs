@@ -0,0 +1,222 @@
+use crate::{Flower, FlowerHandle, FlowerLifecycle, FlowerPoll};
+use core::fmt::{self, Debug, Formatter};
+
+/// A flower whose channel values and success value are converted when they're taken,
+/// see [`Flower::map_channel`] and [`Flower::map_ok`].
+///
+/// # Quick Example:
+///
+///```
+///use flowync::Flower;
+///
+///let total = 200;
+///let flower: Flower<u64, String> = Flower::new(1);
+///let percent = flower.map_channel(move |bytes| bytes * 100 / total);
+///std::thread::spawn({
+///    let handle = percent.handle();
+///    handle.activate();
+///    move || {
+///        let _ = handle.send(50);
+///        handle.ok("done".into());
+///    }
+///});
+///
+///let mut exit = false;
+///while !exit {
+///    percent.then(
+///        |value| if let Some(p) = value { assert_eq!(p, 25) },
+///        |_| exit = true,
+///    );
+///}
+///```
+pub struct MappedFlower<SOME, OK, T, U, ERR = String, ID = usize>
+where
+    SOME: Send,
+    OK: Send,
+    ERR: Send,
+    ID: Send + Sync,
+{
+    flower: Flower<SOME, OK, ERR, ID>,
+    map_channel: Box<dyn Fn(SOME) -> T + Send + Sync>,
+    map_ok: Box<dyn Fn(OK) -> U + Send + Sync>,
+}
+
+impl<SOME, OK, ERR, ID> Flower<SOME, OK, ERR, ID>
+where
+    SOME: Send + 'static,
+    OK: Send + 'static,
+    ERR: Send,
+    ID: Send + Sync,
+{
+    /// Convert each channel value with `f` when it's taken by `then` or `poll`.
+    pub fn map_channel<T>(
+        self,
+        f: impl Fn(SOME) -> T + Send + Sync + 'static,
+    ) -> MappedFlower<SOME, OK, T, OK, ERR, ID> {
+        MappedFlower {
+            flower: self,
+            map_channel: Box::new(f),
+            map_ok: Box::new(|value| value),
+        }
+    }
+
+    /// Convert the success value with `f` when it's taken by `then` or `poll`.
+    pub fn map_ok<U>(
+        self,
+        f: impl Fn(OK) -> U + Send + Sync + 'static,
+    ) -> MappedFlower<SOME, OK, SOME, U, ERR, ID> {
+        MappedFlower {
+            flower: self,
+            map_channel: Box::new(|value| value),
+            map_ok: Box::new(f),
+        }
+    }
+}
+
+impl<SOME, OK, T, U, ERR, ID> MappedFlower<SOME, OK, T, U, ERR, ID>
+where
+    SOME: Send + 'static,
+    OK: Send + 'static,
+    T: 'static,
+    U: 'static,
+    ERR: Send,
+    ID: Send + Sync,
+{
+    /// Convert the already converted channel values again with `f`.
+    pub fn map_channel<V>(
+        self,
+        f: impl Fn(T) -> V + Send + Sync + 'static,
+    ) -> MappedFlower<SOME, OK, V, U, ERR, ID> {
+        let map_channel = self.map_channel;
+        MappedFlower {
+            flower: self.flower,
+            map_channel: Box::new(move |value| f(map_channel(value))),
+            map_ok: self.map_ok,
+        }
+    }
+
+    /// Convert the already converted success value again with `f`.
+    pub fn map_ok<V>(
+        self,
+        f: impl Fn(U) -> V + Send + Sync + 'static,
+    ) -> MappedFlower<SOME, OK, T, V, ERR, ID> {
+        let map_ok = self.map_ok;
+        MappedFlower {
+            flower: self.flower,
+            map_channel: self.map_channel,
+            map_ok: Box::new(move |value| f(map_ok(value))),
+        }
+    }
+}
+
+impl<SOME, OK, T, U, ERR, ID> MappedFlower<SOME, OK, T, U, ERR, ID>
+where
+    SOME: Send,
+    OK: Send,
+    ERR: Send,
+    ID: Send + Sync,
+{
+    /// Get the underlying flower.
+    pub fn inner(&self) -> &Flower<SOME, OK, ERR, ID> {
+        &self.flower
+    }
+
+    /// Get the underlying flower back, the conversions are dropped.
+    pub fn into_inner(self) -> Flower<SOME, OK, ERR, ID> {
+        self.flower
+    }
+
+    /// Get ID of the flower.
+    pub fn id(&self) -> &ID {
+        self.flower.id()
+    }
+
+    /// Get handle of the flower, the worker sends the unconverted values.
+    pub fn handle(&self) -> FlowerHandle<SOME, OK, ERR, ID> {
+        self.flower.handle()
+    }
+
+    /// Cancel the flower, see `Flower::cancel`.
+    pub fn cancel(&self) {
+        self.flower.cancel()
+    }
+
+    /// Check if the flower is canceled
+    pub fn is_canceled(&self) -> bool {
+        self.flower.is_canceled()
+    }
+
+    /// Pause the flower, see `Flower::pause`.
+    pub fn pause(&self) {
+        self.flower.pause()
+    }
+
+    /// Resume the flower, see `Flower::resume`.
+    pub fn resume(&self) {
+        self.flower.resume()
+    }
+
+    /// Check if the flower is paused
+    pub fn is_paused(&self) -> bool {
+        self.flower.is_paused()
+    }
+
+    /// Check if the flower is active
+    pub fn is_active(&self) -> bool {
+        self.flower.is_active()
+    }
+
+    /// Check if the result is ready
+    pub fn result_is_ready(&self) -> bool {
+        self.flower.result_is_ready()
+    }
+
+    /// Check if a channel value is present
+    pub fn channel_is_present(&self) -> bool {
+        self.flower.channel_is_present()
+    }
+
+    /// Get the lifecycle state of the flower, see `Flower::state`.
+    pub fn state(&self) -> FlowerLifecycle {
+        self.flower.state()
+    }
+
+    /// Process the flower, same as `Flower::then` with the converted values.
+    pub fn then(&self, c: impl FnOnce(Option<T>), r: impl FnOnce(Result<U, ERR>)) {
+        match self.poll() {
+            FlowerPoll::Idle => c(None),
+            FlowerPoll::Channel(value) => c(Some(value)),
+            FlowerPoll::Done(result) => r(result),
+        }
+    }
+
+    /// Poll the flower once, same as `Flower::poll` with the converted values.
+    pub fn poll(&self) -> FlowerPoll<T, U, ERR> {
+        match self.flower.poll() {
+            FlowerPoll::Idle => FlowerPoll::Idle,
+            FlowerPoll::Channel(value) => FlowerPoll::Channel((self.map_channel)(value)),
+            FlowerPoll::Done(result) => FlowerPoll::Done(result.map(&self.map_ok)),
+        }
+    }
+
+    /// Try to get the converted result, see `Flower::try_result`.
+    pub fn try_result(&self) -> Option<Result<U, ERR>> {
+        self.flower
+            .try_result()
+            .map(|result| result.map(&self.map_ok))
+    }
+}
+
+impl<SOME, OK, T, U, ERR, ID> Debug for MappedFlower<SOME, OK, T, U, ERR, ID>
+where
+    SOME: Debug + Send,
+    OK: Debug + Send,
+    ERR: Debug + Send,
+    ID: Debug + Send + Sync,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("MappedFlower")
+            .field("flower", &self.flower)
+            .finish()
+    }
+}
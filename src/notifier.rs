@@ -0,0 +1,17 @@
+/// Get notified whenever something new is available from a flower,
+/// see [`Flower::set_notifier`](crate::Flower::set_notifier).
+///
+/// Called from the worker thread right after a channel value or the result is set,
+/// never while the internal mutex is held, e.g. to wake up an event loop.
+pub trait Notifier {
+    fn notify(&self);
+}
+
+impl<F> Notifier for F
+where
+    F: Fn(),
+{
+    fn notify(&self) {
+        self()
+    }
+}
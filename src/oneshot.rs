@@ -0,0 +1,157 @@
+use crate::{FlowerHandle, SendError, SendTimeoutError};
+use core::fmt::{self, Debug, Formatter};
+use std::time::Duration;
+
+/// A handle which sets the result of the flower exactly once, see [`FlowerHandle::into_oneshot`].
+///
+/// `ok` and `err` consume the handle, so nothing can be sent or set after the result.
+/// Dropping it without a result sets the "dropped without result" error like the last
+/// `FlowerHandle` would.
+///
+/// # Quick Example:
+///
+///```
+///use flowync::Flower;
+///
+///let flower: Flower<u32, String> = Flower::new(1);
+///let handle = flower.handle().into_oneshot();
+///handle.activate();
+///std::thread::spawn(move || {
+///    let _ = handle.send(1);
+///    handle.ok("done".into());
+///});
+///
+///let mut exit = false;
+///while !exit {
+///    flower.then(|_| (), |result| {
+///        assert_eq!(result, Ok("done".to_string()));
+///        exit = true;
+///    });
+///}
+///```
+///
+/// A second result doesn't compile:
+///
+///```compile_fail
+///# use flowync::Flower;
+///# let flower: Flower<u32, String> = Flower::new(1);
+///let handle = flower.handle().into_oneshot();
+///handle.ok("done".into());
+///handle.err("again");
+///```
+pub struct OneshotHandle<SOME, OK, ERR = String, ID = usize>
+where
+    SOME: Send,
+    OK: Send,
+    ERR: Send,
+    ID: Send + Sync,
+{
+    handle: FlowerHandle<SOME, OK, ERR, ID>,
+}
+
+impl<SOME, OK, ERR, ID> FlowerHandle<SOME, OK, ERR, ID>
+where
+    SOME: Send,
+    OK: Send,
+    ERR: Send,
+    ID: Send + Sync,
+{
+    /// Turn the handle into a [`OneshotHandle`] whose `ok` and `err` take it by value.
+    ///
+    /// Other clones of the handle can still set the result.
+    pub fn into_oneshot(self) -> OneshotHandle<SOME, OK, ERR, ID> {
+        OneshotHandle { handle: self }
+    }
+}
+
+impl<SOME, OK, ERR, ID> OneshotHandle<SOME, OK, ERR, ID>
+where
+    SOME: Send,
+    OK: Send,
+    ERR: Send,
+    ID: Send + Sync,
+{
+    /// Get ID of the flower.
+    pub fn id(&self) -> &ID {
+        self.handle.id()
+    }
+
+    /// Activate the flower, see `FlowerHandle::activate`.
+    pub fn activate(&self) {
+        self.handle.activate()
+    }
+
+    /// Check if the flower should be canceled, see `FlowerHandle::should_cancel`.
+    pub fn should_cancel(&self) -> bool {
+        self.handle.should_cancel()
+    }
+
+    /// Check if the flower should be paused, see `FlowerHandle::should_pause`.
+    pub fn should_pause(&self) -> bool {
+        self.handle.should_pause()
+    }
+
+    /// Get the reason of the cancelation if any.
+    pub fn cancel_reason(&self) -> Option<String> {
+        self.handle.cancel_reason()
+    }
+
+    /// Set the current progress, see `FlowerHandle::set_progress`.
+    pub fn set_progress(&self, p: f32) {
+        self.handle.set_progress(p)
+    }
+
+    /// Send current progress value, see `FlowerHandle::send`.
+    pub fn send(&self, value: SOME) -> Result<(), SendError<SOME>> {
+        self.handle.send(value)
+    }
+
+    /// Send current progress value overwriting any value which isn't polled yet,
+    /// see `FlowerHandle::send_latest`.
+    pub fn send_latest(&self, value: SOME) {
+        self.handle.send_latest(value)
+    }
+
+    /// Send current progress value, see `FlowerHandle::send_timeout`.
+    pub fn send_timeout(
+        &self,
+        value: SOME,
+        timeout: Duration,
+    ) -> Result<(), SendTimeoutError<SOME>> {
+        self.handle.send_timeout(value, timeout)
+    }
+
+    /// Send current progress value asynchronously, see `FlowerHandle::send_async`.
+    pub async fn send_async(&self, value: SOME) -> Result<(), SendError<SOME>> {
+        self.handle.send_async(value).await
+    }
+
+    /// Set the success value as the result.
+    pub fn ok(self, value: OK) {
+        self.handle.ok(value)
+    }
+
+    /// Set the error value as the result.
+    pub fn err(self, value: impl Into<ERR>) {
+        self.handle.err(value)
+    }
+
+    /// Acknowledge the cancelation as the result, see `FlowerHandle::cancel_ack`.
+    pub fn cancel_ack(self) {
+        self.handle.cancel_ack()
+    }
+}
+
+impl<SOME, OK, ERR, ID> Debug for OneshotHandle<SOME, OK, ERR, ID>
+where
+    SOME: Debug + Send,
+    OK: Debug + Send,
+    ERR: Debug + Send,
+    ID: Debug + Send + Sync,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("OneshotHandle")
+            .field("handle", &self.handle)
+            .finish()
+    }
+}
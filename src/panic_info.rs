@@ -0,0 +1,47 @@
+use core::cell::{Cell, RefCell};
+use std::{backtrace::Backtrace, panic, sync::Once};
+
+thread_local! {
+    // Whether a panic on this thread is caught by `FlowerHandle::catch_panic`.
+    static CAPTURING: Cell<bool> = const { Cell::new(false) };
+    // Where the last caught panic happened, taken by `FlowerHandle::err_panic`.
+    static LOCATION: RefCell<Option<String>> = const { RefCell::new(None) };
+}
+
+static HOOK: Once = Once::new();
+
+// Install the hook once, other panics are left to the previous hook only.
+fn install() {
+    HOOK.call_once(|| {
+        let previous = panic::take_hook();
+        panic::set_hook(Box::new(move |info| {
+            if CAPTURING.with(Cell::get) {
+                let mut location = match info.location() {
+                    Some(at) => format!(" at {}:{}:{}", at.file(), at.line(), at.column()),
+                    None => String::new(),
+                };
+                // Only if enabled with `RUST_BACKTRACE`.
+                let backtrace = Backtrace::capture();
+                if let std::backtrace::BacktraceStatus::Captured = backtrace.status() {
+                    location.push_str(&format!("\n{}", backtrace));
+                }
+                LOCATION.with(|l| *l.borrow_mut() = Some(location));
+            }
+            previous(info)
+        }));
+    });
+}
+
+/// Run `f` with the location of a panic in it recorded for `take`.
+pub(crate) fn capture<R>(f: impl FnOnce() -> R) -> R {
+    install();
+    let capturing = CAPTURING.with(|c| c.replace(true));
+    let result = f();
+    CAPTURING.with(|c| c.set(capturing));
+    result
+}
+
+/// Take the location, and the backtrace if captured, of the last panic on this thread.
+pub(crate) fn take() -> Option<String> {
+    LOCATION.with(|l| l.borrow_mut().take())
+}
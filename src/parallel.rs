@@ -0,0 +1,112 @@
+use crate::FlowerHandle;
+use core::{
+    fmt::{self, Debug, Formatter},
+    sync::atomic::{AtomicUsize, Ordering},
+};
+use rayon::iter::{plumbing::UnindexedConsumer, ParallelIterator};
+
+/// Report the progress of a rayon parallel iterator through a flower handle,
+/// see [`ParallelProgressExt::flower_progress`].
+pub trait ParallelProgressExt: ParallelIterator {
+    /// Count the items taken from the iterator and set the fraction of `total` done
+    /// with `FlowerHandle::set_progress`, about a hundred times over the whole run.
+    ///
+    /// No more items are taken once `should_cancel` is true, so a canceled flower
+    /// short-circuits the rest of the parallel work.
+    ///
+    /// # Quick Example:
+    ///
+    ///```
+    ///use flowync::{Flower, ParallelProgressExt};
+    ///use rayon::prelude::*;
+    ///
+    ///let data: Vec<u64> = (0..10_000).collect();
+    ///let flower: Flower<(), u64> = Flower::new(1);
+    ///let handle = flower.handle();
+    ///handle.activate();
+    ///let sum: u64 = data.par_iter().flower_progress(&handle, data.len()).sum();
+    ///assert_eq!(sum, 49_995_000);
+    ///assert_eq!(flower.progress(), Some(1.0));
+    ///```
+    fn flower_progress<SOME, OK, ERR, ID>(
+        self,
+        handle: &FlowerHandle<SOME, OK, ERR, ID>,
+        total: usize,
+    ) -> FlowerProgressIter<'_, Self, SOME, OK, ERR, ID>
+    where
+        SOME: Send,
+        OK: Send,
+        ERR: Send,
+        ID: Send + Sync,
+        FlowerHandle<SOME, OK, ERR, ID>: Sync,
+    {
+        FlowerProgressIter {
+            base: self,
+            handle,
+            total,
+        }
+    }
+}
+
+impl<I: ParallelIterator> ParallelProgressExt for I {}
+
+/// A parallel iterator reporting its progress, see [`ParallelProgressExt::flower_progress`].
+pub struct FlowerProgressIter<'a, I, SOME, OK, ERR = String, ID = usize>
+where
+    SOME: Send,
+    OK: Send,
+    ERR: Send,
+    ID: Send + Sync,
+{
+    base: I,
+    handle: &'a FlowerHandle<SOME, OK, ERR, ID>,
+    total: usize,
+}
+
+impl<'a, I, SOME, OK, ERR, ID> ParallelIterator for FlowerProgressIter<'a, I, SOME, OK, ERR, ID>
+where
+    I: ParallelIterator,
+    SOME: Send,
+    OK: Send,
+    ERR: Send,
+    ID: Send + Sync,
+    FlowerHandle<SOME, OK, ERR, ID>: Sync,
+{
+    type Item = I::Item;
+
+    fn drive_unindexed<C>(self, consumer: C) -> C::Result
+    where
+        C: UnindexedConsumer<Self::Item>,
+    {
+        let handle = self.handle;
+        let total = self.total;
+        // Report about every percent, not on every item.
+        let step = (total / 100).max(1);
+        let done = AtomicUsize::new(0);
+        self.base
+            .take_any_while(move |_| !handle.should_cancel())
+            .map(move |item| {
+                let n = done.fetch_add(1, Ordering::Relaxed) + 1;
+                if total > 0 && (n.is_multiple_of(step) || n == total) {
+                    handle.set_progress(n as f32 / total as f32);
+                }
+                item
+            })
+            .drive_unindexed(consumer)
+    }
+}
+
+impl<'a, I, SOME, OK, ERR, ID> Debug for FlowerProgressIter<'a, I, SOME, OK, ERR, ID>
+where
+    SOME: Debug + Send,
+    OK: Debug + Send,
+    ERR: Debug + Send,
+    ID: Debug + Send + Sync,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("FlowerProgressIter")
+            .field("handle", &self.handle)
+            .field("total", &self.total)
+            .finish_non_exhaustive()
+    }
+}
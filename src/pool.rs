@@ -0,0 +1,498 @@
+use crate::{
+    flower::SharedNotifier,
+    sync::{earliest, Condvar, Instant, Mutex},
+    Flower, FlowerHandle, FlowerLifecycle, FlowerPoll,
+};
+use core::fmt::{self, Debug, Formatter};
+use std::{
+    borrow::Cow,
+    collections::{HashSet, VecDeque},
+    sync::Arc,
+    time::Duration,
+};
+
+// The results of `collect_results` with the id of their flower.
+type Results<OK, ERR> = Vec<(usize, Result<OK, ERR>)>;
+
+// A job waiting for a free slot with the id of its flower.
+type QueuedJob<SOME, OK, ERR> = (usize, Box<dyn FnOnce(FlowerHandle<SOME, OK, ERR>) + Send>);
+
+/// A snapshot of a flower in a pool, see [`FlowerPool::snapshot`] and [`FlowerPool::cancel_by`].
+///
+/// Read from the flags of the flower without locking its channel, only the label is cloned
+/// under its own lock.
+#[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
+pub struct FlowerStatus {
+    pub id: usize,
+    /// See `Flower::state`.
+    pub state: FlowerLifecycle,
+    /// See `Flower::label`.
+    pub label: Option<Cow<'static, str>>,
+    /// See `Flower::progress`.
+    pub progress: Option<f32>,
+    /// See `Flower::elapsed`.
+    pub elapsed: Option<Duration>,
+    /// See `Flower::is_canceled`.
+    pub canceled: bool,
+}
+
+impl FlowerStatus {
+    fn of<SOME: Send, OK: Send, ERR: Send>(flower: &Flower<SOME, OK, ERR>) -> Self {
+        Self {
+            id: *flower.id(),
+            state: flower.state_unlocked(),
+            label: flower.label(),
+            progress: flower.progress(),
+            elapsed: flower.elapsed(),
+            canceled: flower.is_canceled(),
+        }
+    }
+}
+
+/// A set of flowers, each one running on its own thread.
+///
+/// Ids are assigned in increasing order and never reused within the pool,
+/// so an id always refers to the same job.
+///
+/// # Example
+///
+/// ```
+/// use flowync::{FlowerPoll, FlowerPool};
+///
+/// let mut pool: FlowerPool<u32, u32> = FlowerPool::new();
+/// for i in 0..3 {
+///     pool.spawn(move |handle| {
+///         let _ = handle.send(i);
+///         handle.ok(i * 2);
+///     });
+/// }
+///
+/// while !pool.is_empty() {
+///     pool.poll_all(|id, event| match event {
+///         FlowerPoll::Channel(value) => println!("{}: {}", id, value),
+///         FlowerPoll::Done(result) => println!("{}: {:?}", id, result),
+///         FlowerPoll::Idle => (),
+///     });
+/// }
+/// ```
+pub struct FlowerPool<SOME, OK, ERR = String>
+where
+    SOME: Send,
+    OK: Send,
+    ERR: Send,
+{
+    // Sorted by id, queued jobs already have their flower.
+    flowers: Vec<Flower<SOME, OK, ERR>>,
+    // Jobs waiting for a free slot in FIFO order, locked so `cancel` can drop them.
+    queue: Mutex<VecDeque<QueuedJob<SOME, OK, ERR>>>,
+    // The flowers whose job was started, a queued job dropped on cancelation never is.
+    started: HashSet<usize>,
+    max_concurrency: Option<usize>,
+    next_id: usize,
+    remove_completed: bool,
+}
+
+impl<SOME, OK, ERR> FlowerPool<SOME, OK, ERR>
+where
+    SOME: Send,
+    OK: Send,
+    ERR: Send,
+{
+    /// Create an empty pool, completed flowers are removed by default.
+    pub fn new() -> Self {
+        Self {
+            flowers: Vec::new(),
+            queue: Mutex::new(VecDeque::new()),
+            started: HashSet::new(),
+            max_concurrency: None,
+            next_id: 0,
+            remove_completed: true,
+        }
+    }
+
+    /// Run at most `n` jobs at once (at least one), `spawn` queues the others
+    /// and `poll_all` starts them in order as running jobs deliver their result.
+    pub fn max_concurrency(mut self, n: usize) -> Self {
+        self.max_concurrency = Some(n.max(1));
+        self
+    }
+
+    /// Whether a flower is removed from the pool once its result is polled,
+    /// otherwise it's kept until `remove`.
+    pub fn remove_completed(mut self, remove_completed: bool) -> Self {
+        self.remove_completed = remove_completed;
+        self
+    }
+
+    /// Spawn a job on a new thread with `Flower::spawn`, or queue it if `max_concurrency`
+    /// jobs are already running.
+    ///
+    /// Returns the id of the flower, a queued job can already be canceled with it.
+    pub fn spawn(&mut self, f: impl FnOnce(FlowerHandle<SOME, OK, ERR>) + Send + 'static) -> usize
+    where
+        SOME: 'static,
+        OK: 'static,
+        ERR: From<String> + 'static,
+    {
+        let id = self.next_id;
+        self.next_id += 1;
+        let flower = Flower::new(id);
+        if self.has_free_slot() {
+            flower.start(f);
+            self.started.insert(id);
+        } else {
+            self.queue.lock().push_back((id, Box::new(f)));
+        }
+        self.flowers.push(flower);
+        id
+    }
+
+    fn has_free_slot(&self) -> bool {
+        match self.max_concurrency {
+            Some(max) => self.running_count() < max,
+            None => true,
+        }
+    }
+
+    // Started jobs whose result wasn't polled yet.
+    fn running_count(&self) -> usize {
+        self.flowers
+            .iter()
+            .filter(|flower| flower.is_active() && self.started.contains(flower.id()))
+            .count()
+    }
+
+    // Start the queued jobs while there are free slots.
+    fn start_queued(&mut self)
+    where
+        SOME: 'static,
+        OK: 'static,
+        ERR: 'static,
+    {
+        while self.has_free_slot() {
+            let job = self.queue.lock().pop_front();
+            match job {
+                Some((id, job)) => {
+                    if let Some(flower) = self.get(id) {
+                        flower.start(job);
+                        self.started.insert(id);
+                    }
+                }
+                None => return,
+            }
+        }
+    }
+
+    // Drop the queued jobs whose flower was canceled, their cancelation is acknowledged
+    // so the error is delivered like the one of a running job.
+    fn drop_canceled_jobs(&self) {
+        self.queue.lock().retain(|(id, _)| match self.get(*id) {
+            Some(flower) if flower.is_canceled() => {
+                let handle = flower.handle();
+                handle.activate();
+                handle.cancel_ack();
+                false
+            }
+            Some(_) => true,
+            None => false,
+        });
+    }
+
+    /// Get the number of jobs waiting for a free slot, see `max_concurrency`.
+    pub fn queued_len(&self) -> usize {
+        self.queue.lock().len()
+    }
+
+    /// Poll every flower once, `on_event` is called with the id of each flower which
+    /// has a channel value or a result. Queued jobs are started for the freed slots.
+    pub fn poll_all(&mut self, mut on_event: impl FnMut(usize, FlowerPoll<SOME, OK, ERR>))
+    where
+        SOME: 'static,
+        OK: 'static,
+        ERR: 'static,
+    {
+        self.drop_canceled_jobs();
+        let remove_completed = self.remove_completed;
+        let started = &mut self.started;
+        self.flowers.retain(|flower| match flower.poll() {
+            FlowerPoll::Idle => true,
+            FlowerPoll::Done(result) => {
+                on_event(*flower.id(), FlowerPoll::Done(result));
+                if remove_completed {
+                    started.remove(flower.id());
+                }
+                !remove_completed
+            }
+            event => {
+                on_event(*flower.id(), event);
+                true
+            }
+        });
+        self.start_queued();
+    }
+
+    fn position(&self, id: usize) -> Option<usize> {
+        self.flowers
+            .binary_search_by_key(&id, |flower| *flower.id())
+            .ok()
+    }
+
+    /// Get the flower with the id.
+    pub fn get(&self, id: usize) -> Option<&Flower<SOME, OK, ERR>> {
+        self.position(id).map(|i| &self.flowers[i])
+    }
+
+    /// Iterate over the flowers in id order, including the queued ones.
+    pub fn iter(&self) -> impl Iterator<Item = &Flower<SOME, OK, ERR>> {
+        self.flowers.iter()
+    }
+
+    /// Iterate over the flowers whose job is running, see `active_count`.
+    pub fn iter_active(&self) -> impl Iterator<Item = &Flower<SOME, OK, ERR>> {
+        self.flowers
+            .iter()
+            .filter(|flower| flower.is_active() && !flower.result_is_ready())
+    }
+
+    /// Get a status snapshot of every flower in id order, e.g. for a job list redrawn
+    /// every frame. The channels aren't locked, see [`FlowerStatus`].
+    pub fn snapshot(&self) -> Vec<FlowerStatus> {
+        self.flowers.iter().map(FlowerStatus::of).collect()
+    }
+
+    /// Block until every flower of the pool delivered its result, without spinning.
+    ///
+    /// The channel values are given to `on_progress` so no worker stays blocked in `send`,
+    /// the results come in completion order with the id of their flower. Queued jobs are
+    /// started as usual, see `max_concurrency`. The notifiers of the flowers are still called
+    /// while collecting and put back afterwards.
+    pub fn collect_results(&mut self, on_progress: impl FnMut(usize, SOME)) -> Results<OK, ERR>
+    where
+        SOME: 'static,
+        OK: 'static,
+        ERR: 'static,
+    {
+        self.collect_until(None, on_progress).0
+    }
+
+    /// Same as `collect_results` but gives up after `timeout`, returns the results
+    /// collected so far and the ids of the flowers still outstanding.
+    pub fn collect_results_timeout(
+        &mut self,
+        timeout: Duration,
+        on_progress: impl FnMut(usize, SOME),
+    ) -> (Results<OK, ERR>, Vec<usize>)
+    where
+        SOME: 'static,
+        OK: 'static,
+        ERR: 'static,
+    {
+        self.collect_until(Some(Instant::now() + timeout), on_progress)
+    }
+
+    fn collect_until(
+        &mut self,
+        deadline: Option<Instant>,
+        mut on_progress: impl FnMut(usize, SOME),
+    ) -> (Results<OK, ERR>, Vec<usize>)
+    where
+        SOME: 'static,
+        OK: 'static,
+        ERR: 'static,
+    {
+        let signal = Arc::new(Signal::new());
+        // Chained to the notifiers of the user, which are put back afterwards.
+        let previous: Vec<(usize, Option<SharedNotifier>)> = self
+            .flowers
+            .iter()
+            .map(|flower| {
+                let signal = signal.clone();
+                let previous = flower.replace_notifier(None);
+                let user = previous.clone();
+                flower.set_notifier(move || {
+                    signal.notify();
+                    if let Some(n) = &user {
+                        n.notify();
+                    }
+                });
+                (*flower.id(), previous)
+            })
+            .collect();
+        let mut results = Vec::new();
+        while self.has_outstanding() {
+            // Read before polling, anything sent after the poll moves it.
+            let seen = signal.count();
+            let mut idle = true;
+            self.poll_all(|id, event| match event {
+                FlowerPoll::Channel(value) => {
+                    idle = false;
+                    on_progress(id, value);
+                }
+                FlowerPoll::Done(result) => {
+                    idle = false;
+                    results.push((id, result));
+                }
+                FlowerPoll::Idle => (),
+            });
+            if deadline.is_some_and(|deadline| Instant::now() >= deadline) {
+                break;
+            }
+            if idle && self.has_outstanding() {
+                // Up to the earliest hard deadline too, the next poll stores its error.
+                let hard_deadline = self
+                    .flowers
+                    .iter()
+                    .map(Flower::hard_deadline_at)
+                    .fold(None, earliest);
+                signal.wait_past(seen, earliest(deadline, hard_deadline));
+            }
+        }
+        for (id, previous) in previous {
+            if let Some(flower) = self.get(id) {
+                flower.replace_notifier(previous);
+            }
+        }
+        let outstanding = self
+            .flowers
+            .iter()
+            .filter(|flower| Self::is_outstanding(flower))
+            .map(|flower| *flower.id())
+            .collect();
+        (results, outstanding)
+    }
+
+    // Running, queued or with a result which isn't polled yet.
+    fn is_outstanding(flower: &Flower<SOME, OK, ERR>) -> bool {
+        flower.is_active() || flower.result_is_pending()
+    }
+
+    fn has_outstanding(&self) -> bool {
+        self.queued_len() > 0 || self.flowers.iter().any(Self::is_outstanding)
+    }
+
+    /// Cancel the flower with the id, returns false if it isn't in the pool.
+    ///
+    /// A queued job is dropped without running, its flower delivers the cancelation error.
+    pub fn cancel(&self, id: usize) -> bool {
+        let found = self.get(id).map(Flower::cancel).is_some();
+        self.drop_canceled_jobs();
+        found
+    }
+
+    /// Cancel every flower of the pool, e.g. on shutdown, senders blocked in `send` return
+    /// and the queued jobs are dropped.
+    ///
+    /// The flowers stay in the pool until their result is polled, see `active_count`.
+    pub fn cancel_all(&self) {
+        for flower in &self.flowers {
+            flower.cancel();
+        }
+        self.drop_canceled_jobs();
+    }
+
+    /// Cancel the flowers for which `pred` returns true, returns how many were canceled.
+    pub fn cancel_by(&self, pred: impl Fn(usize, &FlowerStatus) -> bool) -> usize {
+        let mut canceled = 0;
+        for flower in &self.flowers {
+            if pred(*flower.id(), &FlowerStatus::of(flower)) {
+                flower.cancel();
+                canceled += 1;
+            }
+        }
+        self.drop_canceled_jobs();
+        canceled
+    }
+
+    /// Get the number of flowers whose worker didn't set its result yet,
+    /// a canceled worker is counted until it acknowledged or returned.
+    pub fn active_count(&self) -> usize {
+        self.iter_active().count()
+    }
+
+    /// Remove the flower with the id from the pool, a queued job is dropped without running.
+    pub fn remove(&mut self, id: usize) -> Option<Flower<SOME, OK, ERR>> {
+        self.queue.lock().retain(|(queued, _)| *queued != id);
+        self.started.remove(&id);
+        self.position(id).map(|i| self.flowers.remove(i))
+    }
+
+    /// Get the number of flowers in the pool.
+    pub fn len(&self) -> usize {
+        self.flowers.len()
+    }
+
+    /// Check if the pool is empty.
+    pub fn is_empty(&self) -> bool {
+        self.flowers.is_empty()
+    }
+}
+
+impl<SOME, OK, ERR> Default for FlowerPool<SOME, OK, ERR>
+where
+    SOME: Send,
+    OK: Send,
+    ERR: Send,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<SOME, OK, ERR> Debug for FlowerPool<SOME, OK, ERR>
+where
+    SOME: Debug + Send,
+    OK: Debug + Send,
+    ERR: Debug + Send,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("FlowerPool")
+            .field("flowers", &self.flowers)
+            .field("queued_len", &self.queued_len())
+            .field("max_concurrency", &self.max_concurrency)
+            .field("next_id", &self.next_id)
+            .field("remove_completed", &self.remove_completed)
+            .finish()
+    }
+}
+
+// Counts the notifications of the flowers, so `collect_results` can block until the next one.
+struct Signal {
+    count: Mutex<u64>,
+    cvar: Condvar,
+}
+
+impl Signal {
+    fn new() -> Self {
+        Self {
+            count: Mutex::new(0),
+            cvar: Condvar::new(),
+        }
+    }
+
+    fn notify(&self) {
+        *self.count.lock() += 1;
+        self.cvar.notify_all();
+    }
+
+    fn count(&self) -> u64 {
+        *self.count.lock()
+    }
+
+    // Wait until notified after `seen` or the deadline passed.
+    fn wait_past(&self, seen: u64, deadline: Option<Instant>) {
+        let mut count = self.count.lock();
+        while *count == seen {
+            count = match deadline {
+                None => self.cvar.wait(count),
+                Some(deadline) => {
+                    let now = Instant::now();
+                    if now >= deadline {
+                        return;
+                    }
+                    self.cvar.wait_timeout(count, deadline - now)
+                }
+            };
+        }
+    }
+}
@@ -0,0 +1,150 @@
+use crate::{
+    flower::waker_key,
+    sync::{earliest, Instant},
+    Flower, FlowerPoll,
+};
+use core::sync::atomic::{AtomicUsize, Ordering};
+use std::{
+    sync::Arc,
+    task::{Wake, Waker},
+    thread::{self, Thread},
+};
+
+// Where the next scan starts, so a busy flower can't starve the others.
+static NEXT: AtomicUsize = AtomicUsize::new(0);
+
+fn start(len: usize) -> usize {
+    NEXT.fetch_add(1, Ordering::Relaxed) % len
+}
+
+/// Poll the flowers and get the first channel value or result available,
+/// along with the id of the flower it came from.
+///
+/// The scan starts at a rotating index for fairness, only the flower it was consumed from
+/// is polled (and its sender woken up). Returns `None` if nothing is available.
+pub fn select<SOME, OK, ERR>(
+    flowers: &[Flower<SOME, OK, ERR>],
+) -> Option<(usize, FlowerPoll<SOME, OK, ERR>)>
+where
+    SOME: Send,
+    OK: Send,
+    ERR: Send,
+{
+    if flowers.is_empty() {
+        return None;
+    }
+    let start = start(flowers.len());
+    flowers[start..]
+        .iter()
+        .chain(&flowers[..start])
+        .find_map(|flower| match flower.poll() {
+            FlowerPoll::Idle => None,
+            event => Some((*flower.id(), event)),
+        })
+}
+
+// Park until woken or the earliest hard deadline of the flowers, a poll stores its error then.
+fn park<'a, SOME, OK, ERR>(flowers: impl IntoIterator<Item = &'a Flower<SOME, OK, ERR>>)
+where
+    SOME: Send + 'a,
+    OK: Send + 'a,
+    ERR: Send + 'a,
+{
+    match flowers
+        .into_iter()
+        .map(Flower::hard_deadline_at)
+        .fold(None, earliest)
+    {
+        None => thread::park(),
+        Some(deadline) => thread::park_timeout(deadline.saturating_duration_since(Instant::now())),
+    }
+}
+
+struct ThreadWaker(Thread);
+
+impl Wake for ThreadWaker {
+    fn wake(self: Arc<Self>) {
+        self.0.unpark();
+    }
+}
+
+/// Block the current thread until any of the flowers has a channel value or a result,
+/// see [`select`].
+///
+/// Returns `None` right away if none of the flowers is active.
+pub fn select_wait<SOME, OK, ERR>(
+    flowers: &[Flower<SOME, OK, ERR>],
+) -> Option<(usize, FlowerPoll<SOME, OK, ERR>)>
+where
+    SOME: Send,
+    OK: Send,
+    ERR: Send,
+{
+    let key = waker_key();
+    let waker = Waker::from(Arc::new(ThreadWaker(thread::current())));
+    let selected = loop {
+        if !flowers.iter().any(Flower::is_active) {
+            break select(flowers);
+        }
+        let start = start(flowers.len());
+        let selected = flowers[start..]
+            .iter()
+            .chain(&flowers[..start])
+            .find_map(|flower| match flower.poll_or_register(key, &waker) {
+                FlowerPoll::Idle => None,
+                event => Some((*flower.id(), event)),
+            });
+        if selected.is_some() {
+            break selected;
+        }
+        park(flowers);
+    };
+    for flower in flowers {
+        flower.unregister_waker(key);
+    }
+    selected
+}
+
+/// Drive all the flowers to completion on the current thread
+/// and collect their results along with the ids, in the order they completed.
+///
+/// Channel values are forwarded to `on_channel` as they come, from every flower still running,
+/// so no worker is stuck in `send` while the others are waited on.
+/// The thread is parked while nothing is available.
+pub fn join_all<SOME, OK, ERR>(
+    flowers: Vec<Flower<SOME, OK, ERR>>,
+    mut on_channel: impl FnMut(usize, SOME),
+) -> Vec<(usize, Result<OK, ERR>)>
+where
+    SOME: Send,
+    OK: Send,
+    ERR: Send,
+{
+    let key = waker_key();
+    let waker = Waker::from(Arc::new(ThreadWaker(thread::current())));
+    let mut results = Vec::with_capacity(flowers.len());
+    let mut pending = flowers;
+    while !pending.is_empty() {
+        let mut received = false;
+        let mut i = 0;
+        while i < pending.len() {
+            match pending[i].poll_or_register(key, &waker) {
+                FlowerPoll::Idle => i += 1,
+                FlowerPoll::Channel(value) => {
+                    received = true;
+                    on_channel(*pending[i].id(), value);
+                }
+                FlowerPoll::Done(result) => {
+                    received = true;
+                    let flower = pending.swap_remove(i);
+                    flower.unregister_waker(key);
+                    results.push((*flower.id(), result));
+                }
+            }
+        }
+        if !received {
+            park(&pending);
+        }
+    }
+    results
+}
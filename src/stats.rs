@@ -0,0 +1,84 @@
+// Add `$n` (one by default) to the stats counter `$counter` of the flower `$state`.
+// Expands to nothing without the `stats` feature.
+macro_rules! stat {
+    ($state:expr, $counter:ident) => {
+        stat!($state, $counter, 1)
+    };
+    ($state:expr, $counter:ident, $n:expr) => {{
+        #[cfg(feature = "stats")]
+        $state
+            .stats
+            .$counter
+            .fetch_add($n, ::core::sync::atomic::Ordering::Relaxed);
+    }};
+}
+
+#[cfg(feature = "stats")]
+pub use counters::FlowerStats;
+#[cfg(feature = "stats")]
+pub(crate) use counters::Stats;
+
+#[cfg(feature = "stats")]
+mod counters {
+    use crate::sync::Instant;
+    use core::{
+        sync::atomic::{AtomicU64, Ordering},
+        time::Duration,
+    };
+
+    /// A snapshot of the counters of a flower, see [`Flower::stats`](crate::Flower::stats).
+    #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+    pub struct FlowerStats {
+        /// Number of values sent by the handles, delivered or not.
+        pub sends: u64,
+        /// Number of channel values taken by the consumers.
+        pub delivered: u64,
+        /// Number of channel values overwritten, skipped or dropped by the backpressure policy
+        /// before being polled, and of values refused by `send_throttled`.
+        pub dropped: u64,
+        /// Number of polls, e.g. by `then`, which found neither a value nor the result.
+        pub empty_polls: u64,
+        /// Time the blocking senders spent waiting for their value to be polled
+        /// or for room in the channel, and the async ones suspended.
+        pub blocked: Duration,
+    }
+
+    // Every counter is `Relaxed`, they carry no data along.
+    #[derive(Debug, Default)]
+    pub(crate) struct Stats {
+        pub(crate) sends: AtomicU64,
+        pub(crate) delivered: AtomicU64,
+        pub(crate) dropped: AtomicU64,
+        pub(crate) empty_polls: AtomicU64,
+        pub(crate) blocked_nanos: AtomicU64,
+    }
+
+    impl Stats {
+        pub(crate) fn blocked_since(&self, since: Instant) {
+            self.blocked_nanos
+                .fetch_add(since.elapsed().as_nanos() as u64, Ordering::Relaxed);
+        }
+
+        pub(crate) fn snapshot(&self) -> FlowerStats {
+            FlowerStats {
+                sends: self.sends.load(Ordering::Relaxed),
+                delivered: self.delivered.load(Ordering::Relaxed),
+                dropped: self.dropped.load(Ordering::Relaxed),
+                empty_polls: self.empty_polls.load(Ordering::Relaxed),
+                blocked: Duration::from_nanos(self.blocked_nanos.load(Ordering::Relaxed)),
+            }
+        }
+
+        pub(crate) fn reset(&self) {
+            for counter in [
+                &self.sends,
+                &self.delivered,
+                &self.dropped,
+                &self.empty_polls,
+                &self.blocked_nanos,
+            ] {
+                counter.store(0, Ordering::Relaxed);
+            }
+        }
+    }
+}
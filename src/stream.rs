@@ -0,0 +1,97 @@
+use crate::{flower::waker_key, Flower, FlowerEvent, FlowerPoll};
+use core::{
+    fmt::{self, Debug, Formatter},
+    pin::Pin,
+    task::{Context, Poll},
+};
+use futures_core::Stream;
+
+/// A stream of the flower events, see [`Flower::into_stream`].
+///
+/// Yields every channel value followed by the result, then the stream ends.
+pub struct FlowerStream<SOME, OK, ERR = String, ID = usize>
+where
+    SOME: Send,
+    OK: Send,
+    ERR: Send,
+    ID: Send + Sync,
+{
+    flower: Flower<SOME, OK, ERR, ID>,
+    key: usize,
+    done: bool,
+}
+
+impl<SOME, OK, ERR, ID> Flower<SOME, OK, ERR, ID>
+where
+    SOME: Send,
+    OK: Send,
+    ERR: Send,
+    ID: Send + Sync,
+{
+    /// Convert the flower into a stream of channel values and the final result.
+    pub fn into_stream(self) -> FlowerStream<SOME, OK, ERR, ID> {
+        FlowerStream {
+            flower: self,
+            key: waker_key(),
+            done: false,
+        }
+    }
+}
+
+impl<SOME, OK, ERR, ID> Stream for FlowerStream<SOME, OK, ERR, ID>
+where
+    SOME: Send,
+    OK: Send,
+    ERR: Send,
+    ID: Send + Sync,
+{
+    type Item = FlowerEvent<SOME, OK, ERR>;
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        if self.done {
+            return Poll::Ready(None);
+        }
+        match self.flower.poll_or_register(self.key, cx.waker()) {
+            FlowerPoll::Idle => Poll::Pending,
+            FlowerPoll::Channel(value) => Poll::Ready(Some(FlowerEvent::Channel(value))),
+            FlowerPoll::Done(result) => {
+                self.done = true;
+                Poll::Ready(Some(FlowerEvent::Done(result)))
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.done {
+            (0, Some(0))
+        } else {
+            (0, None)
+        }
+    }
+}
+
+impl<SOME, OK, ERR, ID> Drop for FlowerStream<SOME, OK, ERR, ID>
+where
+    SOME: Send,
+    OK: Send,
+    ERR: Send,
+    ID: Send + Sync,
+{
+    fn drop(&mut self) {
+        self.flower.unregister_waker(self.key);
+    }
+}
+
+impl<SOME, OK, ERR, ID> Debug for FlowerStream<SOME, OK, ERR, ID>
+where
+    SOME: Debug + Send,
+    OK: Debug + Send,
+    ERR: Debug + Send,
+    ID: Debug + Send + Sync,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("FlowerStream")
+            .field("flower", &self.flower)
+            .field("done", &self.done)
+            .finish()
+    }
+}
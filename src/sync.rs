@@ -0,0 +1,291 @@
+//! Mutex and condition variable used by the flower state, backed by std
+//! or by parking_lot with the `parking-lot` feature.
+//!
+//! `Instant` comes from web-time on wasm32, where std has no clock, and `CachePadded` keeps
+//! the hot atomics of the state off the lines written by the other side.
+
+#[cfg(feature = "park")]
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::time::Duration;
+#[cfg(feature = "tokio")]
+use std::{
+    future::Future,
+    sync::Arc,
+    task::{Context, Poll, Wake, Waker},
+    thread::{self, Thread},
+};
+
+#[cfg(not(target_arch = "wasm32"))]
+pub(crate) use std::time::Instant;
+#[cfg(target_arch = "wasm32")]
+pub(crate) use web_time::Instant;
+
+#[cfg(any(feature = "parking-lot", feature = "parking_lot"))]
+use parking_lot as imp;
+#[cfg(not(any(feature = "parking-lot", feature = "parking_lot")))]
+use std::sync as imp;
+
+pub(crate) use imp::MutexGuard;
+
+// The earlier of two deadlines, `None` being never.
+pub(crate) fn earliest(a: Option<Instant>, b: Option<Instant>) -> Option<Instant> {
+    match (a, b) {
+        (Some(a), Some(b)) => Some(a.min(b)),
+        (a, b) => a.or(b),
+    }
+}
+
+#[derive(Debug)]
+pub(crate) struct Mutex<T>(imp::Mutex<T>);
+
+impl<T> Mutex<T> {
+    pub(crate) fn new(value: T) -> Self {
+        Self(imp::Mutex::new(value))
+    }
+
+    /// Lock the mutex, recovering the guard if it was poisoned.
+    pub(crate) fn lock(&self) -> MutexGuard<'_, T> {
+        match self.lock_checked() {
+            Ok(guard) | Err(guard) => guard,
+        }
+    }
+
+    /// Lock the mutex, the guard is given back inside the error if it was poisoned.
+    ///
+    /// The poisoning is cleared, so it's only reported once.
+    #[cfg(not(any(feature = "parking-lot", feature = "parking_lot")))]
+    pub(crate) fn lock_checked(&self) -> Result<MutexGuard<'_, T>, MutexGuard<'_, T>> {
+        self.0.lock().map_err(|poisoned| {
+            self.0.clear_poison();
+            poisoned.into_inner()
+        })
+    }
+
+    /// Lock the mutex, parking_lot mutexes are never poisoned.
+    #[cfg(any(feature = "parking-lot", feature = "parking_lot"))]
+    pub(crate) fn lock_checked(&self) -> Result<MutexGuard<'_, T>, MutexGuard<'_, T>> {
+        Ok(self.0.lock())
+    }
+
+    /// Same as `lock_checked` but `None` if the mutex is already locked.
+    #[cfg(not(any(feature = "parking-lot", feature = "parking_lot")))]
+    pub(crate) fn try_lock_checked(&self) -> Option<Result<MutexGuard<'_, T>, MutexGuard<'_, T>>> {
+        match self.0.try_lock() {
+            Ok(guard) => Some(Ok(guard)),
+            Err(imp::TryLockError::Poisoned(poisoned)) => {
+                self.0.clear_poison();
+                Some(Err(poisoned.into_inner()))
+            }
+            Err(imp::TryLockError::WouldBlock) => None,
+        }
+    }
+
+    /// Same as `lock_checked` but `None` if the mutex is already locked.
+    #[cfg(any(feature = "parking-lot", feature = "parking_lot"))]
+    pub(crate) fn try_lock_checked(&self) -> Option<Result<MutexGuard<'_, T>, MutexGuard<'_, T>>> {
+        self.0.try_lock().map(Ok)
+    }
+}
+
+#[derive(Debug)]
+pub(crate) struct Condvar(imp::Condvar);
+
+impl Condvar {
+    pub(crate) fn new() -> Self {
+        Self(imp::Condvar::new())
+    }
+
+    pub(crate) fn notify_all(&self) {
+        self.0.notify_all();
+    }
+
+    /// Block until notified, the guard is relocked before returning.
+    #[cfg(not(any(feature = "parking-lot", feature = "parking_lot")))]
+    pub(crate) fn wait<'a, T>(&self, guard: MutexGuard<'a, T>) -> MutexGuard<'a, T> {
+        self.0
+            .wait(guard)
+            .unwrap_or_else(imp::PoisonError::into_inner)
+    }
+
+    /// Block until notified, the guard is relocked before returning.
+    #[cfg(any(feature = "parking-lot", feature = "parking_lot"))]
+    pub(crate) fn wait<'a, T>(&self, mut guard: MutexGuard<'a, T>) -> MutexGuard<'a, T> {
+        self.0.wait(&mut guard);
+        guard
+    }
+
+    /// Block until notified or the timeout elapsed, the guard is relocked before returning.
+    #[cfg(not(any(feature = "parking-lot", feature = "parking_lot")))]
+    pub(crate) fn wait_timeout<'a, T>(
+        &self,
+        guard: MutexGuard<'a, T>,
+        timeout: Duration,
+    ) -> MutexGuard<'a, T> {
+        self.0
+            .wait_timeout(guard, timeout)
+            .unwrap_or_else(imp::PoisonError::into_inner)
+            .0
+    }
+
+    /// Block until notified or the timeout elapsed, the guard is relocked before returning.
+    #[cfg(any(feature = "parking-lot", feature = "parking_lot"))]
+    pub(crate) fn wait_timeout<'a, T>(
+        &self,
+        mut guard: MutexGuard<'a, T>,
+        timeout: Duration,
+    ) -> MutexGuard<'a, T> {
+        self.0.wait_for(&mut guard, timeout);
+        guard
+    }
+}
+
+#[cfg(feature = "tokio")]
+struct Unparker(Thread);
+
+#[cfg(feature = "tokio")]
+impl Wake for Unparker {
+    fn wake(self: Arc<Self>) {
+        self.0.unpark();
+    }
+}
+
+/// Run the future on the current thread until it completes, `None` if `deadline` passed first.
+#[cfg(feature = "tokio")]
+pub(crate) fn block_on<F: Future>(fut: F, deadline: Option<Instant>) -> Option<F::Output> {
+    let mut fut = core::pin::pin!(fut);
+    let waker = Waker::from(Arc::new(Unparker(thread::current())));
+    let mut cx = Context::from_waker(&waker);
+    loop {
+        if let Poll::Ready(output) = fut.as_mut().poll(&mut cx) {
+            return Some(output);
+        }
+        match deadline {
+            Some(deadline) => {
+                let now = Instant::now();
+                if now >= deadline {
+                    return None;
+                }
+                thread::park_timeout(deadline - now);
+            }
+            None => thread::park(),
+        }
+    }
+}
+
+/// The senders blocked on a flower with the `park` feature: the first one parks its thread
+/// and is unparked directly, the others wait on the condition variable, which is only
+/// notified while any of them does.
+#[cfg(feature = "park")]
+#[derive(Debug)]
+pub(crate) struct Parker {
+    thread: Mutex<Option<std::thread::Thread>>,
+    parked: AtomicBool,
+    waiters: AtomicUsize,
+}
+
+#[cfg(feature = "park")]
+impl Parker {
+    pub(crate) fn new() -> Self {
+        Self {
+            thread: Mutex::new(None),
+            parked: AtomicBool::new(false),
+            waiters: AtomicUsize::new(0),
+        }
+    }
+
+    /// Block until woken or the timeout elapsed, the guard is released meanwhile
+    /// and relocked with `relock` before returning.
+    pub(crate) fn wait<'a, T>(
+        &self,
+        cvar: &Condvar,
+        guard: MutexGuard<'a, T>,
+        timeout: Option<Duration>,
+        relock: impl FnOnce() -> MutexGuard<'a, T>,
+    ) -> MutexGuard<'a, T> {
+        // Registered under the guard, so a waker which takes it afterwards sees this thread.
+        if !self.register() {
+            self.waiters.fetch_add(1, Ordering::SeqCst);
+            let guard = match timeout {
+                None => cvar.wait(guard),
+                Some(timeout) => cvar.wait_timeout(guard, timeout),
+            };
+            self.waiters.fetch_sub(1, Ordering::SeqCst);
+            return guard;
+        }
+        drop(guard);
+        match timeout {
+            None => std::thread::park(),
+            Some(timeout) => std::thread::park_timeout(timeout),
+        }
+        let guard = relock();
+        self.unregister();
+        guard
+    }
+
+    fn register(&self) -> bool {
+        let mut thread = self.thread.lock();
+        if thread.is_some() {
+            return false;
+        }
+        *thread = Some(std::thread::current());
+        self.parked.store(true, Ordering::SeqCst);
+        true
+    }
+
+    // Timed out or woken spuriously, this thread may still be registered.
+    fn unregister(&self) {
+        if !self.parked.load(Ordering::SeqCst) {
+            return;
+        }
+        let mut thread = self.thread.lock();
+        if thread
+            .as_ref()
+            .is_some_and(|thread| thread.id() == std::thread::current().id())
+        {
+            *thread = None;
+            self.parked.store(false, Ordering::SeqCst);
+        }
+    }
+
+    /// Wake every blocked sender, they check again whether they can go on.
+    pub(crate) fn notify_all(&self, cvar: &Condvar) {
+        if self.parked.load(Ordering::SeqCst) {
+            let thread = {
+                let mut thread = self.thread.lock();
+                self.parked.store(false, Ordering::SeqCst);
+                thread.take()
+            };
+            if let Some(thread) = thread {
+                thread.unpark();
+            }
+        }
+        if self.waiters.load(Ordering::SeqCst) > 0 {
+            cvar.notify_all();
+        }
+    }
+}
+
+/// Aligns and pads `T` to its own cache line, so writes next to it don't invalidate it.
+///
+/// x86_64 prefetches cache lines in pairs and the big aarch64 cores have 128 bytes lines.
+#[cfg_attr(any(target_arch = "x86_64", target_arch = "aarch64"), repr(align(128)))]
+#[cfg_attr(
+    not(any(target_arch = "x86_64", target_arch = "aarch64")),
+    repr(align(64))
+)]
+#[derive(Debug)]
+pub(crate) struct CachePadded<T>(T);
+
+impl<T> CachePadded<T> {
+    pub(crate) const fn new(value: T) -> Self {
+        Self(value)
+    }
+}
+
+impl<T> std::ops::Deref for CachePadded<T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        &self.0
+    }
+}
@@ -0,0 +1,54 @@
+use crate::{Flower, FlowerHandle};
+use core::{
+    fmt::Debug,
+    future::{poll_fn, Future},
+    task::Poll,
+};
+
+impl<SOME, OK, ERR, ID> Flower<SOME, OK, ERR, ID>
+where
+    SOME: Send + 'static,
+    OK: Send + 'static,
+    ERR: Send + 'static,
+    ID: Send + Sync + 'static,
+{
+    /// Create a flower and `tokio::spawn` the future returned by `f`,
+    /// the flower is activated before `f` runs.
+    ///
+    /// The output of the future is set with `ok` or `err`, a panic is caught
+    /// and reported as the error "worker panicked: {message}". Must be called within a tokio runtime.
+    pub fn spawn_async<F, Fut>(id: ID, f: F) -> Self
+    where
+        F: FnOnce(FlowerHandle<SOME, OK, ERR, ID>) -> Fut,
+        Fut: Future<Output = Result<OK, ERR>> + Send + 'static,
+        ERR: From<String>,
+        ID: Clone + Debug,
+    {
+        let flower = Self::new(id);
+        let handle = flower.handle();
+        handle.activate();
+        let worker = handle.clone();
+        let mut fut = Box::pin(f(handle.clone()));
+        let task = tokio::spawn(async move {
+            poll_fn(|cx| match worker.catch_panic(|| fut.as_mut().poll(cx)) {
+                Some(poll) => poll.map(Some),
+                None => Poll::Ready(None),
+            })
+            .await
+        });
+        let id = format!("{:?}", flower.id());
+        tokio::spawn(async move {
+            match task.await {
+                Ok(Some(Ok(value))) => handle.ok(value),
+                Ok(Some(Err(value))) => handle.err(value),
+                // The panic is already reported.
+                Ok(None) => (),
+                Err(_) => handle.err(ERR::from(format!(
+                    "the flower with id: {} task canceled",
+                    id
+                ))),
+            }
+        });
+        flower
+    }
+}
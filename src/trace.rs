@@ -0,0 +1,31 @@
+// Emit an event in the span of the flower `$state`, `$level` is a `tracing::Level` constant.
+// Expands to nothing without the `tracing` feature, the arguments aren't even evaluated.
+macro_rules! flower_event {
+    ($state:expr, $level:ident, $($arg:tt)+) => {{
+        #[cfg(feature = "tracing")]
+        ::tracing::event!(parent: &$state.span, ::tracing::Level::$level, $($arg)+);
+    }};
+}
+
+#[cfg(feature = "tracing")]
+pub(crate) use value::TracedValue;
+
+#[cfg(feature = "tracing")]
+mod value {
+    use core::fmt::{self, Display, Formatter};
+
+    /// A channel value in an event, formatted with `Debug` once enabled by `Flower::trace_values`.
+    pub(crate) struct TracedValue<'a, SOME>(
+        pub(crate) Option<&'a fn(&SOME, &mut Formatter<'_>) -> fmt::Result>,
+        pub(crate) &'a SOME,
+    );
+
+    impl<'a, SOME> Display for TracedValue<'a, SOME> {
+        fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+            match self.0 {
+                Some(debug) => debug(self.1, f),
+                None => f.write_str(".."),
+            }
+        }
+    }
+}
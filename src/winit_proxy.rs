@@ -0,0 +1,52 @@
+//! Wake a [`winit`](https://docs.rs/winit) event loop when a flower has news.
+use crate::{sync::Mutex, Flower};
+use ::winit::event_loop::EventLoopProxy;
+
+impl<SOME, OK, ERR, ID> Flower<SOME, OK, ERR, ID>
+where
+    SOME: Send,
+    OK: Send,
+    ERR: Send,
+    ID: Send + Sync,
+{
+    /// Send `event` to the event loop of `proxy` on every `send`, `ok` and `err`,
+    /// so a loop waiting on OS events wakes up and polls the flower.
+    ///
+    /// Only one event is queued until the flower is polled again, poll until `Idle`
+    /// when it arrives. Installed as the notifier of the flower, see `Flower::set_wake_notifier`.
+    ///
+    /// # Quick Example:
+    ///
+    ///```no_run
+    ///use flowync::{Flower, FlowerPoll};
+    ///use winit::event_loop::EventLoop;
+    ///
+    ///#[derive(Debug, Clone)]
+    ///struct Wake;
+    ///
+    ///let event_loop = EventLoop::<Wake>::with_user_event().build().unwrap();
+    ///let flower: Flower<u32, ()> = Flower::new(1);
+    ///flower.notify_event_loop(event_loop.create_proxy(), Wake);
+    ///
+    ///// In `ApplicationHandler::user_event`:
+    ///loop {
+    ///    match flower.poll() {
+    ///        FlowerPoll::Idle => break,
+    ///        FlowerPoll::Channel(percent) => println!("{}%", percent),
+    ///        FlowerPoll::Done(result) => println!("{:?}", result),
+    ///    }
+    ///}
+    ///```
+    pub fn notify_event_loop<T>(&self, proxy: EventLoopProxy<T>, event: T)
+    where
+        T: Clone + Send + 'static,
+    {
+        // Neither the proxy nor the event need to be `Sync`.
+        let wake = Mutex::new((proxy, event));
+        self.set_wake_notifier(move || {
+            let wake = wake.lock();
+            // Nothing to wake once the event loop exited.
+            let _ = wake.0.send_event(wake.1.clone());
+        });
+    }
+}
@@ -0,0 +1,132 @@
+#![cfg(feature = "async")]
+use flowync::AsyncFlower;
+use std::future::Future;
+use std::sync::{
+    atomic::{AtomicU32, Ordering},
+    Arc,
+};
+use std::task::{Context, Wake, Waker};
+use std::time::Duration;
+
+struct CountingWaker(AtomicU32);
+
+impl Wake for CountingWaker {
+    fn wake(self: Arc<Self>) {
+        self.0.fetch_add(1, Ordering::SeqCst);
+    }
+}
+
+#[tokio::test]
+async fn send_suspends_until_consumed() {
+    let flower: AsyncFlower<u32, u32> = AsyncFlower::new(1);
+    let consumed = Arc::new(AtomicU32::new(0));
+    let handle = flower.handle();
+    handle.activate();
+    let worker = tokio::spawn({
+        let consumed = consumed.clone();
+        async move {
+            for i in 0..3 {
+                handle.send_async(i).await;
+                // The value is consumed once the send resolves.
+                assert_eq!(consumed.load(Ordering::SeqCst), i + 1);
+            }
+            handle.ok(10);
+        }
+    });
+
+    let mut values = Vec::new();
+    while let Some(value) = flower.next().await {
+        consumed.fetch_add(1, Ordering::SeqCst);
+        values.push(value);
+        tokio::time::sleep(Duration::from_millis(5)).await;
+    }
+    assert_eq!(values, vec![0, 1, 2]);
+    assert_eq!(flower.result().await, Ok(10));
+    assert!(!flower.is_active());
+    worker.await.unwrap();
+}
+
+#[tokio::test]
+async fn result_discards_values() {
+    let flower: AsyncFlower<u32, ()> = AsyncFlower::new(2);
+    let handle = flower.handle();
+    handle.activate();
+    tokio::spawn(async move {
+        for i in 0..10 {
+            handle.send_async(i).await;
+        }
+        handle.err("failed");
+    });
+    assert_eq!(flower.result().await, Err("failed".to_string()));
+}
+
+#[tokio::test]
+async fn dropped_handle_without_result() {
+    let flower: AsyncFlower<(), ()> = AsyncFlower::new(3);
+    let handle = flower.handle();
+    handle.activate();
+    drop(handle);
+    assert_eq!(
+        flower.result().await,
+        Err("flower handle(s) dropped without result, id: 3".to_string())
+    );
+}
+
+#[tokio::test]
+async fn dropped_flower_releases_sender() {
+    let flower: AsyncFlower<u32, ()> = AsyncFlower::new(4);
+    let handle = flower.handle();
+    let sender = tokio::spawn(async move { handle.send_async(1).await });
+    tokio::time::sleep(Duration::from_millis(10)).await;
+    drop(flower);
+    sender.await.unwrap();
+}
+
+#[tokio::test]
+async fn new_handle_keeps_the_cancelation() {
+    let flower: AsyncFlower<(), ()> = AsyncFlower::new(5);
+    flower.cancel();
+    let handle = flower.handle();
+    assert!(handle.should_cancel());
+    flower.reset_cancel();
+    assert!(!handle.should_cancel());
+}
+
+#[tokio::test]
+async fn cancel_releases_sender() {
+    let flower: AsyncFlower<u32, ()> = AsyncFlower::new(6);
+    let handle = flower.handle();
+    handle.activate();
+    let sender = tokio::spawn(async move {
+        handle.send_async(1).await;
+        handle.ok(());
+    });
+    tokio::time::sleep(Duration::from_millis(10)).await;
+    flower.cancel();
+    tokio::time::timeout(Duration::from_secs(5), sender)
+        .await
+        .expect("the sender returns")
+        .unwrap();
+    // The value was taken back.
+    assert_eq!(flower.next().await, None);
+    assert_eq!(flower.result().await, Ok(()));
+}
+
+#[tokio::test]
+async fn dropped_send_is_not_woken() {
+    let flower: AsyncFlower<u32, ()> = AsyncFlower::new(7);
+    let handle = flower.handle();
+    handle.activate();
+    let count = Arc::new(CountingWaker(AtomicU32::new(0)));
+    let waker = Waker::from(count.clone());
+    let mut send = Box::pin(handle.send_async(1));
+    assert!(send
+        .as_mut()
+        .poll(&mut Context::from_waker(&waker))
+        .is_pending());
+    drop(send);
+
+    assert_eq!(flower.next().await, Some(1));
+    flower.cancel();
+    assert_eq!(count.0.load(Ordering::SeqCst), 0);
+}
@@ -0,0 +1,105 @@
+#![cfg(feature = "atomic")]
+use flowync::{AtomicFlower, FlowerPoll};
+
+#[test]
+fn latest_value_wins() {
+    let flower: AtomicFlower<u32, ()> = AtomicFlower::new(1);
+    let handle = flower.handle();
+    handle.activate();
+    assert!(!flower.channel_is_present());
+    handle.send_latest(1);
+    handle.send_latest(2);
+    assert!(flower.channel_is_present());
+    assert_eq!(flower.latest(), Some(2));
+    assert_eq!(flower.latest(), None);
+    assert!(matches!(flower.poll(), FlowerPoll::Idle));
+}
+
+#[test]
+fn value_sent_before_the_result_comes_first() {
+    let flower: AtomicFlower<u32, String> = AtomicFlower::new(2);
+    let handle = flower.handle();
+    handle.activate();
+    handle.send_latest(7);
+    handle.ok("done".into());
+    assert!(matches!(flower.poll(), FlowerPoll::Channel(7)));
+    match flower.poll() {
+        FlowerPoll::Done(result) => assert_eq!(result.unwrap(), "done"),
+        _ => panic!("the result is ready"),
+    }
+    assert!(!flower.is_active());
+}
+
+#[test]
+fn concurrent_values_increase_up_to_the_last_one() {
+    for run in 0..50 {
+        let flower: AtomicFlower<u64, ()> = AtomicFlower::new(run);
+        let handle = flower.handle();
+        handle.activate();
+        let worker = std::thread::spawn(move || {
+            for i in 1..=10_000 {
+                handle.send_latest(i);
+            }
+            handle.ok(());
+        });
+        let mut last = 0;
+        let mut done = false;
+        while !done {
+            flower.then(
+                |value| {
+                    if let Some(i) = value {
+                        assert!(i > last);
+                        last = i;
+                    }
+                },
+                |_| done = true,
+            );
+        }
+        worker.join().unwrap();
+        assert_eq!(last, 10_000);
+    }
+}
+
+#[test]
+fn cancel_reaches_the_handle() {
+    let flower: AtomicFlower<u8, ()> = AtomicFlower::new(3);
+    let handle = flower.handle();
+    handle.activate();
+    assert!(!handle.should_cancel());
+    flower.cancel();
+    assert!(flower.is_canceled());
+    assert!(handle.should_cancel());
+    assert!(handle.inner().should_cancel());
+}
+
+#[test]
+fn reset_empties_the_slot_and_ignores_stale_handles() {
+    let flower: AtomicFlower<u8, ()> = AtomicFlower::new(4);
+    let stale = flower.handle();
+    stale.activate();
+    stale.send_latest(1);
+    assert!(flower.reset().is_err());
+    stale.ok(());
+    flower.reset().unwrap();
+    assert!(!flower.channel_is_present());
+    stale.send_latest(2);
+    assert!(!flower.channel_is_present());
+
+    let handle = flower.handle();
+    handle.activate();
+    handle.send_latest(3);
+    assert_eq!(flower.latest(), Some(3));
+}
+
+#[test]
+fn try_result_discards_the_slot() {
+    let flower: AtomicFlower<u8, u8> = AtomicFlower::new(5);
+    let handle = flower.handle();
+    handle.activate();
+    handle.send_latest(1);
+    assert!(flower.try_result().is_none());
+    assert!(flower.channel_is_present());
+    handle.ok(2);
+    assert_eq!(flower.try_result().unwrap().unwrap(), 2);
+    assert!(!flower.channel_is_present());
+}
@@ -0,0 +1,114 @@
+use flowync::Flower;
+use std::{
+    future::Future,
+    pin::Pin,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    },
+    task::{Context, Wake, Waker},
+    time::Duration,
+};
+
+struct CountingWaker(AtomicUsize);
+
+impl Wake for CountingWaker {
+    fn wake(self: Arc<Self>) {
+        self.0.fetch_add(1, Ordering::SeqCst);
+    }
+}
+
+fn counting_waker() -> (Arc<CountingWaker>, Waker) {
+    let count = Arc::new(CountingWaker(AtomicUsize::new(0)));
+    (count.clone(), Waker::from(count))
+}
+
+fn poll_pending<F: Future + Unpin>(future: &mut F, waker: &Waker) {
+    let mut cx = Context::from_waker(waker);
+    assert!(Pin::new(future).poll(&mut cx).is_pending());
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn await_result_discards_channel() {
+    let flower = Flower::<u32, u32>::new(1);
+    std::thread::spawn({
+        let handle = flower.handle();
+        handle.activate();
+        move || {
+            for i in 0..10 {
+                handle.send(i).unwrap();
+            }
+            handle.ok(42);
+        }
+    });
+
+    assert_eq!(flower.await_result().await, Ok(42));
+    assert!(!flower.is_active());
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn into_future() {
+    let flower = Flower::<(), u32>::new(1);
+    tokio::spawn({
+        let handle = flower.handle();
+        handle.activate();
+        async move {
+            tokio::time::sleep(Duration::from_millis(10)).await;
+            handle.ok(7);
+        }
+    });
+
+    assert_eq!(flower.await, Ok(7));
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn await_result_cancel_safe() {
+    let flower = Flower::<(), u32>::new(1);
+    let handle = flower.handle();
+    handle.activate();
+
+    let timeout = tokio::time::timeout(Duration::from_millis(10), flower.await_result()).await;
+    assert!(timeout.is_err());
+
+    handle.ok(7);
+    let mut result = None;
+    flower.then(|_| (), |r| result = Some(r));
+    assert_eq!(result, Some(Ok(7)));
+}
+
+#[test]
+fn every_pending_future_is_woken() {
+    let flower = Flower::<(), u32>::new(1);
+    let handle = flower.handle();
+    handle.activate();
+    let (first, first_waker) = counting_waker();
+    let (second, second_waker) = counting_waker();
+    let mut first_future = flower.await_result();
+    let mut second_future = flower.await_result();
+    poll_pending(&mut first_future, &first_waker);
+    poll_pending(&mut second_future, &second_waker);
+    // Registered once per future however often it's polled.
+    poll_pending(&mut first_future, &first_waker);
+
+    handle.ok(1);
+    assert_eq!(first.0.load(Ordering::SeqCst), 1);
+    assert_eq!(second.0.load(Ordering::SeqCst), 1);
+}
+
+#[test]
+fn dropped_future_is_not_woken() {
+    let flower = Flower::<(), u32>::new(1);
+    let handle = flower.handle();
+    handle.activate();
+    let (dropped, dropped_waker) = counting_waker();
+    let (kept, kept_waker) = counting_waker();
+    let mut dropped_future = flower.await_result();
+    let mut kept_future = flower.await_result();
+    poll_pending(&mut dropped_future, &dropped_waker);
+    poll_pending(&mut kept_future, &kept_waker);
+    drop(dropped_future);
+
+    handle.ok(1);
+    assert_eq!(dropped.0.load(Ordering::SeqCst), 0);
+    assert_eq!(kept.0.load(Ordering::SeqCst), 1);
+}
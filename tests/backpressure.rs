@@ -0,0 +1,82 @@
+use flowync::{Backpressure, Flower, FlowerBuilder};
+use std::time::Duration;
+
+fn drain(flower: &Flower<u32, ()>) -> Vec<u32> {
+    let mut received = Vec::new();
+    let mut exit = false;
+    while !exit {
+        flower.then(
+            |channel| {
+                if let Some(value) = channel {
+                    received.push(value);
+                }
+            },
+            |_| exit = true,
+        );
+    }
+    received
+}
+
+fn outrun(backpressure: Backpressure) -> Flower<u32, ()> {
+    let flower = FlowerBuilder::new(1)
+        .capacity(4)
+        .backpressure(backpressure)
+        .build();
+    let handle = flower.handle();
+    handle.activate();
+    for i in 0..10 {
+        handle.send(i).unwrap();
+    }
+    handle.ok(());
+    flower
+}
+
+#[test]
+fn drop_oldest() {
+    let flower = outrun(Backpressure::DropOldest);
+    assert_eq!(flower.channel_len(), 4);
+    assert_eq!(drain(&flower), vec![6, 7, 8, 9]);
+}
+
+#[test]
+fn drop_newest() {
+    let flower = outrun(Backpressure::DropNewest);
+    assert_eq!(flower.channel_len(), 4);
+    assert_eq!(drain(&flower), vec![0, 1, 2, 3]);
+}
+
+#[test]
+fn block() {
+    let flower: Flower<u32, ()> = FlowerBuilder::new(1).capacity(4).build();
+    let worker = std::thread::spawn({
+        let handle = flower.handle();
+        handle.activate();
+        move || {
+            for i in 0..50 {
+                handle.send(i).unwrap();
+            }
+            handle.ok(());
+        }
+    });
+
+    // Let the producer fill up the queue.
+    std::thread::sleep(Duration::from_millis(50));
+    assert_eq!(flower.channel_len(), 4);
+
+    let mut received = Vec::new();
+    let mut exit = false;
+    while !exit {
+        assert!(flower.channel_len() <= 4);
+        flower.then(
+            |channel| {
+                if let Some(value) = channel {
+                    received.push(value);
+                }
+            },
+            |_| exit = true,
+        );
+    }
+
+    worker.join().unwrap();
+    assert_eq!(received, (0..50).collect::<Vec<_>>());
+}
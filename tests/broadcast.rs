@@ -0,0 +1,128 @@
+use flowync::{Flower, FlowerBuilder, FlowerPoll};
+use std::future::Future;
+use std::sync::{
+    atomic::{AtomicBool, Ordering},
+    Arc,
+};
+use std::task::{Context, Waker};
+use std::time::Duration;
+
+#[test]
+fn every_clone_sees_every_value() {
+    let status: Flower<u32, String> = FlowerBuilder::new(1).build_broadcast();
+    let log = status.clone();
+    let handle = status.handle();
+    handle.activate();
+    std::thread::spawn(move || {
+        for i in 0..100 {
+            handle.send(i).unwrap();
+        }
+        handle.ok("done".into());
+    });
+
+    let (mut seen_status, mut seen_log) = (Vec::new(), Vec::new());
+    let (mut status_done, mut log_done) = (None, None);
+    while status_done.is_none() || log_done.is_none() {
+        if status_done.is_none() {
+            status.then(|value| seen_status.extend(value), |r| status_done = Some(r));
+        }
+        if log_done.is_none() {
+            log.then(|value| seen_log.extend(value), |r| log_done = Some(r));
+        }
+    }
+
+    assert_eq!(seen_status, (0..100).collect::<Vec<_>>());
+    assert_eq!(seen_log, seen_status);
+    assert_eq!(status_done, Some(Ok("done".to_string())));
+    assert_eq!(log_done, status_done);
+    assert!(!status.is_active());
+    assert!(matches!(status.poll(), FlowerPoll::Idle));
+}
+
+#[test]
+fn sender_waits_for_all_consumers() {
+    let flower: Flower<u32, ()> = FlowerBuilder::new(2).build_broadcast();
+    let other = flower.clone();
+    let released = Arc::new(AtomicBool::new(false));
+    let handle = flower.handle();
+    handle.activate();
+    std::thread::spawn({
+        let released = released.clone();
+        move || {
+            handle.send(7).unwrap();
+            released.store(true, Ordering::SeqCst);
+            handle.ok(());
+        }
+    });
+
+    while flower.channel_len() == 0 {
+        std::thread::sleep(Duration::from_millis(1));
+    }
+    assert!(matches!(flower.poll(), FlowerPoll::Channel(7)));
+    std::thread::sleep(Duration::from_millis(20));
+    assert!(!released.load(Ordering::SeqCst));
+
+    assert!(matches!(other.poll(), FlowerPoll::Channel(7)));
+    while !released.load(Ordering::SeqCst) {
+        std::thread::sleep(Duration::from_millis(1));
+    }
+}
+
+#[test]
+fn dropped_clone_releases_sender() {
+    let flower: Flower<u32, ()> = FlowerBuilder::new(3).build_broadcast();
+    let other = flower.clone();
+    let handle = flower.handle();
+    handle.activate();
+    let sender = std::thread::spawn(move || {
+        handle.send(1).unwrap();
+        handle.ok(());
+    });
+
+    while flower.channel_len() == 0 {
+        std::thread::sleep(Duration::from_millis(1));
+    }
+    assert!(matches!(flower.poll(), FlowerPoll::Channel(1)));
+    drop(other);
+    sender.join().unwrap();
+    assert!(matches!(flower.poll(), FlowerPoll::Done(Ok(()))));
+}
+
+#[test]
+fn observed_result_waits_for_the_next_one() {
+    let flower: Flower<u32, ()> = FlowerBuilder::new(4).build_broadcast();
+    let other = flower.clone();
+    let handle = flower.handle();
+    handle.activate();
+    handle.ok(());
+    assert!(matches!(flower.poll(), FlowerPoll::Done(Ok(()))));
+
+    // Still ready for `other`, this clone has nothing left and waits.
+    assert_eq!(flower.wait_timeout(Duration::from_millis(20)), None);
+    let mut future = Box::pin(flower.await_result());
+    let mut cx = Context::from_waker(Waker::noop());
+    assert!(future.as_mut().poll(&mut cx).is_pending());
+    assert_eq!(other.wait_timeout(Duration::from_millis(20)), Some(Ok(())));
+}
+
+#[test]
+fn try_result_only_skips_the_values_of_its_clone() {
+    let flower: Flower<u32, ()> = FlowerBuilder::new(5).capacity(4).build_broadcast();
+    let other = flower.clone();
+    let handle = flower.handle();
+    handle.activate();
+    handle.send(1).unwrap();
+    handle.send(2).unwrap();
+
+    assert_eq!(flower.try_result(), None);
+    assert!(matches!(flower.poll(), FlowerPoll::Idle));
+    assert!(matches!(other.poll(), FlowerPoll::Channel(1)));
+    assert_eq!(other.try_result(), None);
+    assert_eq!(flower.channel_len(), 0);
+
+    handle.send(3).unwrap();
+    handle.ok(());
+    assert!(matches!(other.poll(), FlowerPoll::Channel(3)));
+    assert_eq!(flower.try_result(), Some(Ok(())));
+    assert_eq!(other.try_result(), Some(Ok(())));
+}
@@ -0,0 +1,52 @@
+use flowync::Flower;
+
+#[test]
+fn buffered_channel_keeps_every_value() {
+    let flower = Flower::<u32, String>::new_buffered(1);
+    let worker = std::thread::spawn({
+        let handle = flower.handle();
+        handle.activate();
+        move || {
+            for i in 0..100 {
+                // Never blocks on a buffered channel.
+                handle.send(i).unwrap();
+            }
+            handle.ok("Ok".to_string());
+        }
+    });
+    worker.join().unwrap();
+
+    let mut received = Vec::new();
+    let mut exit = false;
+    while !exit {
+        flower.then(
+            |channel| {
+                if let Some(value) = channel {
+                    received.push(value);
+                }
+            },
+            |result| {
+                assert_eq!(result.unwrap(), "Ok");
+                exit = true;
+            },
+        );
+    }
+
+    assert_eq!(received, (0..100).collect::<Vec<_>>());
+    assert!(!flower.channel_is_present());
+    assert!(!flower.is_active());
+}
+
+#[test]
+fn buffered_channel_dropped_flower() {
+    let flower = Flower::<Vec<u8>, ()>::new_buffered(1);
+    let handle = flower.handle();
+    drop(flower);
+    let worker = std::thread::spawn(move || {
+        for _ in 0..10 {
+            assert!(handle.send(vec![0; 16]).is_err());
+        }
+        handle.ok(());
+    });
+    worker.join().unwrap();
+}
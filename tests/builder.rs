@@ -0,0 +1,83 @@
+use flowync::{Backpressure, Flower, FlowerBuilder};
+use std::time::Duration;
+
+// Plain data, e.g. kept in an app config.
+struct Config {
+    jobs: FlowerBuilder,
+}
+
+#[test]
+fn defaults_match_new() {
+    let flower: Flower<u32, ()> = Flower::builder(1).build();
+    assert_eq!(flower.label().as_deref(), None);
+    let handle = flower.handle();
+    handle.activate();
+    // Unbuffered, the value waits to be polled.
+    assert!(handle.send_timeout(1, Duration::from_millis(10)).is_err());
+    assert_eq!(Flower::builder(1), FlowerBuilder::new(1));
+}
+
+#[test]
+fn capacity_bounds_the_channel() {
+    let flower: Flower<u32, ()> = Flower::builder(2).capacity(2).build();
+    let handle = flower.handle();
+    handle.activate();
+    handle.send(1).unwrap();
+    handle.send(2).unwrap();
+    assert!(handle.send_timeout(3, Duration::from_millis(10)).is_err());
+    assert_eq!(flower.channel_len(), 2);
+}
+
+#[test]
+fn backpressure_reaches_the_channel() {
+    let flower: Flower<u32, ()> = Flower::builder(3)
+        .capacity(1)
+        .backpressure(Backpressure::DropOldest)
+        .build();
+    let handle = flower.handle();
+    handle.activate();
+    handle.send(1).unwrap();
+    handle.send(2).unwrap();
+    let mut value = None;
+    flower.then(|v| value = v, |_| ());
+    assert_eq!(value, Some(2));
+}
+
+#[test]
+fn cancel_on_drop_reaches_the_flower() {
+    let flower: Flower<(), ()> = Flower::builder(4).cancel_on_drop(true).build();
+    let handle = flower.handle();
+    drop(flower);
+    assert!(handle.should_cancel());
+}
+
+#[test]
+fn deadline_reaches_the_flower() {
+    let flower: Flower<(), ()> = Flower::builder(5)
+        .deadline(Duration::from_millis(1))
+        .hard_deadline(true)
+        .build();
+    let handle = flower.handle();
+    handle.activate();
+    std::thread::sleep(Duration::from_millis(5));
+    assert!(handle.should_cancel());
+    assert!(matches!(flower.try_result(), Some(Err(_))));
+}
+
+#[test]
+fn label_names_the_flower() {
+    let flower: Flower<(), ()> = Flower::builder(6).label("sync").build();
+    assert_eq!(flower.label().as_deref(), Some("sync"));
+    assert!(format!("{:?}", flower).contains("sync"));
+}
+
+#[test]
+fn stored_builder_builds_many_flowers() {
+    let config = Config {
+        jobs: Flower::builder(7).capacity(4).label("jobs"),
+    };
+    let a: Flower<u32, ()> = config.jobs.build();
+    let b: Flower<String, u8> = config.jobs.clone().build();
+    assert_eq!(a.id(), b.id());
+    assert_eq!(a.label(), b.label());
+}
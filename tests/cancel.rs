@@ -0,0 +1,64 @@
+use flowync::Flower;
+use std::time::{Duration, Instant};
+
+#[test]
+fn cancel_with_reason() {
+    let flower = Flower::<u32, u32>::new(1);
+    let worker = std::thread::spawn({
+        let handle = flower.handle();
+        handle.activate();
+        move || {
+            while !handle.should_cancel() {
+                std::thread::yield_now();
+            }
+            assert_eq!(handle.cancel_reason().as_deref(), Some("user request"));
+            handle.cancel_ack();
+            // A confused worker must not overwrite the acknowledged cancelation.
+            handle.ok(1);
+        }
+    });
+
+    flower.cancel_with("user request");
+    worker.join().unwrap();
+    assert_eq!(
+        flower.wait(),
+        Err("the flower with id: 1 canceled: user request".to_string())
+    );
+}
+
+#[test]
+fn cancel_and_wait_blocked_sender() {
+    let flower = Flower::<u32, u32>::new(1);
+    let worker = std::thread::spawn({
+        let handle = flower.handle();
+        handle.activate();
+        move || {
+            let mut i = 0;
+            while !handle.should_cancel() {
+                // Nobody polls, the worker is parked here.
+                let _ = handle.send(i);
+                i += 1;
+            }
+            handle.cancel_ack();
+        }
+    });
+
+    while !flower.channel_is_present() {
+        std::thread::yield_now();
+    }
+    assert!(flower.cancel_and_wait(Duration::from_secs(5)));
+    worker.join().unwrap();
+    assert!(flower.try_result().unwrap().is_err());
+}
+
+#[test]
+fn cancel_and_wait_timeout() {
+    let flower = Flower::<u32, u32>::new(1);
+    let handle = flower.handle();
+    handle.activate();
+
+    let instant = Instant::now();
+    assert!(!flower.cancel_and_wait(Duration::from_millis(50)));
+    assert!(instant.elapsed() >= Duration::from_millis(50));
+    assert!(handle.should_cancel());
+}
@@ -0,0 +1,48 @@
+use flowync::{Flower, FlowerBuilder};
+use std::{
+    sync::mpsc,
+    time::{Duration, Instant},
+};
+
+fn cooperative_worker(flower: &Flower<u32, ()>) -> mpsc::Receiver<()> {
+    let (tx, rx) = mpsc::channel();
+    let handle = flower.handle();
+    handle.activate();
+    std::thread::spawn(move || {
+        let mut i = 0;
+        while !handle.should_cancel() {
+            let _ = handle.send(i);
+            i += 1;
+        }
+        let _ = tx.send(());
+    });
+    rx
+}
+
+#[test]
+fn cancel_on_drop() {
+    let flower: Flower<u32, ()> = FlowerBuilder::new(1).cancel_on_drop(true).build();
+    let exited = cooperative_worker(&flower);
+    let clone = flower.clone();
+    drop(flower);
+    assert!(exited.recv_timeout(Duration::from_millis(50)).is_err());
+
+    let instant = Instant::now();
+    drop(clone);
+    exited.recv_timeout(Duration::from_secs(5)).unwrap();
+    assert!(instant.elapsed() < Duration::from_secs(5));
+}
+
+#[test]
+fn no_cancel_on_drop_by_default() {
+    let flower = Flower::<u32, ()>::new(1);
+    let handle = flower.handle();
+    drop(flower);
+    assert!(!handle.should_cancel());
+
+    let flower = Flower::<u32, ()>::new(2);
+    flower.cancel_on_drop(true);
+    let handle = flower.handle();
+    drop(flower);
+    assert!(handle.should_cancel());
+}
@@ -0,0 +1,69 @@
+use flowync::{Flower, FlowerBuilder, SendErrorReason};
+use std::sync::mpsc;
+use std::time::Duration;
+
+fn returns_after_cancel(flower: &Flower<u32, ()>, worker: impl FnOnce() + Send + 'static) {
+    let (tx, rx) = mpsc::channel();
+    std::thread::spawn(move || {
+        worker();
+        tx.send(()).unwrap();
+    });
+    std::thread::sleep(Duration::from_millis(20));
+    assert!(rx.try_recv().is_err());
+    flower.cancel();
+    assert!(rx.recv_timeout(Duration::from_secs(5)).is_ok());
+}
+
+#[test]
+fn cancel_releases_send_and_drops_value() {
+    let flower: Flower<u32, ()> = Flower::new(1);
+    let handle = flower.handle();
+    handle.activate();
+    returns_after_cancel(&flower, move || {
+        let err = handle.send(1).unwrap_err();
+        assert_eq!(err.reason(), SendErrorReason::Canceled);
+        assert_eq!(err.into_inner(), 1);
+    });
+    assert_eq!(flower.channel_len(), 0);
+    assert!(!flower.channel_is_present());
+}
+
+#[test]
+fn cancel_releases_full_buffered_send() {
+    let flower: Flower<u32, ()> = FlowerBuilder::new(2).capacity(1).build();
+    let handle = flower.handle();
+    handle.activate();
+    returns_after_cancel(&flower, move || {
+        handle.send(1).unwrap();
+        assert!(handle.send(2).is_err());
+    });
+    assert_eq!(flower.channel_len(), 1);
+}
+
+#[test]
+fn cancel_returns_send_timeout_value() {
+    let flower: Flower<u32, ()> = Flower::new(3);
+    let handle = flower.handle();
+    handle.activate();
+    returns_after_cancel(&flower, move || {
+        let err = handle.send_timeout(7, Duration::from_secs(60)).unwrap_err();
+        assert_eq!(err.into_inner(), 7);
+    });
+}
+
+#[tokio::test]
+async fn cancel_wakes_send_async() {
+    let flower: Flower<u32, ()> = Flower::new(4);
+    let handle = flower.handle();
+    handle.activate();
+    let task = tokio::spawn(async move { handle.send_async(1).await });
+    tokio::time::sleep(Duration::from_millis(20)).await;
+    assert!(!task.is_finished());
+    flower.cancel();
+    tokio::time::timeout(Duration::from_secs(5), task)
+        .await
+        .unwrap()
+        .unwrap()
+        .unwrap_err();
+    assert_eq!(flower.channel_len(), 0);
+}
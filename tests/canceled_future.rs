@@ -0,0 +1,33 @@
+use flowync::Flower;
+use std::time::Duration;
+
+#[tokio::test(flavor = "multi_thread")]
+async fn canceled_future_wins_select() {
+    let flower = Flower::<(), u32>::new(1);
+    let worker = tokio::spawn({
+        let handle = flower.handle();
+        handle.activate();
+        async move {
+            tokio::select! {
+                _ = handle.canceled() => handle.err("canceled"),
+                _ = tokio::time::sleep(Duration::from_secs(30)) => handle.ok(1),
+            }
+        }
+    });
+
+    tokio::time::sleep(Duration::from_millis(10)).await;
+    flower.cancel();
+    tokio::time::timeout(Duration::from_secs(5), worker)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(flower.try_result(), Some(Err("canceled".to_string())));
+}
+
+#[tokio::test]
+async fn canceled_future_already_canceled() {
+    let flower = Flower::<(), u32>::new(1);
+    let handle = flower.handle();
+    flower.cancel();
+    handle.canceled().await;
+}
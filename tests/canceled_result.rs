@@ -0,0 +1,59 @@
+use flowync::Flower;
+
+#[test]
+fn acknowledged_cancel_is_told_apart_from_a_failure() {
+    let flower: Flower<(), ()> = Flower::new(1);
+    let handle = flower.handle();
+    handle.activate();
+    flower.cancel();
+    let worker = std::thread::spawn(move || {
+        while !handle.should_cancel() {
+            std::thread::yield_now();
+        }
+        handle.cancel_ack();
+        // Neither overwrites the acknowledged cancelation.
+        handle.ok(());
+        handle.err("failed");
+    });
+    worker.join().unwrap();
+
+    let mut result = None;
+    flower.then(|_| (), |r| result = Some(r));
+    assert!(result.unwrap().is_err());
+    assert!(flower.result_is_canceled());
+
+    // Clearing the cancel flag for a new run leaves the outcome.
+    flower.reset_cancel();
+    assert!(!flower.is_canceled());
+    assert!(flower.result_is_canceled());
+}
+
+#[test]
+fn failure_is_not_canceled() {
+    let flower: Flower<(), ()> = Flower::new(2);
+    let handle = flower.handle();
+    handle.activate();
+    flower.cancel();
+    handle.err("failed anyway");
+    assert!(matches!(flower.try_result(), Some(Err(_))));
+    assert!(!flower.result_is_canceled());
+}
+
+#[test]
+fn reset_clears_the_outcome() {
+    let flower: Flower<(), ()> = Flower::new(3);
+    let handle = flower.handle();
+    handle.activate();
+    flower.cancel();
+    handle.cancel_ack();
+    assert!(flower.result_is_canceled());
+    drop(handle);
+    flower.reset().unwrap();
+    assert!(!flower.result_is_canceled());
+
+    let handle = flower.handle();
+    handle.activate();
+    handle.ok(());
+    assert_eq!(flower.try_result(), Some(Ok(())));
+    assert!(!flower.result_is_canceled());
+}
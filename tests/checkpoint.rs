@@ -0,0 +1,71 @@
+use flowync::Flower;
+use std::sync::mpsc;
+use std::time::Duration;
+
+fn poll_value(flower: &Flower<u32, ()>) -> u32 {
+    loop {
+        let mut value = None;
+        flower.then(|v| value = v, |_| ());
+        if let Some(value) = value {
+            return value;
+        }
+        std::thread::yield_now();
+    }
+}
+
+#[test]
+fn checkpoint_waits_for_advance_not_for_the_value() {
+    let flower: Flower<u32, ()> = Flower::new(1);
+    let handle = flower.handle();
+    handle.activate();
+    let (tx, rx) = mpsc::channel();
+    let worker = std::thread::spawn(move || {
+        for step in 0..2 {
+            assert!(handle.checkpoint(step));
+            tx.send(step).unwrap();
+        }
+    });
+
+    for step in 0..2 {
+        assert_eq!(poll_value(&flower), step);
+        // Taken, but the worker stays at the checkpoint.
+        assert!(rx.recv_timeout(Duration::from_millis(20)).is_err());
+        flower.advance();
+        assert_eq!(rx.recv_timeout(Duration::from_secs(5)), Ok(step));
+    }
+    worker.join().unwrap();
+}
+
+#[test]
+fn advance_before_checkpoint_lets_it_pass() {
+    let flower: Flower<u32, ()> = flowync::FlowerBuilder::new(2).capacity(1).build();
+    let handle = flower.handle();
+    handle.activate();
+    flower.advance();
+    assert!(handle.checkpoint(1));
+    assert_eq!(poll_value(&flower), 1);
+}
+
+#[test]
+fn cancel_releases_checkpoint() {
+    let flower: Flower<u32, ()> = Flower::new(3);
+    let handle = flower.handle();
+    handle.activate();
+    let worker = std::thread::spawn(move || handle.checkpoint(1));
+    assert_eq!(poll_value(&flower), 1);
+    std::thread::sleep(Duration::from_millis(20));
+    assert!(!worker.is_finished());
+    flower.cancel();
+    assert!(!worker.join().unwrap());
+}
+
+#[test]
+fn dropped_flower_releases_checkpoint() {
+    let flower: Flower<u32, ()> = flowync::FlowerBuilder::new(4).capacity(1).build();
+    let handle = flower.handle();
+    handle.activate();
+    let worker = std::thread::spawn(move || handle.checkpoint(1));
+    std::thread::sleep(Duration::from_millis(20));
+    drop(flower);
+    assert!(!worker.join().unwrap());
+}
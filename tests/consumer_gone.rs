@@ -0,0 +1,57 @@
+use flowync::{Flower, SendErrorReason};
+use std::sync::mpsc;
+use std::time::Duration;
+
+fn exits_after_drop(flower: Flower<u32, ()>, worker: impl FnOnce() + Send + 'static) {
+    let (tx, rx) = mpsc::channel();
+    std::thread::spawn(move || {
+        worker();
+        tx.send(()).unwrap();
+    });
+    std::thread::sleep(Duration::from_millis(20));
+    drop(flower);
+    assert!(rx.recv_timeout(Duration::from_secs(5)).is_ok());
+}
+
+#[test]
+fn dropped_flower_releases_blocked_send() {
+    let flower: Flower<u32, ()> = Flower::new(1);
+    let handle = flower.handle();
+    handle.activate();
+    exits_after_drop(flower, move || {
+        let err = handle.send(1).unwrap_err();
+        assert_eq!(err.reason(), SendErrorReason::ConsumerDropped);
+        assert!(handle.consumer_gone());
+    });
+}
+
+#[test]
+fn dropped_flower_releases_full_buffered_send() {
+    let flower: Flower<u32, ()> = flowync::FlowerBuilder::new(2).capacity(1).build();
+    let handle = flower.handle();
+    handle.activate();
+    exits_after_drop(flower, move || {
+        handle.send(1).unwrap();
+        assert!(handle.send(2).is_err());
+    });
+}
+
+#[test]
+fn dropped_flower_releases_paused_worker() {
+    let flower: Flower<u32, ()> = Flower::new(3);
+    let handle = flower.handle();
+    handle.activate();
+    flower.pause();
+    exits_after_drop(flower, move || handle.wait_if_paused());
+}
+
+#[test]
+fn clone_keeps_consumer() {
+    let flower: Flower<u32, ()> = Flower::new(4);
+    let other = flower.clone();
+    let handle = flower.handle();
+    drop(flower);
+    assert!(!handle.consumer_gone());
+    drop(other);
+    assert!(handle.consumer_gone());
+}
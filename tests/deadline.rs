@@ -0,0 +1,156 @@
+use flowync::{
+    join_all, select_wait, Flower, FlowerBuilder, FlowerEvent, FlowerHandle, FlowerPoll, FlowerPool,
+};
+use std::time::{Duration, Instant};
+
+fn hard_deadline_flower(deadline: Duration, work: Duration) -> Flower<(), u32> {
+    let flower: Flower<(), u32> = FlowerBuilder::new(1)
+        .deadline(deadline)
+        .hard_deadline(true)
+        .build();
+    std::thread::spawn({
+        let handle = flower.handle();
+        handle.activate();
+        move || {
+            // Ignores the cancelation.
+            std::thread::sleep(work);
+            handle.ok(1);
+        }
+    });
+    flower
+}
+
+// The handle is kept by the caller and never delivers anything.
+fn stuck_flower(deadline: Duration) -> (Flower<(), u32>, FlowerHandle<(), u32>) {
+    let flower: Flower<(), u32> = FlowerBuilder::new(1)
+        .deadline(deadline)
+        .hard_deadline(true)
+        .build();
+    let handle = flower.handle();
+    handle.activate();
+    (flower, handle)
+}
+
+fn exceeded<T>() -> Result<T, String> {
+    Err("deadline exceeded".to_string())
+}
+
+fn drive(flower: &Flower<(), u32>) -> Result<u32, String> {
+    loop {
+        let mut result = None;
+        flower.then(|_| (), |r| result = Some(r));
+        if let Some(result) = result {
+            return result;
+        }
+        std::thread::sleep(Duration::from_millis(1));
+    }
+}
+
+#[test]
+fn finishes_before_deadline() {
+    let flower = hard_deadline_flower(Duration::from_secs(5), Duration::from_millis(10));
+    assert_eq!(drive(&flower), Ok(1));
+}
+
+#[test]
+fn finishes_after_deadline() {
+    let flower = hard_deadline_flower(Duration::from_millis(20), Duration::from_millis(200));
+    let instant = Instant::now();
+    assert_eq!(drive(&flower), Err("deadline exceeded".to_string()));
+    assert!(instant.elapsed() < Duration::from_millis(200));
+
+    // The late result is ignored.
+    std::thread::sleep(Duration::from_millis(300));
+    assert!(!flower.result_is_ready());
+}
+
+#[test]
+fn cooperative_deadline() {
+    let flower = Flower::<(), ()>::new(1);
+    let handle = flower.handle();
+    handle.activate();
+    assert!(!handle.should_cancel());
+    flower.set_deadline(Some(Instant::now()));
+    assert!(handle.should_cancel());
+    flower.set_deadline(None);
+    assert!(!handle.should_cancel());
+}
+
+#[test]
+fn try_then_around_the_deadline_always_gets_a_result() {
+    for _ in 0..50 {
+        // The worker finishes right at the deadline, racing the non-blocking polls.
+        let flower = hard_deadline_flower(Duration::from_millis(2), Duration::from_millis(2));
+        let start = Instant::now();
+        let mut result = None;
+        while result.is_none() {
+            assert!(
+                start.elapsed() < Duration::from_secs(5),
+                "no result delivered"
+            );
+            flower.try_then(|_| (), |r| result = Some(r));
+        }
+        assert!(matches!(
+            result.unwrap().as_ref().map_err(String::as_str),
+            Ok(1) | Err("deadline exceeded")
+        ));
+    }
+}
+
+#[test]
+fn wait_returns_at_the_deadline() {
+    let (flower, _handle) = stuck_flower(Duration::from_millis(20));
+    let instant = Instant::now();
+    assert_eq!(flower.wait(), exceeded());
+    assert!(instant.elapsed() < Duration::from_secs(2));
+}
+
+#[test]
+fn wait_timeout_returns_at_the_deadline() {
+    let (flower, _handle) = stuck_flower(Duration::from_millis(20));
+    let instant = Instant::now();
+    assert_eq!(
+        flower.wait_timeout(Duration::from_secs(5)),
+        Some(exceeded())
+    );
+    assert!(instant.elapsed() < Duration::from_secs(2));
+}
+
+#[test]
+fn recv_and_iter_return_at_the_deadline() {
+    let (flower, _handle) = stuck_flower(Duration::from_millis(20));
+    assert!(matches!(flower.recv(), FlowerEvent::Done(Err(e)) if e == "deadline exceeded"));
+
+    let (flower, _handle) = stuck_flower(Duration::from_millis(20));
+    assert_eq!(flower.iter().count(), 0);
+    assert_eq!(flower.try_result(), Some(exceeded()));
+}
+
+#[test]
+fn select_wait_and_join_all_return_at_the_deadline() {
+    let (flower, _handle) = stuck_flower(Duration::from_millis(20));
+    let flowers = [flower];
+    assert!(matches!(
+        select_wait(&flowers),
+        Some((1, FlowerPoll::Done(Err(e)))) if e == "deadline exceeded"
+    ));
+
+    let (flower, _handle) = stuck_flower(Duration::from_millis(20));
+    assert_eq!(join_all(vec![flower], |_, _| ()), vec![(1, exceeded())]);
+}
+
+#[test]
+fn collect_results_returns_at_the_deadline() {
+    let mut pool: FlowerPool<(), u32> = FlowerPool::new();
+    let id = pool.spawn(|handle| {
+        // Ignores the cancelation.
+        std::thread::sleep(Duration::from_secs(2));
+        handle.ok(1);
+    });
+    let flower = pool.get(id).unwrap();
+    flower.set_deadline(Some(Instant::now() + Duration::from_millis(20)));
+    flower.set_hard_deadline(true);
+    let instant = Instant::now();
+    assert_eq!(pool.collect_results(|_, _| ()), vec![(id, exceeded())]);
+    assert!(instant.elapsed() < Duration::from_secs(1));
+}
@@ -0,0 +1,57 @@
+use flowync::{Flower, FlowerBuilder};
+
+#[test]
+fn display_shows_the_id_label_and_lifecycle() {
+    let flower: Flower<u32, ()> = Flower::new(42);
+    assert_eq!(flower.to_string(), "flower#42 [idle]");
+    let handle = flower.handle();
+    handle.activate();
+    flower.set_label("thumbnailer");
+    assert_eq!(flower.to_string(), "flower#42 \"thumbnailer\" [running]");
+    assert_eq!(handle.to_string(), flower.to_string());
+    handle.ok(());
+    assert_eq!(
+        handle.to_string(),
+        "flower#42 \"thumbnailer\" [result ready]"
+    );
+}
+
+#[test]
+fn set_label_replaces_the_builder_label() {
+    let flower: Flower<u32, ()> = FlowerBuilder::new(1).label("import").build();
+    assert_eq!(flower.label().as_deref(), Some("import"));
+    flower.set_label(format!("import {}", 2));
+    assert_eq!(flower.clone().label().as_deref(), Some("import 2"));
+    assert_eq!(format!("{}", flower), "flower#1 \"import 2\" [idle]");
+}
+
+#[test]
+fn label_is_appended_to_the_errors_of_dropped_handles() {
+    let flower: Flower<u32, ()> = FlowerBuilder::new(3).label("thumbnailer").build();
+    let handle = flower.handle();
+    handle.activate();
+    drop(handle);
+    assert_eq!(
+        flower.try_result(),
+        Some(Err(
+            "flower handle(s) dropped without result, id: 3 (label: \"thumbnailer\")".to_string()
+        ))
+    );
+
+    let flower: Flower<u32, ()> = Flower::new(4);
+    flower.set_label("resizer");
+    let handle = flower.handle();
+    handle.activate();
+    let _ = std::thread::spawn(move || {
+        let _handle = handle;
+        panic!("boom");
+    })
+    .join();
+    assert_eq!(
+        flower.try_result(),
+        Some(Err(
+            "the flower handle with id: 4 error, the thread panicked maybe? (label: \"resizer\")"
+                .to_string()
+        ))
+    );
+}
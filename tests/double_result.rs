@@ -0,0 +1,72 @@
+use flowync::{Flower, ResultAlreadySet};
+
+#[test]
+fn second_result_is_rejected() {
+    let flower: Flower<(), u32> = Flower::new(1);
+    let handle = flower.handle();
+    handle.activate();
+    assert_eq!(handle.try_ok(1), Ok(()));
+    assert_eq!(handle.try_err("late"), Err(ResultAlreadySet));
+    assert_eq!(handle.try_ok(2), Err(ResultAlreadySet));
+    handle.err("ignored");
+    assert_eq!(flower.try_result(), Some(Ok(1)));
+}
+
+#[test]
+fn panic_after_ok_keeps_the_ok() {
+    let flower: Flower<(), u32> = Flower::new(2);
+    let handle = flower.handle();
+    handle.activate();
+    let worker = std::thread::spawn(move || {
+        handle.ok(7);
+        panic!("after the result");
+    });
+    assert!(worker.join().is_err());
+    assert_eq!(flower.try_result(), Some(Ok(7)));
+}
+
+#[test]
+fn panic_after_the_ok_was_taken_keeps_it() {
+    let flower: Flower<(), u32> = Flower::new(3);
+    let handle = flower.handle();
+    handle.activate();
+    let (taken, wait) = std::sync::mpsc::channel();
+    let worker = std::thread::spawn(move || {
+        handle.ok(7);
+        wait.recv().unwrap();
+        panic!("after the result was taken");
+    });
+    let result = loop {
+        if let Some(result) = flower.try_result() {
+            break result;
+        }
+        std::thread::yield_now();
+    };
+    assert_eq!(result, Ok(7));
+    taken.send(()).unwrap();
+    assert!(worker.join().is_err());
+    assert!(!flower.result_is_ready());
+}
+
+#[test]
+fn activation_accepts_a_new_result() {
+    let flower: Flower<(), u32> = Flower::new(4);
+    let handle = flower.handle();
+    handle.activate();
+    handle.ok(1);
+    assert_eq!(flower.try_result(), Some(Ok(1)));
+    handle.activate();
+    assert_eq!(handle.try_ok(2), Ok(()));
+    assert_eq!(flower.try_result(), Some(Ok(2)));
+}
+
+#[test]
+fn new_handle_mid_run_keeps_the_first_result() {
+    let flower: Flower<(), u32> = Flower::new(5);
+    let handle = flower.handle();
+    handle.activate();
+    handle.ok(1);
+    let late = flower.handle();
+    assert_eq!(late.try_ok(2), Err(ResultAlreadySet));
+    assert_eq!(flower.try_result(), Some(Ok(1)));
+}
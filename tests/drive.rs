@@ -10,7 +10,7 @@ fn drive() {
         handle.activate();
         move || {
             for i in 1..20 {
-                handle.send(i);
+                handle.send(i).unwrap();
             }
             handle.ok("Ok".to_string());
         }
@@ -31,11 +31,8 @@ fn drive() {
                     }
                 },
                 |result| {
-                    match result {
-                        Ok(value) => {
-                            assert_eq!("Ok", &value);
-                        }
-                        _ => (),
+                    if let Ok(value) = result {
+                        assert_eq!("Ok", &value);
                     }
 
                     exit = true;
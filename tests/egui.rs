@@ -0,0 +1,81 @@
+#![cfg(feature = "egui")]
+use flowync::{egui::show_progress, Flower};
+use std::{
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+
+// Run a pass, the textures are dropped like a renderer would apply them.
+fn frame(ctx: &egui::Context, run_ui: impl FnMut(&mut egui::Ui)) {
+    ctx.run_ui(Default::default(), run_ui)
+        .textures_delta
+        .clear();
+}
+
+// The delays of the repaints requested on the context.
+fn repaints(ctx: &egui::Context) -> Arc<Mutex<Vec<Duration>>> {
+    let delays = Arc::new(Mutex::new(Vec::new()));
+    ctx.set_request_repaint_callback({
+        let delays = delays.clone();
+        move |info| delays.lock().unwrap().push(info.delay)
+    });
+    delays
+}
+
+#[test]
+fn attached_ctx_repaints_on_send_and_result() {
+    let ctx = egui::Context::default();
+    let delays = repaints(&ctx);
+    let flower: Flower<u32, ()> = Flower::new(1);
+    flower.attach_egui_ctx(ctx.clone());
+    let handle = flower.handle();
+    handle.activate();
+
+    handle.send_latest(1);
+    assert_eq!(delays.lock().unwrap().as_slice(), [Duration::ZERO]);
+    // Past the first passes which egui repaints anyway.
+    for _ in 0..3 {
+        frame(&ctx, |_| flower.then(|_| (), |_| ()));
+    }
+    delays.lock().unwrap().clear();
+    handle.ok(());
+    assert!(delays.lock().unwrap().contains(&Duration::ZERO));
+}
+
+#[test]
+fn show_progress_repaints_while_running() {
+    let ctx = egui::Context::default();
+    let flower: Flower<(), ()> = Flower::new(2);
+    let handle = flower.handle();
+    handle.activate();
+    handle.set_progress(0.5);
+    // Past the first passes which egui repaints anyway.
+    for _ in 0..3 {
+        frame(&ctx, |ui| {
+            show_progress(ui, &flower);
+        });
+    }
+    let delays = repaints(&ctx);
+    frame(&ctx, |ui| {
+        let response = show_progress(ui, &flower);
+        assert!(response.rect.width() > 0.0);
+    });
+    assert!(delays
+        .lock()
+        .unwrap()
+        .iter()
+        .any(|delay| *delay > Duration::ZERO));
+
+    handle.ok(());
+    let _ = flower.try_result();
+    for _ in 0..3 {
+        frame(&ctx, |ui| {
+            show_progress(ui, &flower);
+        });
+    }
+    delays.lock().unwrap().clear();
+    frame(&ctx, |ui| {
+        show_progress(ui, &flower);
+    });
+    assert!(delays.lock().unwrap().is_empty());
+}
@@ -0,0 +1,30 @@
+use flowync::Flower;
+use std::time::Duration;
+
+#[test]
+fn elapsed_from_activation_to_completion() {
+    let flower = Flower::<(), ()>::new(1);
+    assert_eq!(flower.elapsed(), None);
+    assert_eq!(flower.started_at(), None);
+
+    let handle = flower.handle();
+    handle.activate();
+    std::thread::sleep(Duration::from_millis(20));
+    let running = flower.elapsed().unwrap();
+    assert!(running >= Duration::from_millis(20));
+
+    handle.ok(());
+    let total = flower.elapsed().unwrap();
+    std::thread::sleep(Duration::from_millis(20));
+    // The clock stops once the result is set.
+    assert_eq!(flower.elapsed(), Some(total));
+    assert!(flower.started_at().is_some());
+
+    assert_eq!(flower.try_result(), Some(Ok(())));
+    flower.reset().unwrap();
+    assert_eq!(flower.elapsed(), None);
+
+    let handle = flower.handle();
+    handle.activate();
+    assert!(flower.elapsed().unwrap() < total);
+}
@@ -0,0 +1,47 @@
+use flowync::Flower;
+use std::{error::Error, fmt, num::ParseIntError};
+
+#[derive(Debug)]
+struct ParseFailed(ParseIntError);
+
+impl fmt::Display for ParseFailed {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "failed to parse")
+    }
+}
+
+impl Error for ParseFailed {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        Some(&self.0)
+    }
+}
+
+fn parse(s: &str) -> Result<u32, ParseFailed> {
+    s.parse().map_err(ParseFailed)
+}
+
+#[test]
+fn err_from_source_chain() {
+    let flower: Flower<(), u32> = Flower::new(1);
+    flower.handle().result(parse("x"));
+    assert_eq!(
+        flower.try_result(),
+        Some(Err(
+            "failed to parse: invalid digit found in string".to_string()
+        ))
+    );
+
+    // A new run takes a new result.
+    let handle = flower.handle();
+    handle.activate();
+    handle.result(parse("7"));
+    assert_eq!(flower.try_result(), Some(Ok(7)));
+}
+
+#[test]
+fn err_from_boxed() {
+    let flower: Flower<(), u32> = Flower::new(1);
+    let e: Box<dyn Error> = "boxed".into();
+    flower.handle().err_from(&*e);
+    assert_eq!(flower.try_result(), Some(Err("boxed".to_string())));
+}
@@ -0,0 +1,64 @@
+use flowync::{ContextExt, Failed, Flower, FlowerHandle, Leaper};
+
+#[test]
+fn context_prefix() {
+    let flower: Flower<(), ()> = Flower::new(1);
+    let handle = flower.handle();
+    handle.activate();
+    handle.err_with_context("while syncing playlist 42", "connection reset");
+    assert_eq!(
+        flower.wait(),
+        Err("while syncing playlist 42: connection reset".to_string())
+    );
+}
+
+fn fetch(ok: bool) -> Result<u32, std::io::Error> {
+    if ok {
+        Ok(42)
+    } else {
+        Err(std::io::Error::other("connection reset"))
+    }
+}
+
+fn worker(handle: &FlowerHandle<(), u32>, ok: bool) -> Result<(), Failed> {
+    let value = fetch(ok).map_err(|e| handle.fail_ctx("while fetching", e))?;
+    handle.ok(value);
+    Ok(())
+}
+
+#[test]
+fn fail_ctx_with_question_mark() {
+    let flower: Flower<(), u32> = Flower::spawn(2, |handle| worker(&handle, false).unwrap_or(()));
+    assert_eq!(
+        flower.wait(),
+        Err("while fetching: connection reset".to_string())
+    );
+    let flower: Flower<(), u32> = Flower::spawn(3, |handle| worker(&handle, true).unwrap_or(()));
+    assert_eq!(flower.wait(), Ok(42));
+}
+
+#[test]
+fn fail_ctx_returns_unit() {
+    let flower: Flower<(), ()> = Flower::spawn(4, |handle| {
+        if let Err(e) = fetch(false) {
+            return handle.fail_ctx("while fetching", e).into();
+        }
+        handle.ok(());
+    });
+    assert_eq!(
+        flower.wait(),
+        Err("while fetching: connection reset".to_string())
+    );
+}
+
+#[test]
+fn leaper_context() {
+    let leaper: Leaper<()> = Leaper::new(5);
+    let handle = leaper.handle();
+    handle.activate();
+    assert_eq!(handle.fail_ctx("while loading", "not found"), Failed);
+    assert_eq!(
+        leaper.try_result(),
+        Some(Err("while loading: not found".to_string()))
+    );
+}
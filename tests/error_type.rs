@@ -0,0 +1,97 @@
+use flowync::{Flower, FlowerBuilder};
+
+#[derive(Debug, PartialEq)]
+enum MyError {
+    Timeout,
+    Panicked(String),
+}
+
+impl From<String> for MyError {
+    fn from(msg: String) -> Self {
+        MyError::Panicked(msg)
+    }
+}
+
+#[derive(Debug)]
+struct Response;
+
+#[test]
+fn custom_error_type() {
+    let flower = Flower::<u32, Response, MyError>::new(1);
+    std::thread::spawn({
+        let handle = flower.handle();
+        handle.activate();
+        move || {
+            handle.send(1).unwrap();
+            handle.err(MyError::Timeout);
+        }
+    });
+
+    let mut exit = false;
+    loop {
+        if flower.is_active() {
+            flower.then(
+                |_| (),
+                |result| {
+                    assert!(matches!(result, Err(MyError::Timeout)));
+                    exit = true;
+                },
+            );
+        }
+
+        if exit {
+            break;
+        }
+    }
+}
+
+#[test]
+fn custom_error_type_on_panic() {
+    let flower = Flower::<(), Response, MyError>::new(2);
+    let handle = flower.handle();
+    handle.activate();
+    let _ = std::thread::spawn(move || {
+        let _handle = handle;
+        panic!("boom");
+    })
+    .join();
+
+    let mut exit = false;
+    while !exit {
+        flower.then(
+            |_| (),
+            |result| {
+                assert!(matches!(result, Err(MyError::Panicked(_))));
+                exit = true;
+            },
+        );
+    }
+}
+
+// No `From<String>`, the flower makes its errors with `build_with_err`.
+#[derive(Debug, PartialEq)]
+enum StrictError {
+    Timeout,
+    Flower(String),
+}
+
+#[test]
+fn error_type_without_from_string() {
+    let flower: Flower<(), Response, StrictError> =
+        FlowerBuilder::new(3).build_with_err(StrictError::Flower);
+    let handle = flower.handle();
+    handle.activate();
+    drop(handle);
+    assert_eq!(
+        flower.try_result().unwrap().unwrap_err(),
+        StrictError::Flower("flower handle(s) dropped without result, id: 3".into())
+    );
+
+    let handle = flower.handle();
+    handle.activate();
+    handle.err(StrictError::Timeout);
+    assert_eq!(
+        flower.try_result().unwrap().unwrap_err(),
+        StrictError::Timeout
+    );
+}
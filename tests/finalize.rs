@@ -0,0 +1,28 @@
+use flowync::Flower;
+
+#[test]
+fn finalize_result() {
+    let flower = Flower::<(), u32>::new(1);
+    let handle = flower.handle();
+    handle.activate();
+
+    // Not ready yet.
+    let flower = flower.finalize().unwrap_err();
+    handle.ok(42);
+    assert_eq!(flower.finalize().unwrap(), Ok(42));
+}
+
+#[test]
+fn finalize_refuses_with_clone() {
+    let flower = Flower::<(), u32>::new(1);
+    let handle = flower.handle();
+    handle.activate();
+    handle.ok(42);
+
+    let clone = flower.clone();
+    let flower = flower.finalize().unwrap_err();
+    assert!(flower.result_is_ready());
+
+    drop(clone);
+    assert_eq!(flower.finalize().unwrap(), Ok(42));
+}
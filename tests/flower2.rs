@@ -0,0 +1,98 @@
+use flowync::{Flower2, SendErrorReason};
+
+#[test]
+fn a_pending_a_value_doesnt_hold_up_b() {
+    let flower: Flower2<u32, String, ()> = Flower2::new(1);
+    let handle = flower.handle();
+    handle.activate();
+    let sender_a = std::thread::spawn({
+        let handle = handle.clone();
+        move || handle.send_a(1).unwrap()
+    });
+    while !flower.inner().channel_is_present() {
+        std::thread::yield_now();
+    }
+    let sender_b = std::thread::spawn(move || handle.send_b("stats".into()).unwrap());
+
+    let (mut a, mut b) = (Vec::new(), Vec::new());
+    while b.is_empty() {
+        flower.then(|value| a.extend(value), |value| b.extend(value), |_| ());
+    }
+    sender_a.join().unwrap();
+    sender_b.join().unwrap();
+    assert_eq!(a, [1]);
+    assert_eq!(b, ["stats"]);
+}
+
+#[test]
+fn result_comes_after_both_channels_drained() {
+    let flower: Flower2<u32, u8, &str> = Flower2::new_buffered(2);
+    let handle = flower.handle();
+    handle.activate();
+    handle.send_a(1).unwrap();
+    handle.send_b(10).unwrap();
+    handle.send_b(11).unwrap();
+    handle.send_b(12).unwrap();
+    handle.send_a(2).unwrap();
+    handle.ok("done");
+
+    let (mut a, mut b, mut result) = (Vec::new(), Vec::new(), None);
+    let mut calls = 0;
+    while result.is_none() {
+        flower.then(
+            |value| a.extend(value),
+            |value| b.extend(value),
+            |r| result = Some(r),
+        );
+        calls += 1;
+    }
+    assert_eq!(calls, 4);
+    assert_eq!(a, [1, 2]);
+    assert_eq!(b, [10, 11, 12]);
+    assert_eq!(result, Some(Ok("done")));
+    assert!(!flower.is_active());
+}
+
+#[test]
+fn cancel_releases_senders_of_both_channels() {
+    let flower: Flower2<u32, u32, ()> = Flower2::new(3);
+    let handle = flower.handle();
+    handle.activate();
+    let senders = [
+        std::thread::spawn({
+            let handle = handle.clone();
+            move || handle.send_a(1).unwrap_err().reason()
+        }),
+        std::thread::spawn({
+            let handle = handle.clone();
+            move || handle.send_b(2).unwrap_err().reason()
+        }),
+    ];
+    while !flower.inner().channel_is_present() {
+        std::thread::yield_now();
+    }
+    std::thread::sleep(std::time::Duration::from_millis(20));
+    flower.cancel();
+    assert!(handle.should_cancel());
+    for sender in senders {
+        assert_eq!(sender.join().unwrap(), SendErrorReason::Canceled);
+    }
+}
+
+#[test]
+fn dropped_handles_report_through_the_result() {
+    let flower: Flower2<u32, u32, ()> = Flower2::new(4);
+    let handle = flower.handle();
+    handle.activate();
+    handle.send_latest_b(5);
+    drop(handle);
+    let mut b = None;
+    flower.then(|_| (), |value| b = value, |_| unreachable!());
+    assert_eq!(b, Some(5));
+    assert_eq!(
+        flower.try_result(),
+        Some(Err(
+            "flower handle(s) dropped without result, id: 4".to_string()
+        ))
+    );
+}
@@ -0,0 +1,95 @@
+use flowync::{ErrorKind, Flower, FlowerBuilder, FlowerError};
+
+fn result_of(flower: &Flower<(), ()>) -> Result<(), FlowerError> {
+    loop {
+        let mut result = None;
+        flower.then_structured(|_| (), |r| result = Some(r));
+        if let Some(result) = result {
+            return result;
+        }
+        std::thread::yield_now();
+    }
+}
+
+#[test]
+fn worker_error_is_custom() {
+    let flower: Flower<(), ()> = Flower::new(1);
+    let handle = flower.handle();
+    handle.activate();
+    handle.err("no input");
+    let err = result_of(&flower).unwrap_err();
+    assert_eq!(err.id, 1);
+    assert_eq!(err.kind, ErrorKind::Custom);
+    assert_eq!(err.message, "no input");
+    assert_eq!(err.to_string(), "no input");
+}
+
+#[test]
+fn panic_is_panicked() {
+    let flower: Flower<(), ()> = Flower::new(2);
+    let handle = flower.handle();
+    handle.activate();
+    let _ = std::thread::spawn(move || {
+        let _handle = handle;
+        panic!("boom");
+    })
+    .join();
+    assert_eq!(result_of(&flower).unwrap_err().kind, ErrorKind::Panicked);
+}
+
+#[test]
+fn cancel_ack_is_canceled() {
+    let flower: Flower<(), ()> = Flower::new(3);
+    let handle = flower.handle();
+    handle.activate();
+    flower.cancel();
+    handle.cancel_ack();
+    // Not overwritten by a late error.
+    handle.err("too late");
+    let err = result_of(&flower).unwrap_err();
+    assert_eq!(err.kind, ErrorKind::Canceled);
+    assert!(err.message.contains("canceled"));
+}
+
+#[test]
+fn dropped_handles_are_handle_dropped() {
+    let flower: Flower<(), ()> = Flower::new(4);
+    let handle = flower.handle();
+    handle.activate();
+    drop(handle);
+    assert_eq!(
+        result_of(&flower).unwrap_err().kind,
+        ErrorKind::HandleDropped
+    );
+}
+
+#[test]
+fn kind_follows_the_latest_error() {
+    let flower: Flower<(), ()> = Flower::new(5);
+    let handle = flower.handle();
+    handle.activate();
+    drop(handle);
+    assert_eq!(
+        result_of(&flower).unwrap_err().kind,
+        ErrorKind::HandleDropped
+    );
+
+    flower.reset().unwrap();
+    let handle = flower.handle();
+    handle.activate();
+    handle.err("failed");
+    assert_eq!(result_of(&flower).unwrap_err().kind, ErrorKind::Custom);
+}
+
+#[test]
+fn custom_id_is_kept() {
+    let flower: Flower<(), (), String, &str> = FlowerBuilder::new("import").build();
+    let handle = flower.handle();
+    handle.activate();
+    handle.err("no input");
+    let mut result = None;
+    flower.then_structured(|_| (), |r| result = Some(r));
+    let err: FlowerError<&str> = result.unwrap().unwrap_err();
+    assert_eq!(err.id, "import");
+    assert_eq!(err.kind, ErrorKind::Custom);
+}
@@ -0,0 +1,83 @@
+use flowync::{Flower, FlowerBuilder, SendErrorReason};
+
+#[test]
+fn late_result_of_a_canceled_run_is_ignored() {
+    let flower: Flower<u32, String> = Flower::new(1);
+    let run_a = flower.handle();
+    run_a.activate();
+    assert_eq!(flower.generation(), 0);
+
+    flower.cancel();
+    flower.reset_cancel();
+    let run_b = flower.handle();
+    run_b.activate();
+    assert_eq!(flower.generation(), 1);
+    assert!(run_a.is_stale() && run_a.should_cancel());
+    assert!(!run_b.is_stale() && !run_b.should_cancel());
+
+    // Run A finishes anyway.
+    run_a.send_latest(1);
+    assert_eq!(
+        run_a.send(2).unwrap_err().reason(),
+        SendErrorReason::Canceled
+    );
+    run_a.ok("from A".into());
+    drop(run_a);
+    assert!(!flower.result_is_ready());
+    assert!(flower.is_active());
+
+    run_b.send_latest(3);
+    run_b.ok("from B".into());
+    let mut values = Vec::new();
+    let mut result = None;
+    while result.is_none() {
+        flower.then(|v| values.extend(v), |r| result = Some(r));
+    }
+    assert_eq!(values, vec![3]);
+    assert_eq!(result, Some(Ok("from B".to_string())));
+}
+
+#[test]
+fn handles_of_the_same_run_are_not_stale() {
+    let flower: Flower<(), ()> = Flower::new(2);
+    let a = flower.handle();
+    let b = flower.handle();
+    let c = a.clone();
+    assert!(!a.is_stale() && !b.is_stale() && !c.is_stale());
+    assert_eq!(flower.generation(), 0);
+}
+
+#[test]
+fn reset_starts_a_new_run() {
+    let flower: Flower<(), u32> = FlowerBuilder::new(3).build();
+    let old = flower.handle();
+    old.activate();
+    old.ok(1);
+    assert_eq!(flower.try_result(), Some(Ok(1)));
+    flower.reset().unwrap();
+    assert_eq!(flower.generation(), 1);
+    assert!(old.is_stale());
+
+    let new = flower.handle();
+    new.activate();
+    old.ok(2);
+    assert!(!flower.result_is_ready());
+    new.ok(3);
+    assert_eq!(flower.try_result(), Some(Ok(3)));
+}
+
+#[test]
+fn taking_a_handle_keeps_the_cancel() {
+    let flower: Flower<(), ()> = Flower::new(4);
+    flower.cancel_with("user");
+    let handle = flower.handle();
+    assert!(flower.is_canceled());
+    assert!(handle.should_cancel());
+    assert_eq!(handle.cancel_reason().as_deref(), Some("user"));
+    assert_eq!(flower.generation(), 0);
+
+    flower.reset_cancel();
+    assert!(!flower.is_canceled());
+    assert!(handle.cancel_reason().is_none());
+    assert!(handle.is_stale());
+}
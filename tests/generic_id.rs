@@ -0,0 +1,30 @@
+use flowync::{Flower, FlowerBuilder};
+
+#[test]
+fn string_id() {
+    let flower: Flower<u32, String, String, String> = Flower::new("jobs/import".to_string());
+    let handle = flower.handle();
+    assert_eq!(flower.id(), "jobs/import");
+    assert_eq!(handle.id(), flower.id());
+    handle.activate();
+    std::thread::spawn(move || handle.ok("done".into()));
+    assert_eq!(flower.wait(), Ok("done".to_string()));
+}
+
+#[test]
+fn id_in_error_messages() {
+    let flower: Flower<(), (), String, &str> = FlowerBuilder::new("import").build();
+    let handle = flower.handle();
+    handle.activate();
+    drop(handle);
+    assert_eq!(
+        flower.wait(),
+        Err("flower handle(s) dropped without result, id: \"import\"".to_string())
+    );
+}
+
+#[test]
+fn default_usize_id() {
+    let flower: Flower<u32, String> = Flower::new(7);
+    assert_eq!(*flower.id(), 7);
+}
@@ -0,0 +1,51 @@
+use flowync::Flower;
+
+#[test]
+fn handle_count() {
+    let flower = Flower::<(), ()>::new(1);
+    assert_eq!(flower.handle_count(), 0);
+    assert!(!flower.has_handle());
+
+    let handle = flower.handle();
+    let clone = handle.clone();
+    assert_eq!(flower.handle_count(), 2);
+    drop(handle);
+    assert_eq!(flower.handle_count(), 1);
+    drop(clone);
+    assert!(!flower.has_handle());
+}
+
+#[test]
+fn worker_disappeared_after_panic() {
+    let flower = Flower::<(), ()>::new(1);
+    let handle = flower.handle();
+    handle.activate();
+    let _ = std::thread::spawn(move || {
+        let _handle = handle;
+        panic!("boom");
+    })
+    .join();
+
+    assert_eq!(flower.handle_count(), 0);
+    assert!(flower.is_active());
+    assert!(flower.result_is_ready());
+    assert!(flower.try_result().unwrap().is_err());
+}
+
+#[test]
+fn last_handle_dropped_without_result() {
+    let flower = Flower::<(), ()>::new(1);
+    let handle = flower.handle();
+    handle.activate();
+    let clone = handle.clone();
+    std::thread::spawn(move || drop(clone)).join().unwrap();
+    assert!(!flower.result_is_ready());
+
+    std::thread::spawn(move || drop(handle)).join().unwrap();
+    assert_eq!(
+        flower.try_result(),
+        Some(Err(
+            "flower handle(s) dropped without result, id: 1".to_string()
+        ))
+    );
+}
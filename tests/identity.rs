@@ -0,0 +1,47 @@
+// Only the id and the address of the shared state are hashed, not the interior mutability.
+#![allow(clippy::mutable_key_type)]
+use flowync::Flower;
+use std::collections::{HashMap, HashSet};
+
+#[test]
+fn clones_are_equal() {
+    let flower: Flower<(), ()> = Flower::new(1);
+    let clone = flower.clone();
+    assert_eq!(flower, clone);
+    assert!(flower.same_flower(&clone));
+
+    let handle = flower.handle();
+    assert_eq!(handle, handle.clone());
+    assert_eq!(handle, clone.handle());
+}
+
+#[test]
+fn flowers_sharing_an_id_differ() {
+    let a: Flower<(), ()> = Flower::new(7);
+    let b: Flower<(), ()> = Flower::new(7);
+    assert_eq!(a.id(), b.id());
+    assert_ne!(a, b);
+    assert!(!a.same_flower(&b));
+    assert_ne!(a.handle(), b.handle());
+
+    let set: HashSet<_> = vec![a.clone(), b.clone(), a.clone()].into_iter().collect();
+    assert_eq!(set.len(), 2);
+}
+
+#[test]
+fn flowers_round_trip_through_a_hash_map() {
+    let flowers: Vec<Flower<u32, ()>> = (0..4).map(Flower::new).collect();
+    let mut names = HashMap::new();
+    for flower in &flowers {
+        names.insert(flower.clone(), format!("flower {}", flower.id()));
+    }
+    let handles: HashSet<_> = flowers.iter().map(Flower::handle).collect();
+
+    for flower in &flowers {
+        assert_eq!(names[flower], format!("flower {}", flower.id()));
+        assert!(handles.contains(&flower.handle()));
+    }
+    let stranger: Flower<u32, ()> = Flower::new(0);
+    assert!(!names.contains_key(&stranger));
+    assert!(!handles.contains(&stranger.handle()));
+}
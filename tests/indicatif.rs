@@ -0,0 +1,69 @@
+#![cfg(feature = "cli")]
+use ::indicatif::ProgressBar;
+use flowync::{indicatif, Flower};
+
+#[test]
+fn bar_follows_the_channel_values() {
+    let flower: Flower<(u64, u64), u32> = Flower::spawn(1, |handle| {
+        for pos in 1..=5 {
+            handle.send((pos, 5)).unwrap();
+        }
+        handle.ok(5);
+    });
+    let bar = ProgressBar::hidden();
+    assert_eq!(indicatif::drive_to_completion(&flower, bar.clone()), Ok(5));
+    assert!(bar.is_finished());
+    assert_eq!(bar.length(), Some(5));
+    assert_eq!(bar.position(), 5);
+}
+
+#[test]
+fn worker_without_values_still_finishes_the_bar() {
+    let flower: Flower<u64, ()> = Flower::spawn(2, |handle| handle.ok(()));
+    let bar = ProgressBar::hidden();
+    assert_eq!(indicatif::drive_to_completion(&flower, bar.clone()), Ok(()));
+    assert!(bar.is_finished());
+    assert_eq!(bar.length(), Some(0));
+
+    // A known length is filled up.
+    let flower: Flower<u64, ()> = Flower::spawn(3, |handle| handle.ok(()));
+    let bar = ProgressBar::hidden();
+    bar.set_length(100);
+    assert_eq!(indicatif::drive_to_completion(&flower, bar.clone()), Ok(()));
+    assert_eq!(bar.position(), 100);
+}
+
+#[test]
+fn error_abandons_the_bar_where_it_stopped() {
+    let flower: Flower<u64, ()> = Flower::spawn(4, |handle| {
+        handle.send(3).unwrap();
+        handle.err("broken");
+    });
+    let bar = ProgressBar::hidden();
+    bar.set_length(10);
+    assert_eq!(
+        indicatif::drive_to_completion(&flower, bar.clone()),
+        Err("broken".to_string())
+    );
+    assert!(bar.is_finished());
+    assert_eq!(bar.position(), 3);
+}
+
+#[test]
+fn update_follows_set_progress_without_blocking() {
+    let flower: Flower<u64, ()> = Flower::new(5);
+    let handle = flower.handle();
+    handle.activate();
+    let bar = ProgressBar::hidden();
+    bar.set_length(10);
+    let mut attached = indicatif::attach(&flower, bar);
+    assert_eq!(attached.update(), None);
+
+    handle.set_progress(0.5);
+    assert_eq!(attached.update(), None);
+    assert_eq!(attached.bar().position(), 5);
+
+    handle.ok(());
+    assert_eq!(attached.update(), Some(Ok(())));
+    assert!(attached.bar().is_finished());
+}
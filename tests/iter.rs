@@ -0,0 +1,39 @@
+use flowync::Flower;
+
+#[test]
+fn iter_channel_values() {
+    let flower = Flower::<u32, String>::new(1);
+    std::thread::spawn({
+        let handle = flower.handle();
+        handle.activate();
+        move || {
+            for i in 0..10 {
+                handle.send(i).unwrap();
+            }
+            handle.ok("Ok".to_string());
+        }
+    });
+
+    let received = flower.iter().collect::<Vec<_>>();
+    assert_eq!(received, (0..10).collect::<Vec<_>>());
+    assert_eq!(flower.try_result(), Some(Ok("Ok".to_string())));
+}
+
+#[test]
+fn iter_dropped_mid_stream() {
+    let flower = Flower::<u32, String>::new(1);
+    let worker = std::thread::spawn({
+        let handle = flower.handle();
+        handle.activate();
+        move || {
+            for i in 0..10 {
+                handle.send(i).unwrap();
+            }
+            handle.ok("Ok".to_string());
+        }
+    });
+
+    assert_eq!(flower.iter().take(3).collect::<Vec<_>>(), vec![0, 1, 2]);
+    assert_eq!(flower.wait(), Ok("Ok".to_string()));
+    worker.join().unwrap();
+}
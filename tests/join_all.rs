@@ -0,0 +1,46 @@
+use flowync::{join_all, Flower};
+use std::time::Duration;
+
+#[test]
+fn join_all_collects_results() {
+    let flowers: Vec<Flower<u32, u32>> = (0..4).map(Flower::new).collect();
+    for flower in &flowers {
+        let handle = flower.handle();
+        handle.activate();
+        std::thread::spawn(move || {
+            let id = *handle.id() as u32;
+            // Unbuffered sends, the worker is blocked until the value is drained.
+            for i in 0..5 {
+                handle.send(i).unwrap();
+            }
+            std::thread::sleep(Duration::from_millis(5 * (4 - id as u64)));
+            if id == 3 {
+                handle.err("failed");
+            } else {
+                handle.ok(id * 10);
+            }
+        });
+    }
+
+    let mut channel = [0; 4];
+    let mut results = join_all(flowers, |id, _| channel[id] += 1);
+    assert_eq!(channel, [5; 4]);
+    // The slowest flower completes last.
+    assert_eq!(results.last().map(|(id, _)| *id), Some(0));
+    results.sort_by_key(|(id, _)| *id);
+    assert_eq!(
+        results,
+        vec![
+            (0, Ok(0)),
+            (1, Ok(10)),
+            (2, Ok(20)),
+            (3, Err("failed".to_string()))
+        ]
+    );
+}
+
+#[test]
+fn join_all_empty() {
+    let results = join_all(Vec::<Flower<(), ()>>::new(), |_, _| ());
+    assert!(results.is_empty());
+}
@@ -0,0 +1,152 @@
+use flowync::Leaper;
+use std::future::Future;
+use std::sync::{
+    atomic::{AtomicUsize, Ordering},
+    Arc,
+};
+use std::task::{Context, Poll, Wake, Waker};
+use std::time::Duration;
+
+struct CountingWaker(AtomicUsize);
+
+impl Wake for CountingWaker {
+    fn wake(self: Arc<Self>) {
+        self.0.fetch_add(1, Ordering::SeqCst);
+    }
+}
+
+fn counting_waker() -> (Arc<CountingWaker>, Waker) {
+    let count = Arc::new(CountingWaker(AtomicUsize::new(0)));
+    (count.clone(), Waker::from(count))
+}
+
+#[test]
+fn leaper_try_result() {
+    let leaper: Leaper<u32> = Leaper::new(1);
+    let handle = leaper.handle();
+    handle.activate();
+    assert!(leaper.is_active());
+    assert_eq!(leaper.try_result(), None);
+    std::thread::spawn(move || handle.ok(1)).join().unwrap();
+    assert!(leaper.result_is_ready());
+    assert_eq!(leaper.try_result(), Some(Ok(1)));
+    assert!(!leaper.is_active());
+    assert_eq!(leaper.try_result(), None);
+}
+
+#[test]
+fn leaper_cancel() {
+    let leaper: Leaper<()> = Leaper::new(2);
+    let handle = leaper.handle();
+    handle.activate();
+    let worker = std::thread::spawn(move || {
+        while !handle.should_cancel() {
+            std::thread::sleep(Duration::from_millis(1));
+        }
+        handle.err("canceled");
+    });
+    leaper.cancel();
+    worker.join().unwrap();
+    assert_eq!(leaper.try_result(), Some(Err("canceled".to_string())));
+}
+
+#[test]
+fn leaper_handle_dropped_or_panicked() {
+    let leaper: Leaper<()> = Leaper::new(3);
+    let handle = leaper.handle();
+    handle.activate();
+    drop(handle);
+    assert_eq!(
+        leaper.try_result(),
+        Some(Err(
+            "leaper handle(s) dropped without result, id: 3".to_string()
+        ))
+    );
+
+    let handle = leaper.handle();
+    handle.activate();
+    assert!(std::thread::spawn(move || {
+        let _handle = handle;
+        panic!("boom");
+    })
+    .join()
+    .is_err());
+    assert_eq!(
+        leaper.try_result(),
+        Some(Err(
+            "the leaper handle with id: 3 error, the thread panicked maybe?".to_string()
+        ))
+    );
+}
+
+#[test]
+fn first_result_wins() {
+    let leaper: Leaper<u32> = Leaper::new(3);
+    let handle = leaper.handle();
+    handle.activate();
+    handle.clone().ok(1);
+    handle.err("late");
+    assert_eq!(leaper.try_result(), Some(Ok(1)));
+
+    // A panic after the result was taken doesn't deliver another one.
+    assert!(std::thread::spawn(move || {
+        let _handle = handle;
+        panic!("boom");
+    })
+    .join()
+    .is_err());
+    assert!(!leaper.result_is_ready());
+    assert_eq!(leaper.try_result(), None);
+}
+
+#[tokio::test]
+async fn leaper_await_result() {
+    let leaper: Leaper<u32> = Leaper::new(4);
+    let handle = leaper.handle();
+    handle.activate();
+    std::thread::spawn(move || {
+        std::thread::sleep(Duration::from_millis(10));
+        handle.ok(4);
+    });
+    assert_eq!((&leaper).await, Ok(4));
+}
+
+#[test]
+fn every_pending_future_is_woken() {
+    let leaper: Leaper<u32> = Leaper::new(5);
+    let handle = leaper.handle();
+    handle.activate();
+    let (first_count, first_waker) = counting_waker();
+    let (second_count, second_waker) = counting_waker();
+    let (dropped_count, dropped_waker) = counting_waker();
+    let mut first = Box::pin(leaper.await_result());
+    let mut second = Box::pin(leaper.await_result());
+    let mut dropped = Box::pin(leaper.await_result());
+    let mut first_cx = Context::from_waker(&first_waker);
+    assert!(first.as_mut().poll(&mut first_cx).is_pending());
+    assert!(second
+        .as_mut()
+        .poll(&mut Context::from_waker(&second_waker))
+        .is_pending());
+    assert!(dropped
+        .as_mut()
+        .poll(&mut Context::from_waker(&dropped_waker))
+        .is_pending());
+    drop(dropped);
+
+    handle.ok(5);
+    assert_eq!(first_count.0.load(Ordering::SeqCst), 1);
+    assert_eq!(second_count.0.load(Ordering::SeqCst), 1);
+    assert_eq!(dropped_count.0.load(Ordering::SeqCst), 0);
+    assert_eq!(first.as_mut().poll(&mut first_cx), Poll::Ready(Ok(5)));
+}
+
+#[test]
+fn new_handle_keeps_the_cancelation() {
+    let leaper: Leaper<()> = Leaper::new(4);
+    leaper.cancel();
+    let handle = leaper.handle();
+    assert!(handle.should_cancel());
+    leaper.reset_cancel();
+    assert!(!handle.should_cancel());
+}
@@ -0,0 +1,37 @@
+use flowync::{Flower, FlowerLifecycle};
+
+#[test]
+fn lifecycle_transitions() {
+    let flower = Flower::<u32, ()>::new(1);
+    assert_eq!(flower.state(), FlowerLifecycle::Idle);
+
+    let worker = std::thread::spawn({
+        let handle = flower.handle();
+        handle.activate();
+        move || {
+            handle.send(1).unwrap();
+            while !handle.should_cancel() {
+                std::thread::yield_now();
+            }
+            handle.cancel_ack();
+        }
+    });
+
+    while flower.state() != FlowerLifecycle::ChannelPending {
+        std::thread::yield_now();
+    }
+    assert_eq!(flower.poll(), flowync::FlowerPoll::Channel(1));
+    assert_eq!(flower.state(), FlowerLifecycle::Running);
+
+    flower.cancel();
+    assert!(matches!(
+        flower.state(),
+        FlowerLifecycle::Canceled | FlowerLifecycle::ResultReady
+    ));
+    worker.join().unwrap();
+    assert_eq!(flower.state(), FlowerLifecycle::ResultReady);
+    assert!(flower.try_result().unwrap().is_err());
+    assert_eq!(flower.state(), FlowerLifecycle::Canceled);
+    flower.reset().unwrap();
+    assert_eq!(flower.state(), FlowerLifecycle::Idle);
+}
@@ -0,0 +1,138 @@
+use flowync::{FlowerMap, FlowerPoll, SpawnPolicy};
+use std::{
+    path::{Path, PathBuf},
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    },
+    time::Duration,
+};
+
+fn wait_cancel(handle: flowync::FlowerHandle<u32, u32>) {
+    while !handle.should_cancel() {
+        std::thread::sleep(Duration::from_millis(1));
+    }
+    handle.cancel_ack();
+}
+
+fn poll_until_idle(map: &mut FlowerMap<PathBuf, u32, u32>) -> Vec<(PathBuf, Result<u32, String>)> {
+    let mut results = Vec::new();
+    while map.iter().any(|(key, _)| map.is_busy(key)) {
+        map.poll_all(|key, event| {
+            if let FlowerPoll::Done(result) = event {
+                results.push((key.clone(), result));
+            }
+        });
+    }
+    results
+}
+
+#[test]
+fn jobs_are_polled_by_key() {
+    let mut map: FlowerMap<PathBuf, u32, u32> = FlowerMap::new();
+    for (i, path) in ["a.png", "b.png"].iter().enumerate() {
+        assert!(map.spawn(PathBuf::from(path), move |handle| {
+            handle.send(i as u32).unwrap();
+            handle.ok(i as u32 * 10);
+        }));
+    }
+    assert!(map.contains(Path::new("a.png")));
+    assert!(!map.contains(Path::new("c.png")));
+
+    let mut values = Vec::new();
+    let mut results = Vec::new();
+    while results.len() < 2 {
+        map.poll_all(|key, event| match event {
+            FlowerPoll::Channel(value) => values.push((key.clone(), value)),
+            FlowerPoll::Done(result) => results.push((key.clone(), result.unwrap())),
+            FlowerPoll::Idle => unreachable!(),
+        });
+    }
+    values.sort();
+    results.sort();
+    assert_eq!(
+        values,
+        [(PathBuf::from("a.png"), 0), (PathBuf::from("b.png"), 1)]
+    );
+    assert_eq!(
+        results,
+        [(PathBuf::from("a.png"), 0), (PathBuf::from("b.png"), 10)]
+    );
+
+    assert!(!map.is_busy(Path::new("a.png")));
+    assert_eq!(map.len(), 2);
+    assert_eq!(map.remove_finished(), 2);
+    assert!(map.is_empty());
+}
+
+#[test]
+fn reject_keeps_the_running_job() {
+    let mut map: FlowerMap<PathBuf, u32, u32> = FlowerMap::new();
+    let key = PathBuf::from("a.png");
+    assert!(map.spawn(key.clone(), wait_cancel));
+    assert!(!map.spawn(key.clone(), |handle| handle.ok(1)));
+    assert!(map.cancel(&key));
+    let results = poll_until_idle(&mut map);
+    assert_eq!(results.len(), 1);
+    assert!(results[0].1.as_ref().unwrap_err().contains("canceled"));
+
+    // A finished key takes a new job.
+    assert!(map.spawn(key, |handle| handle.ok(2)));
+    assert_eq!(poll_until_idle(&mut map)[0].1, Ok(2));
+}
+
+#[test]
+fn replace_cancels_the_running_job() {
+    let mut map: FlowerMap<PathBuf, u32, u32> = FlowerMap::new().spawn_policy(SpawnPolicy::Replace);
+    let key = PathBuf::from("a.png");
+    map.spawn(key.clone(), wait_cancel);
+    let old = map.get(&key).unwrap().clone();
+    assert!(map.spawn(key.clone(), |handle| handle.ok(3)));
+    assert!(old.is_canceled());
+    assert!(!map.get(&key).unwrap().same_flower(&old));
+    let results = poll_until_idle(&mut map);
+    assert_eq!(results, [(key, Ok(3))]);
+}
+
+#[test]
+fn queue_runs_the_jobs_one_after_the_other() {
+    let running = Arc::new(AtomicUsize::new(0));
+    let mut map: FlowerMap<PathBuf, u32, u32> = FlowerMap::new().spawn_policy(SpawnPolicy::Queue);
+    let key = PathBuf::from("a.png");
+    for i in 0..3 {
+        let running = running.clone();
+        assert!(map.spawn(key.clone(), move |handle| {
+            assert_eq!(running.fetch_add(1, Ordering::SeqCst), 0);
+            std::thread::sleep(Duration::from_millis(5));
+            running.fetch_sub(1, Ordering::SeqCst);
+            handle.ok(i);
+        }));
+    }
+    assert_eq!(map.queued_len(&key), 2);
+    let results: Vec<u32> = poll_until_idle(&mut map)
+        .into_iter()
+        .map(|(_, result)| result.unwrap())
+        .collect();
+    assert_eq!(results, [0, 1, 2]);
+    assert_eq!(map.queued_len(&key), 0);
+}
+
+#[test]
+fn cancel_drops_the_queued_jobs() {
+    let ran = Arc::new(AtomicUsize::new(0));
+    let mut map: FlowerMap<PathBuf, u32, u32> = FlowerMap::new().spawn_policy(SpawnPolicy::Queue);
+    let key = PathBuf::from("a.png");
+    map.spawn(key.clone(), wait_cancel);
+    map.spawn(key.clone(), {
+        let ran = ran.clone();
+        move |handle| {
+            ran.fetch_add(1, Ordering::SeqCst);
+            handle.ok(0);
+        }
+    });
+    map.cancel_all();
+    assert_eq!(map.queued_len(&key), 0);
+    assert_eq!(poll_until_idle(&mut map).len(), 1);
+    assert_eq!(ran.load(Ordering::SeqCst), 0);
+    assert!(map.remove(&key).is_some());
+}
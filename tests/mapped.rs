@@ -0,0 +1,54 @@
+use flowync::{Flower, FlowerPoll};
+
+#[test]
+fn map_channel_and_ok() {
+    let flower: Flower<u64, usize> = Flower::new(1);
+    let mapped = flower
+        .map_channel(|bytes| bytes * 100 / 400)
+        .map_ok(|n| format!("{} files", n));
+    let handle = mapped.handle();
+    handle.activate();
+    std::thread::spawn(move || {
+        handle.send(100).unwrap();
+        handle.send(400).unwrap();
+        handle.ok(3);
+    });
+
+    let mut percent = Vec::new();
+    let mut result = None;
+    while result.is_none() {
+        mapped.then(|value| percent.extend(value), |r| result = Some(r));
+    }
+    assert_eq!(percent, vec![25, 100]);
+    assert_eq!(result, Some(Ok("3 files".to_string())));
+    assert!(!mapped.is_active());
+}
+
+#[test]
+fn composed_maps() {
+    let flower: Flower<u32, u32> = Flower::new_buffered(2);
+    let mapped = flower.map_channel(|v| v + 1).map_channel(|v| v * 10);
+    let handle = mapped.handle();
+    handle.activate();
+    handle.send(1).unwrap();
+    assert!(matches!(mapped.poll(), FlowerPoll::Channel(20)));
+    handle.ok(5);
+    assert_eq!(mapped.try_result(), Some(Ok(5)));
+}
+
+#[test]
+fn forwards_queries() {
+    let flower: Flower<u32, u32> = Flower::new(3);
+    let mapped = flower.map_ok(|v| v * 2);
+    let handle = mapped.handle();
+    handle.activate();
+    assert!(mapped.is_active());
+    mapped.pause();
+    assert!(mapped.is_paused() && handle.should_pause());
+    mapped.resume();
+    mapped.cancel();
+    assert!(mapped.is_canceled() && handle.should_cancel());
+    handle.err("failed");
+    assert!(matches!(mapped.poll(), FlowerPoll::Done(Err(e)) if e == "failed"));
+    assert_eq!(*mapped.into_inner().id(), 3);
+}
@@ -0,0 +1,70 @@
+use flowync::Flower;
+use std::sync::{
+    atomic::{AtomicUsize, Ordering},
+    Arc,
+};
+use std::time::Duration;
+
+#[test]
+fn concurrent_senders_lose_nothing() {
+    let flower: Flower<u32, ()> = Flower::new(1);
+    let handle = flower.handle();
+    handle.activate();
+    let producers: Vec<_> = (0..4u32)
+        .map(|p| {
+            let handle = handle.clone();
+            std::thread::spawn(move || {
+                for i in p * 1000..(p + 1) * 1000 {
+                    handle.send(i).unwrap();
+                }
+            })
+        })
+        .collect();
+    std::thread::spawn(move || {
+        for producer in producers {
+            producer.join().unwrap();
+        }
+        handle.ok(());
+    });
+
+    let mut values = Vec::new();
+    let mut done = false;
+    while !done {
+        flower.then(|value| values.extend(value), |_| done = true);
+    }
+
+    // FIFO for each producer.
+    for p in 0..4u32 {
+        let own: Vec<u32> = values.iter().copied().filter(|v| v / 1000 == p).collect();
+        assert_eq!(own, (p * 1000..(p + 1) * 1000).collect::<Vec<_>>());
+    }
+    values.sort_unstable();
+    assert_eq!(values, (0..4000).collect::<Vec<_>>());
+}
+
+#[test]
+fn one_poll_releases_one_sender() {
+    let flower: Flower<u32, ()> = Flower::new(2);
+    let released = Arc::new(AtomicUsize::new(0));
+    let handle = flower.handle();
+    for i in 0..2 {
+        let handle = handle.clone();
+        let released = released.clone();
+        std::thread::spawn(move || {
+            handle.send(i).unwrap();
+            released.fetch_add(1, Ordering::SeqCst);
+        });
+    }
+    while flower.channel_len() < 2 {
+        std::thread::sleep(Duration::from_millis(1));
+    }
+
+    flower.then(|_| (), |_| ());
+    std::thread::sleep(Duration::from_millis(50));
+    assert_eq!(released.load(Ordering::SeqCst), 1);
+
+    flower.then(|_| (), |_| ());
+    while released.load(Ordering::SeqCst) < 2 {
+        std::thread::sleep(Duration::from_millis(1));
+    }
+}
@@ -0,0 +1,31 @@
+use flowync::{last_issued_id, Flower};
+
+#[test]
+fn auto_ids_are_unique() {
+    let ids: Vec<usize> = (0..8)
+        .map(|_| std::thread::spawn(|| *Flower::<(), ()>::new_auto().id()))
+        .collect::<Vec<_>>()
+        .into_iter()
+        .map(|t| t.join().unwrap())
+        .collect();
+    let mut sorted = ids.clone();
+    sorted.sort_unstable();
+    sorted.dedup();
+    assert_eq!(sorted.len(), ids.len());
+    assert!(ids.iter().all(|&id| id >= 1 && id <= last_issued_id()));
+}
+
+#[test]
+fn last_issued() {
+    let flower: Flower<(), ()> = Flower::new_auto();
+    assert!(last_issued_id() >= *flower.id());
+}
+
+#[test]
+fn named() {
+    let flower: Flower<(), ()> = Flower::new_auto_named("import");
+    assert_eq!(flower.label().as_deref(), Some("import"));
+    assert!(format!("{:?}", flower).contains("name: Some(\"import\")"));
+    let flower: Flower<(), ()> = Flower::new(1);
+    assert_eq!(flower.label().as_deref(), None);
+}
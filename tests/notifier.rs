@@ -0,0 +1,105 @@
+use flowync::{Flower, FlowerPoll, Notifier};
+use std::sync::{
+    atomic::{AtomicUsize, Ordering},
+    Arc,
+};
+
+struct Counter(Arc<AtomicUsize>);
+
+impl Notifier for Counter {
+    fn notify(&self) {
+        self.0.fetch_add(1, Ordering::SeqCst);
+    }
+}
+
+#[test]
+fn notifier_on_every_send() {
+    let flower = Flower::<u32, ()>::new(1);
+    let count = Arc::new(AtomicUsize::new(0));
+    flower.set_notifier(Counter(count.clone()));
+
+    let worker = std::thread::spawn({
+        let handle = flower.handle();
+        handle.activate();
+        move || {
+            for i in 0..5 {
+                handle.send(i).unwrap();
+            }
+            handle.ok(());
+        }
+    });
+
+    let mut exit = false;
+    while !exit {
+        flower.then(|_| (), |_| exit = true);
+    }
+    worker.join().unwrap();
+    assert_eq!(count.load(Ordering::SeqCst), 6);
+}
+
+#[test]
+fn closure_notifier_touching_flower() {
+    let flower = Flower::<u32, ()>::new_buffered(1);
+    let count = Arc::new(AtomicUsize::new(0));
+    flower.set_notifier({
+        let count = count.clone();
+        let flower = flower.clone();
+        // The internal mutex isn't held while notifying.
+        move || {
+            count.store(flower.channel_len(), Ordering::SeqCst);
+        }
+    });
+
+    let handle = flower.handle();
+    handle.send(1).unwrap();
+    handle.send(2).unwrap();
+    assert_eq!(count.load(Ordering::SeqCst), 2);
+
+    flower.clear_notifier();
+    handle.send(3).unwrap();
+    assert_eq!(count.load(Ordering::SeqCst), 2);
+}
+
+#[test]
+fn wake_notifier_once_until_polled() {
+    let flower = Flower::<u32, ()>::new_buffered(3);
+    let count = Arc::new(AtomicUsize::new(0));
+    flower.set_wake_notifier(Counter(count.clone()));
+    let handle = flower.handle();
+    handle.activate();
+
+    for i in 0..3 {
+        handle.send(i).unwrap();
+    }
+    handle.ok(());
+    assert_eq!(count.load(Ordering::SeqCst), 1);
+
+    // Everything sent before a poll is still there.
+    let mut values = Vec::new();
+    let mut done = false;
+    while !done {
+        flower.then(|value| values.extend(value), |_| done = true);
+    }
+    assert_eq!(values, [0, 1, 2]);
+    assert_eq!(count.load(Ordering::SeqCst), 1);
+}
+
+#[test]
+fn wake_notifier_again_after_a_poll() {
+    let flower = Flower::<u32, ()>::new_buffered(4);
+    let count = Arc::new(AtomicUsize::new(0));
+    flower.set_wake_notifier(Counter(count.clone()));
+    let handle = flower.handle();
+    handle.activate();
+
+    handle.send(1).unwrap();
+    assert!(matches!(flower.poll(), FlowerPoll::Channel(1)));
+    assert!(matches!(flower.poll(), FlowerPoll::Idle));
+    handle.send(2).unwrap();
+    handle.send(3).unwrap();
+    assert_eq!(count.load(Ordering::SeqCst), 2);
+    // Polling with values left doesn't lose the next wake-up.
+    assert!(matches!(flower.poll(), FlowerPoll::Channel(2)));
+    handle.ok(());
+    assert_eq!(count.load(Ordering::SeqCst), 3);
+}
@@ -0,0 +1,69 @@
+use flowync::Flower;
+use std::sync::{
+    atomic::{AtomicUsize, Ordering},
+    Arc,
+};
+
+fn counter() -> (Arc<AtomicUsize>, impl FnOnce() + Send + 'static) {
+    let calls = Arc::new(AtomicUsize::new(0));
+    let f = {
+        let calls = Arc::clone(&calls);
+        move || {
+            calls.fetch_add(1, Ordering::SeqCst);
+        }
+    };
+    (calls, f)
+}
+
+#[test]
+fn cancel_invokes_the_callback_once() {
+    let flower: Flower<(), ()> = Flower::new(1);
+    let handle = flower.handle();
+    handle.activate();
+    let (calls, f) = counter();
+    handle.set_on_cancel(f);
+    assert_eq!(calls.load(Ordering::SeqCst), 0);
+
+    flower.cancel();
+    assert_eq!(calls.load(Ordering::SeqCst), 1);
+    flower.cancel_with("again");
+    assert_eq!(calls.load(Ordering::SeqCst), 1);
+}
+
+#[test]
+fn already_canceled_invokes_right_away() {
+    let flower: Flower<(), ()> = Flower::new(2);
+    let handle = flower.handle();
+    flower.cancel();
+    let (calls, f) = counter();
+    handle.set_on_cancel(f);
+    assert_eq!(calls.load(Ordering::SeqCst), 1);
+}
+
+#[test]
+fn cleared_callback_is_not_invoked() {
+    let flower: Flower<(), ()> = Flower::new(3);
+    let handle = flower.handle();
+    let (calls, f) = counter();
+    handle.set_on_cancel(f);
+    handle.clear_on_cancel();
+    flower.cancel();
+    assert_eq!(calls.load(Ordering::SeqCst), 0);
+}
+
+#[test]
+fn callback_unblocks_a_long_work_item() {
+    let flower: Flower<(), ()> = Flower::new(4);
+    let handle = flower.handle();
+    handle.activate();
+    let (tx, rx) = std::sync::mpsc::channel::<()>();
+    // Dropping the sender stands in for closing the socket.
+    handle.set_on_cancel(move || drop(tx));
+    let worker = std::thread::spawn(move || {
+        assert!(rx.recv().is_err());
+        handle.cancel_ack();
+    });
+    flower.cancel();
+    worker.join().unwrap();
+    assert!(flower.result_is_canceled());
+}
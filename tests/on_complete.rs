@@ -0,0 +1,38 @@
+use flowync::Flower;
+use std::sync::{
+    atomic::{AtomicUsize, Ordering},
+    Arc,
+};
+
+#[test]
+fn on_complete_called_once() {
+    let flower = Flower::<(), u32>::new(1);
+    let calls = Arc::new(AtomicUsize::new(0));
+    flower.set_on_complete({
+        let calls = calls.clone();
+        let flower = flower.clone();
+        move || {
+            // Touching the flower from the callback must not deadlock.
+            assert!(flower.result_is_ready());
+            calls.fetch_add(1, Ordering::SeqCst);
+        }
+    });
+
+    let handle = flower.handle();
+    handle.activate();
+    std::thread::spawn(move || {
+        handle.ok(1);
+        handle.ok(2);
+    })
+    .join()
+    .unwrap();
+    assert_eq!(calls.load(Ordering::SeqCst), 1);
+    // The first result is kept.
+    assert_eq!(flower.try_result(), Some(Ok(1)));
+
+    flower.clear_on_complete();
+    let handle = flower.handle();
+    handle.activate();
+    handle.ok(3);
+    assert_eq!(calls.load(Ordering::SeqCst), 1);
+}
@@ -0,0 +1,57 @@
+use flowync::{Flower, FlowerEvent, FlowerPoll};
+
+#[test]
+fn on_event_only_calls_when_something_is_available() {
+    let flower: Flower<u32, String> = Flower::new(1);
+    let handle = flower.handle();
+    handle.activate();
+    let mut calls = 0;
+    assert!(!flower.on_event(|_| calls += 1));
+    assert_eq!(calls, 0);
+
+    let worker = std::thread::spawn(move || {
+        handle.send(7).unwrap();
+        handle.ok("done".into());
+    });
+    let mut events: Vec<FlowerEvent<u32, String>> = Vec::new();
+    while !events.last().is_some_and(FlowerEvent::is_done) {
+        flower.on_event(|event| events.push(event));
+    }
+    worker.join().unwrap();
+    assert_eq!(
+        events,
+        [
+            FlowerEvent::Channel(7),
+            FlowerEvent::Done(Ok("done".into()))
+        ]
+    );
+    assert!(!flower.on_event(|_| unreachable!()));
+}
+
+#[test]
+fn event_helpers() {
+    let channel: FlowerEvent<u32, u32> = FlowerEvent::Channel(2);
+    assert!(!channel.is_done());
+    assert_eq!(
+        channel.clone().map_channel(|v| v * 10),
+        FlowerEvent::Channel(20)
+    );
+    assert_eq!(channel.clone().map_ok(|v| v + 1), FlowerEvent::Channel(2));
+
+    let done: FlowerEvent<u32, u32> = Ok(3).into();
+    assert!(done.is_done());
+    assert_eq!(done.clone().map_ok(|v| v + 1), FlowerEvent::Done(Ok(4)));
+    assert_eq!(
+        done.clone().map_channel(|v| v * 10),
+        FlowerEvent::Done(Ok(3))
+    );
+    let failed: FlowerEvent<u32, u32> = Err("failed".to_string()).into();
+    assert_eq!(
+        failed.map_ok(|v| v + 1),
+        FlowerEvent::Done(Err("failed".into()))
+    );
+
+    assert_eq!(FlowerPoll::from(channel.clone()), FlowerPoll::Channel(2));
+    assert_eq!(FlowerPoll::Channel(2).into_event(), Some(channel));
+    assert_eq!(FlowerPoll::<u32, u32>::Idle.into_event(), None);
+}
@@ -0,0 +1,58 @@
+use flowync::{ErrorKind, Flower};
+
+#[test]
+fn oneshot_sends_then_sets_the_result() {
+    let flower: Flower<u32, String> = Flower::new(1);
+    let handle = flower.handle().into_oneshot();
+    handle.activate();
+    assert_eq!(handle.id(), &1);
+    let worker = std::thread::spawn(move || {
+        handle.send(1).unwrap();
+        handle.ok("done".into());
+    });
+
+    let mut values = Vec::new();
+    let mut result = None;
+    while result.is_none() {
+        flower.then(|v| values.extend(v), |r| result = Some(r));
+    }
+    worker.join().unwrap();
+    assert_eq!(values, vec![1]);
+    assert_eq!(result, Some(Ok("done".to_string())));
+}
+
+#[test]
+fn dropped_oneshot_reports_the_missing_result() {
+    let flower: Flower<(), ()> = Flower::new(2);
+    let handle = flower.handle().into_oneshot();
+    handle.activate();
+    drop(handle);
+
+    let mut kind = None;
+    flower.then_structured(|_| (), |r| kind = r.err().map(|e| e.kind));
+    assert_eq!(kind, Some(ErrorKind::HandleDropped));
+}
+
+#[test]
+fn oneshot_sees_the_cancel() {
+    let flower: Flower<(), ()> = Flower::new(3);
+    let handle = flower.handle().into_oneshot();
+    handle.activate();
+    flower.cancel_with("stop");
+    assert!(handle.should_cancel());
+    assert_eq!(handle.cancel_reason().as_deref(), Some("stop"));
+    handle.cancel_ack();
+    assert!(flower.result_is_canceled());
+}
+
+#[tokio::test]
+async fn oneshot_sends_asynchronously() {
+    let flower: Flower<u32, ()> = flowync::FlowerBuilder::new(4).capacity(1).build();
+    let handle = flower.handle().into_oneshot();
+    handle.activate();
+    handle.send_async(5).await.unwrap();
+    handle.ok(());
+    let mut value = None;
+    flower.then(|v| value = v, |_| ());
+    assert_eq!(value, Some(5));
+}
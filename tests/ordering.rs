@@ -0,0 +1,68 @@
+// Small enough to run under Miri: `cargo +nightly miri test --test ordering`.
+use flowync::{Flower, FlowerBuilder, Leaper};
+
+#[test]
+fn result_is_visible_once_ready() {
+    let flower: Flower<(), String> = Flower::new(1);
+    let handle = flower.handle();
+    handle.activate();
+    let worker = std::thread::spawn(move || handle.ok("done".to_string()));
+    while !flower.result_is_ready() {
+        std::thread::yield_now();
+    }
+    assert_eq!(flower.try_result(), Some(Ok("done".to_string())));
+    assert!(!flower.is_active());
+    worker.join().unwrap();
+}
+
+#[test]
+fn channel_value_is_visible_once_present() {
+    let flower: Flower<Vec<u32>, ()> = FlowerBuilder::new(2).capacity(2).build();
+    let handle = flower.handle();
+    handle.activate();
+    let worker = std::thread::spawn(move || {
+        handle.send(vec![1, 2, 3]).unwrap();
+        handle.ok(());
+    });
+    while !flower.channel_is_present() {
+        std::thread::yield_now();
+    }
+    let mut value = None;
+    flower.then(|v| value = v, |_| ());
+    assert_eq!(value, Some(vec![1, 2, 3]));
+    worker.join().unwrap();
+}
+
+#[test]
+fn cancel_is_visible_to_the_worker() {
+    let flower: Flower<(), u32> = Flower::new(3);
+    let handle = flower.handle();
+    handle.activate();
+    let worker = std::thread::spawn(move || {
+        let mut spins = 0;
+        while !handle.should_cancel() {
+            spins += 1;
+            std::thread::yield_now();
+        }
+        handle.ok(spins);
+    });
+    flower.cancel();
+    worker.join().unwrap();
+    assert!(matches!(flower.try_result(), Some(Ok(_))));
+}
+
+#[test]
+fn leaper_result_is_visible_once_ready() {
+    let leaper: Leaper<String> = Leaper::new(4);
+    let handle = leaper.handle();
+    handle.activate();
+    let worker = std::thread::spawn(move || handle.ok("done".to_string()));
+    let result = loop {
+        if let Some(result) = leaper.try_result() {
+            break result;
+        }
+        std::thread::yield_now();
+    };
+    assert_eq!(result, Ok("done".to_string()));
+    worker.join().unwrap();
+}
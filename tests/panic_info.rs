@@ -0,0 +1,40 @@
+use flowync::{ErrorKind, Flower};
+
+fn error_of(flower: &Flower<(), ()>) -> flowync::FlowerError {
+    loop {
+        let mut error = None;
+        flower.then_structured(|_| (), |r| error = r.err());
+        if let Some(error) = error {
+            return error;
+        }
+        std::thread::yield_now();
+    }
+}
+
+#[test]
+fn err_panic_reports_the_payload() {
+    let flower: Flower<(), ()> = Flower::new(1);
+    let handle = flower.handle();
+    handle.activate();
+    let payload = std::panic::catch_unwind(|| panic!("out of cheese")).unwrap_err();
+    handle.err_panic(payload);
+
+    let error = error_of(&flower);
+    assert_eq!(error.kind, ErrorKind::Panicked);
+    assert!(error.message.starts_with("worker panicked: out of cheese"));
+}
+
+#[cfg(feature = "panic-info")]
+#[test]
+fn spawned_panic_reports_the_location() {
+    let flower: Flower<(), ()> = Flower::spawn(2, |_| panic!("out of cheese"));
+    let error = error_of(&flower);
+    assert_eq!(error.kind, ErrorKind::Panicked);
+    assert!(
+        error
+            .message
+            .starts_with("worker panicked: out of cheese at tests/panic_info.rs:"),
+        "{}",
+        error.message
+    );
+}
@@ -0,0 +1,85 @@
+use flowync::{Flower, FlowerBuilder, PanicPolicy};
+use std::panic::{self, AssertUnwindSafe};
+
+// Caught and handed to `err_panic` like the spawn helpers do.
+fn caught_panic(policy: PanicPolicy) -> Flower<(), ()> {
+    let flower: Flower<(), ()> = FlowerBuilder::new(1).panic_policy(policy).build();
+    let handle = flower.handle();
+    handle.activate();
+    std::thread::spawn(move || {
+        if let Err(payload) = panic::catch_unwind(|| panic!("boom")) {
+            handle.err_panic(payload);
+        }
+    })
+    .join()
+    .unwrap();
+    flower
+}
+
+// Panics while holding a handle, outside of the spawn helpers.
+fn dropped_panic(policy: PanicPolicy) -> Flower<(), ()> {
+    let flower: Flower<(), ()> = FlowerBuilder::new(2).panic_policy(policy).build();
+    std::thread::scope(|s| {
+        let worker = flower.scoped_spawn(s, |_handle| panic!("boom"));
+        assert!(worker.join().is_err());
+    });
+    flower
+}
+
+#[test]
+fn report_delivers_an_error() {
+    let flower = caught_panic(PanicPolicy::Report);
+    assert_eq!(flower.wait(), Err("worker panicked: boom".to_string()));
+    let flower = dropped_panic(PanicPolicy::Report);
+    assert!(flower
+        .wait()
+        .unwrap_err()
+        .contains("the thread panicked maybe?"));
+}
+
+#[test]
+fn propagate_resumes_the_panic_on_the_consumer() {
+    let flower = caught_panic(PanicPolicy::Propagate);
+    assert!(!flower.is_active());
+    let payload =
+        panic::catch_unwind(AssertUnwindSafe(|| flower.then(|_| (), |_| ()))).unwrap_err();
+    assert_eq!(payload.downcast_ref::<&str>(), Some(&"boom"));
+    // Resumed once, nothing is left to poll.
+    assert!(flower.try_result().is_none());
+
+    let flower = dropped_panic(PanicPolicy::Propagate);
+    let payload = panic::catch_unwind(AssertUnwindSafe(|| flower.poll())).unwrap_err();
+    assert!(payload
+        .downcast_ref::<String>()
+        .unwrap()
+        .contains("the thread panicked maybe?"));
+}
+
+#[test]
+fn propagate_wakes_a_blocked_wait() {
+    let flower: Flower<(), ()> = FlowerBuilder::new(3)
+        .panic_policy(PanicPolicy::Propagate)
+        .build();
+    let handle = flower.handle();
+    handle.activate();
+    let worker = std::thread::spawn(move || {
+        std::thread::sleep(std::time::Duration::from_millis(20));
+        let payload = panic::catch_unwind(|| panic!("late")).unwrap_err();
+        handle.err_panic(payload);
+    });
+    let payload = panic::catch_unwind(AssertUnwindSafe(|| flower.wait())).unwrap_err();
+    assert_eq!(payload.downcast_ref::<&str>(), Some(&"late"));
+    worker.join().unwrap();
+}
+
+#[test]
+fn ignore_deactivates_without_a_result() {
+    for flower in [
+        caught_panic(PanicPolicy::Ignore),
+        dropped_panic(PanicPolicy::Ignore),
+    ] {
+        assert!(!flower.is_active());
+        assert!(!flower.result_is_ready());
+        assert!(flower.try_result().is_none());
+    }
+}
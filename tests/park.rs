@@ -0,0 +1,109 @@
+#![cfg(feature = "park")]
+use flowync::{Flower, FlowerBuilder, SendErrorReason};
+use std::{
+    sync::mpsc,
+    time::{Duration, Instant},
+};
+
+const VALUES: u32 = 20_000;
+
+// Fails instead of hanging if a wake up is lost.
+fn join_within<T: Send + 'static>(worker: std::thread::JoinHandle<T>, timeout: Duration) -> T {
+    let (done, wait_done) = mpsc::channel();
+    std::thread::spawn(move || done.send(worker.join()));
+    wait_done
+        .recv_timeout(timeout)
+        .expect("the sender was never woken")
+        .unwrap()
+}
+
+#[test]
+fn parked_sender_is_woken_on_every_value() {
+    let flower: Flower<u32, ()> = Flower::new(1);
+    let handle = flower.handle();
+    handle.activate();
+    let worker = std::thread::spawn(move || {
+        for i in 0..VALUES {
+            handle.send(i).unwrap();
+        }
+        handle.ok(());
+    });
+
+    let mut received = Vec::with_capacity(VALUES as usize);
+    let mut done = false;
+    while !done {
+        flower.then(|value| received.extend(value), |_| done = true);
+    }
+    join_within(worker, Duration::from_secs(10));
+    assert_eq!(received, (0..VALUES).collect::<Vec<_>>());
+}
+
+#[test]
+fn senders_beyond_the_parked_one_wait_on_the_condvar() {
+    let flower: Flower<u32, ()> = FlowerBuilder::new(2).capacity(1).build();
+    let senders: Vec<_> = (0..4)
+        .map(|n| {
+            let handle = flower.handle();
+            handle.activate();
+            std::thread::spawn(move || {
+                for i in 0..1_000 {
+                    handle.send(n * 1_000 + i).unwrap();
+                }
+            })
+        })
+        .collect();
+
+    let mut received = Vec::new();
+    while received.len() < 4_000 {
+        flower.then(|value| received.extend(value), |_| ());
+    }
+    for sender in senders {
+        join_within(sender, Duration::from_secs(10));
+    }
+    received.sort_unstable();
+    assert_eq!(received, (0..4_000).collect::<Vec<_>>());
+}
+
+#[test]
+fn cancel_wakes_the_parked_sender() {
+    let flower: Flower<u32, ()> = Flower::new(3);
+    let handle = flower.handle();
+    handle.activate();
+    let worker = std::thread::spawn(move || handle.send(1));
+    while !flower.channel_is_present() {
+        std::thread::yield_now();
+    }
+    std::thread::sleep(Duration::from_millis(20));
+    flower.cancel();
+    let err = join_within(worker, Duration::from_secs(5)).unwrap_err();
+    assert_eq!(err.reason(), SendErrorReason::Canceled);
+    assert_eq!(err.into_inner(), 1);
+}
+
+#[test]
+fn dropping_the_flower_wakes_the_parked_sender() {
+    let flower: Flower<u32, ()> = Flower::new(4);
+    let handle = flower.handle();
+    handle.activate();
+    let worker = std::thread::spawn(move || handle.send(1));
+    while !flower.channel_is_present() {
+        std::thread::yield_now();
+    }
+    drop(flower);
+    let err = join_within(worker, Duration::from_secs(5)).unwrap_err();
+    assert_eq!(err.reason(), SendErrorReason::ConsumerDropped);
+}
+
+#[test]
+fn parked_send_timeout_gives_up() {
+    let flower: Flower<u32, ()> = Flower::new(5);
+    let handle = flower.handle();
+    handle.activate();
+    let start = Instant::now();
+    let err = handle
+        .send_timeout(1, Duration::from_millis(50))
+        .unwrap_err();
+    assert!(start.elapsed() >= Duration::from_millis(50));
+    assert_eq!(err.into_inner(), 1);
+    assert!(!flower.channel_is_present());
+}
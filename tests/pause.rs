@@ -0,0 +1,39 @@
+use flowync::Flower;
+use std::{sync::mpsc, time::Duration};
+
+fn paused_worker(flower: &Flower<(), ()>) -> mpsc::Receiver<()> {
+    let (tx, rx) = mpsc::channel();
+    let handle = flower.handle();
+    handle.activate();
+    flower.pause();
+    std::thread::spawn(move || {
+        assert!(handle.should_pause());
+        handle.wait_if_paused();
+        let _ = tx.send(());
+        handle.ok(());
+    });
+    rx
+}
+
+#[test]
+fn resume_unparks_paused_worker() {
+    let flower = Flower::<(), ()>::new(1);
+    let unparked = paused_worker(&flower);
+    assert!(unparked.recv_timeout(Duration::from_millis(50)).is_err());
+    assert!(flower.is_paused());
+
+    flower.resume();
+    unparked.recv_timeout(Duration::from_secs(5)).unwrap();
+    assert!(!flower.is_paused());
+}
+
+#[test]
+fn cancel_unparks_paused_worker() {
+    let flower = Flower::<(), ()>::new(1);
+    let unparked = paused_worker(&flower);
+    assert!(unparked.recv_timeout(Duration::from_millis(50)).is_err());
+
+    flower.cancel();
+    unparked.recv_timeout(Duration::from_secs(5)).unwrap();
+    assert!(flower.is_paused());
+}
@@ -0,0 +1,62 @@
+use flowync::{Flower, FlowerBuilder};
+use std::sync::{
+    atomic::{AtomicBool, Ordering},
+    Arc,
+};
+use std::time::Duration;
+
+#[test]
+fn peek_keeps_value_and_sender_blocked() {
+    let flower: Flower<u32, ()> = Flower::new(1);
+    let released = Arc::new(AtomicBool::new(false));
+    let handle = flower.handle();
+    handle.activate();
+    std::thread::spawn({
+        let released = released.clone();
+        move || {
+            handle.send(5).unwrap();
+            released.store(true, Ordering::SeqCst);
+            handle.ok(());
+        }
+    });
+
+    while flower.channel_len() == 0 {
+        std::thread::sleep(Duration::from_millis(1));
+    }
+    flower.peek_channel(|value| assert_eq!(value, Some(&5)));
+    assert_eq!(flower.clone_channel(), Some(5));
+    std::thread::sleep(Duration::from_millis(20));
+    assert!(!released.load(Ordering::SeqCst));
+    assert_eq!(flower.channel_len(), 1);
+
+    let mut polled = None;
+    flower.then(|value| polled = value, |_| ());
+    assert_eq!(polled, Some(5));
+    assert_eq!(flower.wait(), Ok(()));
+    assert!(released.load(Ordering::SeqCst));
+    assert_eq!(flower.clone_channel(), None);
+}
+
+#[test]
+fn peek_buffered_front() {
+    let flower: Flower<u32, ()> = Flower::new_buffered(2);
+    let handle = flower.handle();
+    handle.send(1).unwrap();
+    handle.send(2).unwrap();
+    assert_eq!(flower.clone_channel(), Some(1));
+    assert_eq!(flower.clone_channel(), Some(1));
+}
+
+#[test]
+fn peek_broadcast_cursor() {
+    let flower: Flower<u32, ()> = FlowerBuilder::new(3).buffered(true).build_broadcast();
+    let other = flower.clone();
+    let handle = flower.handle();
+    handle.send(1).unwrap();
+    handle.send(2).unwrap();
+    let mut polled = None;
+    flower.then(|value| polled = value, |_| ());
+    assert_eq!(polled, Some(1));
+    assert_eq!(flower.clone_channel(), Some(2));
+    assert_eq!(other.clone_channel(), Some(1));
+}
@@ -0,0 +1,45 @@
+use flowync::{Backpressure, Flower, FlowerBuilder};
+use std::sync::atomic::{AtomicBool, Ordering};
+
+static ARMED: AtomicBool = AtomicBool::new(true);
+
+struct Bomb;
+
+impl Drop for Bomb {
+    fn drop(&mut self) {
+        // Only the first drop panics.
+        if ARMED.swap(false, Ordering::Relaxed) {
+            panic!("boom");
+        }
+    }
+}
+
+#[test]
+fn poisoned_then_delivers_error() {
+    let flower: Flower<Bomb, ()> = FlowerBuilder::new(1)
+        .capacity(1)
+        .backpressure(Backpressure::DropOldest)
+        .build();
+    let handle = flower.handle();
+    handle.activate();
+    let worker = std::thread::spawn(move || {
+        handle.send(Bomb).unwrap();
+        // Drops the oldest value while holding the lock.
+        handle.send(Bomb).unwrap();
+    });
+    assert!(worker.join().is_err());
+
+    let mut error = None;
+    flower.then(|_| (), |result| error = result.err());
+    #[cfg(not(any(feature = "parking-lot", feature = "parking_lot")))]
+    assert_eq!(error.as_deref(), Some("flower state poisoned"));
+    #[cfg(any(feature = "parking-lot", feature = "parking_lot"))]
+    assert!(error.is_some());
+
+    // The flower is usable again once reset.
+    assert!(flower.reset().is_ok());
+    let handle = flower.handle();
+    handle.activate();
+    handle.ok(());
+    assert_eq!(flower.try_result(), Some(Ok(())));
+}
@@ -0,0 +1,52 @@
+use flowync::{Flower, FlowerPoll};
+
+#[test]
+fn poll_mixed_with_then() {
+    let flower = Flower::<i32, String>::new(1);
+    std::thread::spawn({
+        let handle = flower.handle();
+        handle.activate();
+        move || {
+            for i in 1..20 {
+                handle.send(i).unwrap();
+            }
+            handle.ok("Ok".to_string());
+        }
+    });
+
+    let mut sum = 0;
+    let mut use_poll = true;
+    loop {
+        if use_poll {
+            match flower.poll() {
+                FlowerPoll::Channel(value) => sum += value,
+                FlowerPoll::Done(result) => {
+                    assert_eq!(result.unwrap(), "Ok");
+                    break;
+                }
+                FlowerPoll::Idle => continue,
+            }
+        } else {
+            let mut exit = false;
+            flower.then(
+                |channel| {
+                    if let Some(value) = channel {
+                        sum += value;
+                    }
+                },
+                |result| {
+                    assert_eq!(result.unwrap(), "Ok");
+                    exit = true;
+                },
+            );
+            if exit {
+                break;
+            }
+        }
+        use_poll = !use_poll;
+    }
+
+    assert_eq!(sum, 190);
+    assert!(!flower.is_active());
+    assert_eq!(flower.poll(), FlowerPoll::Idle);
+}
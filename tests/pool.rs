@@ -0,0 +1,396 @@
+use flowync::{FlowerLifecycle, FlowerPoll, FlowerPool};
+use std::{
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc, Mutex,
+    },
+    time::{Duration, Instant},
+};
+
+#[test]
+fn pool_runs_jobs_to_completion() {
+    let mut pool: FlowerPool<u32, u32> = FlowerPool::new();
+    let ids: Vec<usize> = (0..3)
+        .map(|i| {
+            pool.spawn(move |handle| {
+                handle.send(i).unwrap();
+                handle.ok(i * 10);
+            })
+        })
+        .collect();
+    assert_eq!(ids, vec![0, 1, 2]);
+    assert_eq!(pool.len(), 3);
+
+    let mut values = Vec::new();
+    let mut results = Vec::new();
+    while !pool.is_empty() {
+        pool.poll_all(|id, event| match event {
+            FlowerPoll::Channel(value) => values.push((id, value)),
+            FlowerPoll::Done(result) => results.push((id, result)),
+            FlowerPoll::Idle => unreachable!(),
+        });
+    }
+    values.sort();
+    results.sort();
+    assert_eq!(values, vec![(0, 0), (1, 1), (2, 2)]);
+    assert_eq!(results, vec![(0, Ok(0)), (1, Ok(10)), (2, Ok(20))]);
+
+    // Ids aren't reused.
+    assert_eq!(pool.spawn(|handle| handle.ok(0)), 3);
+}
+
+#[test]
+fn pool_cancel_and_keep_completed() {
+    let mut pool: FlowerPool<(), ()> = FlowerPool::new().remove_completed(false);
+    let id = pool.spawn(|handle| {
+        while !handle.should_cancel() {
+            std::thread::sleep(Duration::from_millis(1));
+        }
+        handle.err("canceled");
+    });
+    assert!(pool.cancel(id));
+    assert!(!pool.cancel(id + 1));
+
+    let mut result = None;
+    while result.is_none() {
+        pool.poll_all(|_, event| {
+            if let FlowerPoll::Done(r) = event {
+                result = Some(r);
+            }
+        });
+    }
+    assert_eq!(result, Some(Err("canceled".to_string())));
+    assert_eq!(pool.len(), 1);
+    assert!(!pool.get(id).unwrap().is_active());
+    assert!(pool.remove(id).is_some());
+    assert!(pool.is_empty());
+}
+
+// Cooperative workers, half of them blocked in `send`.
+fn spawn_workers(pool: &mut FlowerPool<u32, ()>, n: u32) {
+    for i in 0..n {
+        pool.spawn(move |handle| {
+            if i % 2 == 0 {
+                let _ = handle.send(i);
+            }
+            while !handle.should_cancel() {
+                std::thread::sleep(Duration::from_millis(1));
+            }
+            handle.cancel_ack();
+        });
+    }
+}
+
+fn wait_inactive(pool: &FlowerPool<u32, ()>, timeout: Duration) -> bool {
+    let deadline = Instant::now() + timeout;
+    while pool.active_count() > 0 {
+        if Instant::now() >= deadline {
+            return false;
+        }
+        std::thread::sleep(Duration::from_millis(5));
+    }
+    true
+}
+
+#[test]
+fn cancel_all_stops_every_worker() {
+    let mut pool: FlowerPool<u32, ()> = FlowerPool::new();
+    spawn_workers(&mut pool, 100);
+    assert_eq!(pool.active_count(), 100);
+    pool.cancel_all();
+    assert!(wait_inactive(&pool, Duration::from_secs(10)));
+
+    let mut canceled = 0;
+    while !pool.is_empty() {
+        pool.poll_all(|_, event| {
+            if let FlowerPoll::Done(result) = event {
+                assert!(result.unwrap_err().ends_with("canceled"));
+                canceled += 1;
+            }
+        });
+    }
+    assert_eq!(canceled, 100);
+}
+
+#[test]
+fn cancel_by_only_cancels_the_matching_flowers() {
+    let mut pool: FlowerPool<u32, ()> = FlowerPool::new();
+    spawn_workers(&mut pool, 10);
+    let odd = pool.cancel_by(|id, meta| {
+        assert_ne!(meta.state, FlowerLifecycle::Idle);
+        id % 2 == 1
+    });
+    assert_eq!(odd, 5);
+    assert!(pool.get(1).unwrap().is_canceled());
+    assert!(!pool.get(2).unwrap().is_canceled());
+
+    let deadline = Instant::now() + Duration::from_secs(10);
+    while pool.active_count() > 5 {
+        assert!(Instant::now() < deadline);
+        std::thread::sleep(Duration::from_millis(5));
+    }
+    pool.cancel_all();
+    assert!(wait_inactive(&pool, Duration::from_secs(10)));
+}
+
+#[test]
+fn max_concurrency_limits_the_running_jobs() {
+    let running = Arc::new(AtomicUsize::new(0));
+    let max_running = Arc::new(AtomicUsize::new(0));
+    let mut pool: FlowerPool<(), usize> = FlowerPool::new().max_concurrency(2);
+    for i in 0..6 {
+        let running = running.clone();
+        let max_running = max_running.clone();
+        pool.spawn(move |handle| {
+            let now = running.fetch_add(1, Ordering::SeqCst) + 1;
+            max_running.fetch_max(now, Ordering::SeqCst);
+            std::thread::sleep(Duration::from_millis(5));
+            running.fetch_sub(1, Ordering::SeqCst);
+            handle.ok(i);
+        });
+    }
+    assert_eq!(pool.queued_len(), 4);
+
+    let mut results = Vec::new();
+    while !pool.is_empty() {
+        pool.poll_all(|id, event| {
+            if let FlowerPoll::Done(result) = event {
+                results.push((id, result.unwrap()));
+            }
+        });
+    }
+    assert_eq!(pool.queued_len(), 0);
+    assert!(max_running.load(Ordering::SeqCst) <= 2);
+    results.sort();
+    assert_eq!(results, (0..6).map(|i| (i, i)).collect::<Vec<_>>());
+}
+
+#[test]
+fn queued_jobs_start_in_order() {
+    let started = Arc::new(Mutex::new(Vec::new()));
+    let mut pool: FlowerPool<(), ()> = FlowerPool::new().max_concurrency(1);
+    for i in 0..5 {
+        let started = started.clone();
+        pool.spawn(move |handle| {
+            started.lock().unwrap().push(i);
+            handle.ok(());
+        });
+    }
+    while !pool.is_empty() {
+        pool.poll_all(|_, _| ());
+    }
+    assert_eq!(*started.lock().unwrap(), [0, 1, 2, 3, 4]);
+}
+
+#[test]
+fn canceled_queued_job_never_runs() {
+    let ran = Arc::new(AtomicUsize::new(0));
+    let mut pool: FlowerPool<(), ()> = FlowerPool::new().max_concurrency(1);
+    let first = pool.spawn(|handle| {
+        while !handle.should_cancel() {
+            std::thread::sleep(Duration::from_millis(1));
+        }
+        handle.ok(());
+    });
+    let queued = pool.spawn({
+        let ran = ran.clone();
+        move |handle| {
+            ran.fetch_add(1, Ordering::SeqCst);
+            handle.ok(());
+        }
+    });
+    assert_eq!(pool.queued_len(), 1);
+    assert!(pool.cancel(queued));
+    assert_eq!(pool.queued_len(), 0);
+
+    let mut canceled = None;
+    pool.poll_all(|id, event| {
+        if let FlowerPoll::Done(result) = event {
+            assert_eq!(id, queued);
+            canceled = Some(result);
+        }
+    });
+    assert!(canceled.unwrap().unwrap_err().contains("canceled"));
+
+    pool.cancel(first);
+    while !pool.is_empty() {
+        pool.poll_all(|_, _| ());
+    }
+    assert_eq!(ran.load(Ordering::SeqCst), 0);
+}
+
+#[test]
+fn canceled_queued_job_takes_no_slot() {
+    let ran = Arc::new(AtomicUsize::new(0));
+    let mut pool: FlowerPool<(), ()> = FlowerPool::new().max_concurrency(2);
+    let first = pool.spawn(|handle| {
+        while !handle.should_cancel() {
+            std::thread::sleep(Duration::from_millis(1));
+        }
+        handle.ok(());
+    });
+    let second = pool.spawn(|handle| {
+        while !handle.should_cancel() {
+            std::thread::sleep(Duration::from_millis(1));
+        }
+        handle.ok(());
+    });
+    let queued = pool.spawn(|handle| handle.ok(()));
+    assert!(pool.cancel(queued));
+    pool.cancel(second);
+    assert!(pool.remove(second).is_some());
+
+    // Only `first` runs, the canceled job never started.
+    let third = pool.spawn({
+        let ran = ran.clone();
+        move |handle| {
+            ran.fetch_add(1, Ordering::SeqCst);
+            handle.ok(());
+        }
+    });
+    assert_eq!(pool.queued_len(), 0);
+    pool.cancel(first);
+    while pool.get(third).is_some() {
+        pool.poll_all(|_, _| ());
+    }
+    assert_eq!(ran.load(Ordering::SeqCst), 1);
+}
+
+#[test]
+fn cancel_all_drops_the_queue() {
+    let mut pool: FlowerPool<u32, ()> = FlowerPool::new().max_concurrency(3);
+    spawn_workers(&mut pool, 10);
+    assert_eq!(pool.queued_len(), 7);
+    pool.cancel_all();
+    assert_eq!(pool.queued_len(), 0);
+    let mut errors = 0;
+    let start = Instant::now();
+    while !pool.is_empty() && start.elapsed() < Duration::from_secs(5) {
+        pool.poll_all(|_, event| {
+            if let FlowerPoll::Done(Err(_)) = event {
+                errors += 1;
+            }
+        });
+    }
+    assert!(pool.is_empty());
+    assert!(errors >= 7);
+}
+
+#[test]
+fn collect_results_forwards_progress_and_gathers_every_result() {
+    let mut pool: FlowerPool<u32, u32> = FlowerPool::new().max_concurrency(4);
+    for i in 0..10 {
+        pool.spawn(move |handle| {
+            for step in 0..3 {
+                handle.send(step).unwrap();
+            }
+            std::thread::sleep(Duration::from_millis(u64::from(10 - i)));
+            handle.ok(i);
+        });
+    }
+    let mut progress = 0;
+    let mut results = pool.collect_results(|_, _| progress += 1);
+    assert_eq!(progress, 30);
+    assert!(pool.is_empty());
+    results.sort();
+    assert_eq!(
+        results,
+        (0..10).map(|i| (i as usize, Ok(i))).collect::<Vec<_>>()
+    );
+}
+
+#[test]
+fn collect_results_timeout_returns_the_outstanding_ids() {
+    let mut pool: FlowerPool<(), ()> = FlowerPool::new();
+    let done = pool.spawn(|handle| handle.ok(()));
+    let stuck = pool.spawn(|handle| {
+        while !handle.should_cancel() {
+            std::thread::sleep(Duration::from_millis(1));
+        }
+        handle.cancel_ack();
+    });
+    let (results, outstanding) = pool.collect_results_timeout(Duration::from_millis(50), |_, _| ());
+    assert_eq!(results.len(), 1);
+    assert_eq!(results[0].0, done);
+    assert_eq!(outstanding, [stuck]);
+
+    pool.cancel(stuck);
+    let results = pool.collect_results(|_, _| ());
+    assert_eq!(results[0].0, stuck);
+    assert!(results[0].1.is_err());
+}
+
+#[test]
+fn collect_results_keeps_the_notifier_of_the_user() {
+    let mut pool: FlowerPool<u32, ()> = FlowerPool::new();
+    let (go, wait) = std::sync::mpsc::channel();
+    let id = pool.spawn(move |handle| {
+        wait.recv().unwrap();
+        handle.send(1).unwrap();
+        wait.recv().unwrap();
+        handle.ok(());
+    });
+    let notified = Arc::new(AtomicUsize::new(0));
+    pool.get(id).unwrap().set_notifier({
+        let notified = notified.clone();
+        move || {
+            notified.fetch_add(1, Ordering::SeqCst);
+        }
+    });
+
+    go.send(()).unwrap();
+    let mut progress = 0;
+    let (results, outstanding) =
+        pool.collect_results_timeout(Duration::from_millis(100), |_, _| progress += 1);
+    assert_eq!((progress, results.len(), outstanding.len()), (1, 0, 1));
+    // Called along with the one of the pool.
+    assert_eq!(notified.load(Ordering::SeqCst), 1);
+
+    go.send(()).unwrap();
+    while !pool.get(id).unwrap().result_is_ready() {
+        std::thread::sleep(Duration::from_millis(1));
+    }
+    assert_eq!(notified.load(Ordering::SeqCst), 2);
+}
+
+#[test]
+fn snapshot_lists_every_flower() {
+    let mut pool: FlowerPool<(), ()> = FlowerPool::new().max_concurrency(1);
+    let running = pool.spawn(|handle| {
+        handle.set_progress(0.5);
+        while !handle.should_cancel() {
+            std::thread::sleep(Duration::from_millis(1));
+        }
+        handle.cancel_ack();
+    });
+    let queued = pool.spawn(|handle| handle.ok(()));
+    pool.get(running).unwrap().set_label("import");
+    while pool.get(running).unwrap().progress().is_none() {
+        std::thread::sleep(Duration::from_millis(1));
+    }
+
+    let snapshot = pool.snapshot();
+    assert_eq!(snapshot.len(), 2);
+    let status = &snapshot[0];
+    assert_eq!(status.id, running);
+    assert_eq!(status.state, FlowerLifecycle::Running);
+    assert_eq!(status.label.as_deref(), Some("import"));
+    assert_eq!(status.progress, Some(0.5));
+    assert!(status.elapsed.is_some());
+    assert!(!status.canceled);
+    assert_eq!(snapshot[1].id, queued);
+    assert_eq!(snapshot[1].state, FlowerLifecycle::Idle);
+    assert_eq!(snapshot[1].elapsed, None);
+    assert_eq!(
+        pool.iter_active()
+            .map(|flower| *flower.id())
+            .collect::<Vec<_>>(),
+        [running]
+    );
+    assert_eq!(pool.iter().count(), 2);
+
+    pool.cancel(running);
+    assert!(pool.snapshot()[0].canceled);
+    let results = pool.collect_results(|_, _| ());
+    assert_eq!(results.len(), 2);
+}
@@ -0,0 +1,39 @@
+use flowync::Flower;
+
+#[test]
+fn progress_fast_path() {
+    let flower = Flower::<String, ()>::new(1);
+    assert_eq!(flower.progress(), None);
+
+    let worker = std::thread::spawn({
+        let handle = flower.handle();
+        handle.activate();
+        move || {
+            for i in 0..=1000 {
+                handle.set_progress(i as f32 / 1000.0);
+            }
+            handle.send("done".to_string()).unwrap();
+            handle.ok(());
+        }
+    });
+
+    while !flower.channel_is_present() {
+        let p = flower.progress().unwrap_or(0.0);
+        assert!((0.0..=1.0).contains(&p));
+    }
+    assert_eq!(flower.progress(), Some(1.0));
+    assert_eq!(flower.wait(), Ok(()));
+    worker.join().unwrap();
+}
+
+#[test]
+fn progress_clamped() {
+    let flower = Flower::<(), ()>::new(1);
+    let handle = flower.handle();
+    handle.set_progress(2.5);
+    assert_eq!(flower.progress(), Some(1.0));
+    handle.set_progress(-1.0);
+    assert_eq!(flower.progress(), Some(0.0));
+    handle.set_progress(f32::NAN);
+    assert_eq!(flower.progress(), Some(0.0));
+}
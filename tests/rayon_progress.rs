@@ -0,0 +1,45 @@
+#![cfg(feature = "rayon")]
+use flowync::{Flower, ParallelProgressExt};
+use rayon::prelude::*;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+#[test]
+fn progress_reaches_one() {
+    let data: Vec<u64> = (1..=1_000).collect();
+    let flower: Flower<(), ()> = Flower::new(1);
+    let handle = flower.handle();
+    handle.activate();
+    let sum: u64 = data.par_iter().flower_progress(&handle, data.len()).sum();
+    assert_eq!(sum, 500_500);
+    assert_eq!(flower.progress(), Some(1.0));
+}
+
+#[test]
+fn progress_works_with_par_bridge() {
+    let flower: Flower<(), ()> = Flower::new(2);
+    let handle = flower.handle();
+    handle.activate();
+    let count = (0..250).par_bridge().flower_progress(&handle, 500).count();
+    assert_eq!(count, 250);
+    assert_eq!(flower.progress(), Some(0.5));
+}
+
+#[test]
+fn cancel_short_circuits_the_iterator() {
+    let flower: Flower<(), ()> = Flower::new(3);
+    let handle = flower.handle();
+    handle.activate();
+    let taken = AtomicUsize::new(0);
+    let total = 100_000;
+    (0..total)
+        .into_par_iter()
+        .flower_progress(&handle, total)
+        .for_each(|i| {
+            taken.fetch_add(1, Ordering::Relaxed);
+            if i == 10 {
+                flower.cancel();
+            }
+        });
+    assert!(taken.load(Ordering::Relaxed) < total);
+    assert!(flower.progress().unwrap_or(0.0) < 1.0);
+}
@@ -0,0 +1,77 @@
+use flowync::{Flower, SendErrorReason};
+use std::sync::mpsc::RecvTimeoutError;
+use std::time::Duration;
+
+#[test]
+fn handles_feed_the_receivers_directly() {
+    let flower: Flower<u32, String> = Flower::new(1);
+    let values = flower.channel_receiver();
+    let results = flower.result_receiver();
+    let handle = flower.handle();
+    handle.activate();
+    let worker = std::thread::spawn(move || {
+        // Nothing polls the flower, the sends don't block.
+        for i in 0..3 {
+            handle.send(i).unwrap();
+        }
+        handle.ok("done".into());
+    });
+
+    let received: Vec<_> = (0..3)
+        .map(|_| values.recv_timeout(Duration::from_secs(5)).unwrap())
+        .collect();
+    assert_eq!(received, vec![0, 1, 2]);
+    assert_eq!(
+        results.recv_timeout(Duration::from_secs(5)),
+        Ok(Ok("done".to_string()))
+    );
+    worker.join().unwrap();
+    assert!(!flower.is_active());
+    assert!(!flower.channel_is_present());
+}
+
+#[test]
+fn dropped_receiver_fails_the_send() {
+    let flower: Flower<u32, String> = Flower::new(2);
+    let values = flower.channel_receiver();
+    let handle = flower.handle();
+    handle.activate();
+    drop(values);
+
+    let err = handle.send(1).unwrap_err();
+    assert_eq!(err.reason(), SendErrorReason::ConsumerDropped);
+    assert_eq!(err.into_inner(), 1);
+}
+
+#[test]
+fn result_falls_back_to_the_flower_without_receiver() {
+    let flower: Flower<u32, String> = Flower::new(3);
+    let values = flower.channel_receiver();
+    let results = flower.result_receiver();
+    drop(results);
+    let handle = flower.handle();
+    handle.activate();
+    handle.send_latest(1);
+    handle.err("failed");
+
+    assert_eq!(values.try_recv(), Ok(1));
+    // Disconnected by the result.
+    assert_eq!(
+        values.recv_timeout(Duration::from_millis(10)),
+        Err(RecvTimeoutError::Disconnected)
+    );
+    assert_eq!(flower.try_result(), Some(Err("failed".to_string())));
+}
+
+#[test]
+fn canceled_flower_fails_the_bridged_send() {
+    let flower: Flower<u32, String> = Flower::new(4);
+    let _values = flower.channel_receiver();
+    let handle = flower.handle();
+    handle.activate();
+    flower.cancel();
+    assert_eq!(
+        handle.send(1).unwrap_err().reason(),
+        SendErrorReason::Canceled
+    );
+}
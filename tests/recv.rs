@@ -0,0 +1,108 @@
+use flowync::{Flower, FlowerBuilder, FlowerEvent};
+use std::sync::{
+    atomic::{AtomicUsize, Ordering},
+    Arc,
+};
+use std::time::Duration;
+
+#[test]
+fn recv_returns_values_then_the_result() {
+    let flower: Flower<u32, String> = Flower::spawn(1, |handle| {
+        for i in 0..3 {
+            handle.send(i).unwrap();
+        }
+        handle.ok("done".into());
+    });
+    let mut events = Vec::new();
+    loop {
+        let event = flower.recv();
+        let done = matches!(event, FlowerEvent::Done(_));
+        events.push(event);
+        if done {
+            break;
+        }
+    }
+    assert_eq!(
+        events,
+        vec![
+            FlowerEvent::Channel(0),
+            FlowerEvent::Channel(1),
+            FlowerEvent::Channel(2),
+            FlowerEvent::Done(Ok("done".to_string())),
+        ]
+    );
+}
+
+#[test]
+fn recv_timeout_gives_up() {
+    let flower: Flower<u32, ()> = Flower::new(2);
+    let handle = flower.handle();
+    handle.activate();
+    assert_eq!(flower.recv_timeout(Duration::from_millis(10)), None);
+    handle.send_latest(1);
+    assert_eq!(
+        flower.recv_timeout(Duration::from_millis(10)),
+        Some(FlowerEvent::Channel(1))
+    );
+}
+
+#[test]
+fn recv_wakes_on_a_late_result() {
+    let flower: Flower<(), u32> = Flower::new(3);
+    let handle = flower.handle();
+    handle.activate();
+    let worker = std::thread::spawn(move || {
+        std::thread::sleep(Duration::from_millis(20));
+        handle.ok(7);
+    });
+    assert_eq!(
+        flower.recv_timeout(Duration::from_secs(5)),
+        Some(FlowerEvent::Done(Ok(7)))
+    );
+    worker.join().unwrap();
+}
+
+#[test]
+fn recv_coexists_with_then_on_another_thread() {
+    const VALUES: usize = 100;
+    let flower: Flower<usize, ()> = FlowerBuilder::new(4).capacity(4).build();
+    let handle = flower.handle();
+    handle.activate();
+    let worker = std::thread::spawn(move || {
+        for i in 0..VALUES {
+            handle.send(i).unwrap();
+        }
+    });
+    let count = Arc::new(AtomicUsize::new(0));
+    let poller = {
+        let flower = flower.clone();
+        let count = Arc::clone(&count);
+        std::thread::spawn(move || {
+            let mut taken = Vec::new();
+            while count.load(Ordering::SeqCst) < VALUES {
+                flower.then(
+                    |v| {
+                        if let Some(v) = v {
+                            taken.push(v);
+                            count.fetch_add(1, Ordering::SeqCst);
+                        }
+                    },
+                    |_| (),
+                );
+            }
+            taken
+        })
+    };
+    // Never sleeps past a value, even if the other thread took the one it woke up for.
+    let mut all = Vec::new();
+    while count.load(Ordering::SeqCst) < VALUES {
+        if let Some(FlowerEvent::Channel(v)) = flower.recv_timeout(Duration::from_millis(10)) {
+            all.push(v);
+            count.fetch_add(1, Ordering::SeqCst);
+        }
+    }
+    worker.join().unwrap();
+    all.extend(poller.join().unwrap());
+    all.sort_unstable();
+    assert_eq!(all, (0..VALUES).collect::<Vec<_>>());
+}
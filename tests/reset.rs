@@ -0,0 +1,40 @@
+use flowync::{Flower, ResetWhileActive};
+
+#[test]
+fn reset_for_another_run() {
+    let flower = Flower::<u32, u32>::new(1);
+    let handle = flower.handle();
+    handle.activate();
+    assert_eq!(flower.reset(), Err(ResetWhileActive));
+
+    handle.ok(1);
+    flower.cancel();
+    assert_eq!(flower.reset(), Ok(()));
+    assert!(!flower.is_active());
+    assert!(!flower.is_canceled());
+    assert!(!flower.result_is_ready());
+    assert_eq!(flower.try_result(), None);
+
+    std::thread::spawn({
+        let handle = flower.handle();
+        handle.activate();
+        move || handle.ok(2)
+    });
+    assert_eq!(flower.wait(), Ok(2));
+}
+
+#[test]
+fn reset_wakes_stale_sender() {
+    let flower = Flower::<u32, u32>::new(1);
+    let worker = std::thread::spawn({
+        let handle = flower.handle();
+        move || handle.send(1)
+    });
+
+    while !flower.channel_is_present() {
+        std::thread::yield_now();
+    }
+    assert_eq!(flower.reset(), Ok(()));
+    assert!(!flower.channel_is_present());
+    assert!(worker.join().unwrap().is_ok());
+}
@@ -0,0 +1,48 @@
+use flowync::Flower;
+use std::time::{Duration, Instant};
+
+#[test]
+fn retry_until_success() {
+    let flower: Flower<u32, u32> =
+        Flower::spawn_with_retry(1, 5, Duration::from_millis(1), |handle, attempt| {
+            assert_eq!(handle.attempt(), attempt);
+            handle.send(attempt).unwrap();
+            if attempt < 3 {
+                Err("not yet")
+            } else {
+                Ok(attempt * 10)
+            }
+        });
+    assert_eq!(flower.iter().collect::<Vec<_>>(), vec![1, 2, 3]);
+    assert_eq!(flower.wait(), Ok(30));
+    assert_eq!(flower.attempt(), 3);
+}
+
+#[test]
+fn retry_gives_up_with_last_error() {
+    let flower: Flower<(), ()> =
+        Flower::spawn_with_retry(2, 3, Duration::from_millis(1), |_, attempt| {
+            Err(format!("failed attempt {}", attempt))
+        });
+    assert_eq!(
+        flower.wait(),
+        Err("failed attempt 3 (after 3 attempts)".to_string())
+    );
+}
+
+#[test]
+fn cancel_during_backoff_aborts_promptly() {
+    let flower: Flower<u32, ()> =
+        Flower::spawn_with_retry(3, 5, Duration::from_secs(10), |handle, attempt| {
+            handle.send(attempt).unwrap();
+            Err("failed")
+        });
+    assert_eq!(flower.iter().next(), Some(1));
+    let instant = Instant::now();
+    flower.cancel();
+    assert_eq!(
+        flower.wait(),
+        Err("the flower with id: 3 canceled".to_string())
+    );
+    assert!(instant.elapsed() < Duration::from_secs(1));
+}
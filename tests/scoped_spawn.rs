@@ -0,0 +1,34 @@
+use flowync::Flower;
+
+#[test]
+fn scoped_worker_borrows_local_data() {
+    let items = vec![1u32, 2, 3, 4];
+    let mut sum = 0;
+    std::thread::scope(|scope| {
+        let flower: Flower<&u32, usize> = Flower::new(1);
+        flower.scoped_spawn(scope, |handle| {
+            for item in &items {
+                handle.send(item).unwrap();
+            }
+            handle.ok(items.len());
+        });
+        for item in flower.iter() {
+            sum += item;
+        }
+        assert_eq!(flower.wait(), Ok(4));
+    });
+    assert_eq!(sum, 10);
+}
+
+#[test]
+fn scoped_worker_panic_is_reported() {
+    std::thread::scope(|scope| {
+        let flower: Flower<(), ()> = Flower::new(2);
+        let worker = flower.scoped_spawn(scope, |_| panic!("boom"));
+        assert!(worker.join().is_err());
+        assert_eq!(
+            flower.wait(),
+            Err("the flower handle with id: 2 error, the thread panicked maybe?".to_string())
+        );
+    });
+}
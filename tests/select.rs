@@ -0,0 +1,62 @@
+use flowync::{select, select_wait, Flower, FlowerPoll};
+use std::time::Duration;
+
+#[test]
+fn select_is_fair() {
+    let flowers: Vec<Flower<u32, ()>> = (0..3).map(Flower::new_buffered).collect();
+    for flower in &flowers {
+        let handle = flower.handle();
+        handle.activate();
+        for i in 0..10 {
+            handle.send(i).unwrap();
+        }
+    }
+
+    let mut counts = [0; 3];
+    for _ in 0..9 {
+        match select(&flowers) {
+            Some((id, FlowerPoll::Channel(_))) => counts[id] += 1,
+            _ => unreachable!(),
+        }
+    }
+    assert_eq!(counts, [3, 3, 3]);
+}
+
+#[test]
+fn select_idle() {
+    let flowers: Vec<Flower<u32, ()>> = (0..3).map(Flower::new).collect();
+    assert!(select(&flowers).is_none());
+    assert!(select::<u32, (), String>(&[]).is_none());
+}
+
+#[test]
+fn select_wait_parks_until_ready() {
+    let flowers: Vec<Flower<u32, u32>> = (0..4).map(Flower::new).collect();
+    let handles: Vec<_> = flowers.iter().map(Flower::handle).collect();
+    for handle in &handles {
+        handle.activate();
+    }
+    std::thread::spawn({
+        let handle = flowers[2].handle();
+        move || {
+            std::thread::sleep(Duration::from_millis(20));
+            handle.send(7).unwrap();
+            handle.ok(8);
+        }
+    });
+
+    match select_wait(&flowers) {
+        Some((2, FlowerPoll::Channel(7))) => (),
+        _ => unreachable!(),
+    }
+    match select_wait(&flowers) {
+        Some((2, FlowerPoll::Done(Ok(8)))) => (),
+        _ => unreachable!(),
+    }
+}
+
+#[test]
+fn select_wait_inactive() {
+    let flowers: Vec<Flower<u32, ()>> = (0..2).map(Flower::new).collect();
+    assert!(select_wait(&flowers).is_none());
+}
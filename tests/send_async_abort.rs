@@ -0,0 +1,66 @@
+use flowync::{Flower, FlowerBuilder, FlowerPoll};
+use std::time::Duration;
+
+async fn poll_value(flower: &Flower<u32, ()>) -> u32 {
+    loop {
+        if let FlowerPoll::Channel(value) = flower.poll() {
+            return value;
+        }
+        tokio::task::yield_now().await;
+    }
+}
+
+#[tokio::test]
+async fn aborted_send_async_withdraws_its_value() {
+    let flower: Flower<u32, ()> = Flower::new(1);
+    let handle = flower.handle();
+    handle.activate();
+    let task = tokio::spawn({
+        let handle = handle.clone();
+        async move { handle.send_async(1).await }
+    });
+    while !flower.channel_is_present() {
+        tokio::task::yield_now().await;
+    }
+    task.abort();
+    assert!(task.await.unwrap_err().is_cancelled());
+    assert!(!flower.channel_is_present());
+    assert_eq!(flower.channel_len(), 0);
+    assert!(matches!(flower.poll(), FlowerPoll::Idle));
+
+    // A fresh sender is delivered and released as usual.
+    let task = tokio::spawn(async move { handle.send_async(2).await });
+    assert_eq!(poll_value(&flower).await, 2);
+    tokio::time::timeout(Duration::from_secs(5), task)
+        .await
+        .unwrap()
+        .unwrap()
+        .unwrap();
+}
+
+#[tokio::test]
+async fn aborted_buffered_send_async_leaves_the_queue_intact() {
+    let flower: Flower<u32, ()> = FlowerBuilder::new(2).capacity(1).build();
+    let handle = flower.handle();
+    handle.activate();
+    handle.send(1).unwrap();
+    let task = tokio::spawn({
+        let handle = handle.clone();
+        async move { handle.send_async(2).await }
+    });
+    tokio::time::sleep(Duration::from_millis(20)).await;
+    assert!(!task.is_finished());
+    task.abort();
+    assert!(task.await.unwrap_err().is_cancelled());
+    assert_eq!(flower.channel_len(), 1);
+
+    assert_eq!(poll_value(&flower).await, 1);
+    assert!(matches!(flower.poll(), FlowerPoll::Idle));
+    let task = tokio::spawn(async move { handle.send_async(3).await });
+    assert_eq!(poll_value(&flower).await, 3);
+    tokio::time::timeout(Duration::from_secs(5), task)
+        .await
+        .unwrap()
+        .unwrap()
+        .unwrap();
+}
@@ -0,0 +1,42 @@
+use flowync::{Flower, FlowerBuilder};
+use std::time::Duration;
+
+const ROUNDS: usize = 100;
+const VALUES: u32 = 50;
+
+// The consumer polls from its own thread while the async sender is being suspended,
+// the sender must always be woken once its value is taken or there's room again.
+async fn race(new_flower: impl Fn() -> Flower<u32, ()>) {
+    for _ in 0..ROUNDS {
+        let flower = new_flower();
+        let handle = flower.handle();
+        handle.activate();
+        let sender = tokio::spawn(async move {
+            for i in 0..VALUES {
+                handle.send_async(i).await.unwrap();
+            }
+        });
+        let consumer = std::thread::spawn(move || {
+            let mut received = Vec::new();
+            while received.len() < VALUES as usize {
+                flower.then(|value| received.extend(value), |_| ());
+            }
+            received
+        });
+        tokio::time::timeout(Duration::from_secs(5), sender)
+            .await
+            .expect("the sender was never woken")
+            .unwrap();
+        assert_eq!(consumer.join().unwrap(), (0..VALUES).collect::<Vec<_>>());
+    }
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn send_async_always_completes() {
+    race(|| Flower::new(1)).await;
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn buffered_send_async_always_completes() {
+    race(|| FlowerBuilder::new(2).capacity(1).build()).await;
+}
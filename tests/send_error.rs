@@ -0,0 +1,54 @@
+use flowync::{Flower, FlowerBuilder, SendErrorReason};
+
+#[test]
+fn delivered_send_is_ok() {
+    let flower: Flower<u32, ()> = Flower::new(1);
+    let handle = flower.handle();
+    handle.activate();
+    let worker = std::thread::spawn(move || handle.send(1));
+
+    let mut value = None;
+    while value.is_none() {
+        flower.then(|v| value = v, |_| ());
+    }
+    assert_eq!(value, Some(1));
+    assert!(worker.join().unwrap().is_ok());
+}
+
+#[test]
+fn canceled_send_gives_value_back() {
+    let flower: Flower<u32, ()> = Flower::new(2);
+    let handle = flower.handle();
+    handle.activate();
+    flower.cancel();
+
+    let err = handle.send(7).unwrap_err();
+    assert_eq!(err.reason(), SendErrorReason::Canceled);
+    assert_eq!(err.to_string(), "the flower is canceled");
+    assert_eq!(err.into_inner(), 7);
+}
+
+#[test]
+fn dropped_flower_send_gives_value_back() {
+    let flower: Flower<String, ()> = FlowerBuilder::new(3).capacity(1).build();
+    let handle = flower.handle();
+    handle.activate();
+    drop(flower);
+
+    let err = handle.send("lost".into()).unwrap_err();
+    assert_eq!(err.reason(), SendErrorReason::ConsumerDropped);
+    assert_eq!(err.to_string(), "the flower is dropped");
+    assert_eq!(err.into_inner(), "lost");
+}
+
+#[tokio::test]
+async fn send_async_reports_cancel() {
+    let flower: Flower<u32, ()> = Flower::new(4);
+    let handle = flower.handle();
+    handle.activate();
+    flower.cancel();
+
+    let err = handle.send_async(9).await.unwrap_err();
+    assert_eq!(err.reason(), SendErrorReason::Canceled);
+    assert_eq!(err.into_inner(), 9);
+}
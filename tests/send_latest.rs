@@ -0,0 +1,49 @@
+use flowync::Flower;
+use std::time::Duration;
+
+#[test]
+fn send_latest_overwrites() {
+    let flower: Flower<u32, ()> = Flower::new(1);
+    let handle = flower.handle();
+    handle.activate();
+    for i in 0..5 {
+        handle.send_latest(i);
+    }
+    assert!(flower.channel_is_present());
+    assert_eq!(flower.dropped_count(), 4);
+
+    let mut values = Vec::new();
+    flower.then(|value| values.extend(value), |_| ());
+    flower.then(|value| values.extend(value), |_| ());
+    assert_eq!(values, vec![4]);
+}
+
+#[test]
+fn latest_skips_stale_values() {
+    let flower: Flower<u32, ()> = Flower::new_buffered(2);
+    let handle = flower.handle();
+    handle.activate();
+    for i in 0..3 {
+        handle.send(i).unwrap();
+    }
+    assert_eq!(flower.latest(), Some(2));
+    assert_eq!(flower.latest(), None);
+    assert_eq!(flower.dropped_count(), 2);
+    assert!(!flower.channel_is_present());
+}
+
+#[test]
+fn send_latest_wakes_blocked_sender() {
+    let flower: Flower<u32, ()> = Flower::new(3);
+    let blocked = std::thread::spawn({
+        let handle = flower.handle();
+        move || handle.send(1)
+    });
+    while !flower.channel_is_present() {
+        std::thread::sleep(Duration::from_millis(1));
+    }
+    flower.handle().send_latest(2);
+    assert!(blocked.join().unwrap().is_ok());
+    assert_eq!(flower.latest(), Some(2));
+    assert_eq!(flower.dropped_count(), 1);
+}
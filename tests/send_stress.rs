@@ -0,0 +1,50 @@
+use flowync::{Flower, FlowerBuilder};
+use std::collections::HashSet;
+
+const SENDERS: u32 = 4;
+const VALUES: u32 = 5_000;
+
+// Every value is received exactly once, however the senders are woken.
+fn hammer(flower: Flower<u32, ()>) {
+    let workers: Vec<_> = (0..SENDERS)
+        .map(|n| {
+            let handle = flower.handle();
+            handle.activate();
+            std::thread::spawn(move || {
+                for i in 0..VALUES {
+                    handle.send(n * VALUES + i).unwrap();
+                }
+            })
+        })
+        .collect();
+
+    let mut received = HashSet::new();
+    let expected = (SENDERS * VALUES) as usize;
+    let mut count = 0;
+    while count < expected {
+        flower.then(
+            |value| {
+                if let Some(value) = value {
+                    assert!(received.insert(value), "{} received twice", value);
+                    count += 1;
+                }
+            },
+            |_| (),
+        );
+    }
+    for worker in workers {
+        worker.join().unwrap();
+    }
+    assert_eq!(received.len(), expected);
+    assert!(!flower.channel_is_present());
+}
+
+#[test]
+fn concurrent_sends_are_neither_lost_nor_duplicated() {
+    hammer(Flower::new(1));
+}
+
+#[test]
+fn concurrent_buffered_sends_are_neither_lost_nor_duplicated() {
+    hammer(FlowerBuilder::new(2).capacity(8).build());
+}
@@ -0,0 +1,59 @@
+use flowync::{Flower, FlowerBuilder, FlowerPoll};
+
+#[test]
+fn tags_travel_with_the_values() {
+    let flower: Flower<u64, ()> = Flower::new_buffered(1);
+    let handle = flower.handle();
+    handle.activate();
+    handle.send_tagged(1, 512).unwrap();
+    handle.send(7).unwrap();
+    handle.send_tagged(2, 1024).unwrap();
+    handle.ok(());
+
+    let mut values = Vec::new();
+    let mut done = false;
+    while !done {
+        flower.then_tagged(|value| values.extend(value), |_| done = true);
+    }
+    assert_eq!(values, [(1, 512), (0, 7), (2, 1024)]);
+}
+
+#[test]
+fn untagged_consumers_only_get_the_values() {
+    let flower: Flower<u64, ()> = Flower::new(2);
+    let handle = flower.handle();
+    handle.activate();
+    let worker = std::thread::spawn(move || handle.send_tagged(3, 9).unwrap());
+    while !flower.channel_is_present() {
+        std::thread::yield_now();
+    }
+    assert!(matches!(flower.poll(), FlowerPoll::Channel(9)));
+    worker.join().unwrap();
+}
+
+#[test]
+fn dropped_values_take_their_tag_along() {
+    let flower: Flower<u64, ()> = FlowerBuilder::new(3)
+        .capacity(1)
+        .backpressure(flowync::Backpressure::DropOldest)
+        .build();
+    let handle = flower.handle();
+    handle.activate();
+    handle.send_tagged(1, 1).unwrap();
+    handle.send_tagged(2, 2).unwrap();
+    assert!(matches!(flower.poll_tagged(), FlowerPoll::Channel((2, 2))));
+    handle.send_tagged(5, 3).unwrap();
+    handle.send_latest(4);
+    assert!(matches!(flower.poll_tagged(), FlowerPoll::Channel((0, 4))));
+}
+
+#[test]
+fn broadcast_consumers_get_the_tag() {
+    let flower: Flower<u64, ()> = FlowerBuilder::new(4).buffered(true).build_broadcast();
+    let other = flower.clone();
+    let handle = flower.handle();
+    handle.activate();
+    handle.send_tagged(6, 60).unwrap();
+    assert!(matches!(flower.poll_tagged(), FlowerPoll::Channel((6, 60))));
+    assert!(matches!(other.poll_tagged(), FlowerPoll::Channel((6, 60))));
+}
@@ -0,0 +1,48 @@
+use flowync::{Flower, FlowerBuilder};
+use std::time::Duration;
+
+fn drain(flower: &Flower<u32, ()>) -> Vec<u32> {
+    let mut values = Vec::new();
+    flower.then_drain(|value| values.push(value), |_| ());
+    values
+}
+
+#[test]
+fn values_within_the_interval_are_dropped() {
+    let flower: Flower<u32, ()> = FlowerBuilder::new(1).capacity(16).build();
+    let handle = flower.handle();
+    handle.activate();
+    let interval = Duration::from_millis(50);
+
+    assert!(handle.send_throttled(1, interval));
+    assert!(!handle.send_throttled(2, interval));
+    assert!(!handle.send_throttled(3, interval));
+    std::thread::sleep(interval);
+    assert!(handle.send_throttled(4, interval));
+    // The final value is forced through.
+    handle.send(5).unwrap();
+    assert_eq!(drain(&flower), vec![1, 4, 5]);
+    assert_eq!(flower.dropped_count(), 2);
+}
+
+#[test]
+fn handle_clones_share_the_throttle() {
+    let flower: Flower<u32, ()> = FlowerBuilder::new(2).capacity(16).build();
+    let handle = flower.handle();
+    let other = handle.clone();
+    handle.activate();
+
+    assert!(handle.send_throttled(1, Duration::from_secs(60)));
+    assert!(!other.send_throttled(2, Duration::from_secs(60)));
+    assert!(other.send_throttled(3, Duration::ZERO));
+    assert_eq!(drain(&flower), vec![1, 3]);
+}
+
+#[test]
+fn throttled_send_after_cancel_is_not_sent() {
+    let flower: Flower<u32, ()> = FlowerBuilder::new(3).capacity(16).build();
+    let handle = flower.handle();
+    handle.activate();
+    flower.cancel();
+    assert!(!handle.send_throttled(1, Duration::ZERO));
+}
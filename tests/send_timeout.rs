@@ -0,0 +1,58 @@
+use flowync::Flower;
+use std::time::{Duration, Instant};
+
+#[test]
+fn send_timeout_gives_value_back() {
+    let flower = Flower::<u32, ()>::new(1);
+    let handle = flower.handle();
+    handle.activate();
+
+    let instant = Instant::now();
+    let result = handle.send_timeout(7, Duration::from_millis(50));
+    assert!(instant.elapsed() >= Duration::from_millis(50));
+    assert_eq!(result.unwrap_err().into_inner(), 7);
+    assert!(!flower.channel_is_present());
+}
+
+#[test]
+fn send_timeout_delivered() {
+    let flower = Flower::<u32, ()>::new(1);
+    let worker = std::thread::spawn({
+        let handle = flower.handle();
+        handle.activate();
+        move || {
+            let result = handle.send_timeout(7, Duration::from_secs(2));
+            handle.ok(());
+            result
+        }
+    });
+
+    let mut received = None;
+    let mut exit = false;
+    while !exit {
+        flower.then(
+            |channel| {
+                if channel.is_some() {
+                    received = channel;
+                }
+            },
+            |_| exit = true,
+        );
+    }
+
+    assert_eq!(received, Some(7));
+    assert!(worker.join().unwrap().is_ok());
+}
+
+#[test]
+fn buffered_send_timeout_after_cancel_is_rejected() {
+    let flower = Flower::<u32, ()>::new_buffered(1);
+    let handle = flower.handle();
+    handle.activate();
+    flower.cancel();
+
+    let result = handle.send_timeout(7, Duration::from_secs(2));
+    assert_eq!(result.unwrap_err().into_inner(), 7);
+    assert!(!flower.channel_is_present());
+    assert_eq!(flower.channel_len(), 0);
+}
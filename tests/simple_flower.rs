@@ -0,0 +1,45 @@
+use flowync::{Flower, SendErrorReason, SimpleFlower, SimpleFlowerHandle};
+use std::time::Duration;
+
+fn work(handle: SimpleFlowerHandle<u32>) {
+    handle.activate();
+    handle.ok(42);
+}
+
+#[test]
+fn result_only_flower_delivers_the_result() {
+    let flower: SimpleFlower<u32> = SimpleFlower::result_only(1);
+    let handle = flower.handle();
+    std::thread::spawn(move || work(handle)).join().unwrap();
+
+    let mut result = None;
+    flower.then(|value| assert_eq!(value, None), |r| result = Some(r));
+    assert_eq!(result, Some(Ok(42)));
+    assert!(!flower.is_active());
+}
+
+#[test]
+fn simple_flower_is_a_flower_without_channel() {
+    let flower: Flower<(), u32> = SimpleFlower::result_only(2);
+    let handle: SimpleFlowerHandle<u32> = flower.handle();
+    handle.activate();
+    assert!(!flower.channel_is_present());
+    handle.err("failed");
+    assert_eq!(flower.try_result(), Some(Err("failed".to_string())));
+}
+
+#[test]
+fn result_only_flower_rejects_values_without_waiting() {
+    let flower: SimpleFlower<u32> = SimpleFlower::result_only(3);
+    let handle = flower.handle();
+    handle.activate();
+    let error = handle.send(()).unwrap_err();
+    assert_eq!(error.reason(), SendErrorReason::NoChannel);
+    assert_eq!(error.to_string(), "the flower has no channel");
+    assert!(handle.send_timeout((), Duration::from_secs(5)).is_err());
+    handle.send_latest(());
+    assert!(!flower.channel_is_present());
+
+    handle.ok(1);
+    assert_eq!(flower.wait(), Ok(1));
+}
@@ -0,0 +1,29 @@
+use flowync::{Flower, FlowerHandle};
+use std::mem::size_of;
+
+// One pointer to the shared state, the flower also keeps the broadcast cursor
+// and the number of results observed by this clone, the handle the run it belongs to.
+#[cfg(target_pointer_width = "64")]
+#[test]
+fn flower_and_handle_hold_a_single_pointer() {
+    assert_eq!(
+        size_of::<Flower<u8, u8>>(),
+        size_of::<usize>() + 2 * size_of::<u64>()
+    );
+    assert_eq!(
+        size_of::<FlowerHandle<u8, u8>>(),
+        size_of::<usize>() + size_of::<u64>()
+    );
+}
+
+#[test]
+fn size_doesnt_depend_on_the_types() {
+    assert_eq!(
+        size_of::<Flower<[u8; 1024], String>>(),
+        size_of::<Flower<u8, u8>>()
+    );
+    assert_eq!(
+        size_of::<FlowerHandle<[u8; 1024], String>>(),
+        size_of::<FlowerHandle<u8, u8>>()
+    );
+}
@@ -0,0 +1,70 @@
+use flowync::Flower;
+
+// The panic error without the location added by the `panic-info` feature.
+fn panicked(flower: Flower<(), ()>) -> Result<(), String> {
+    flower
+        .wait()
+        .map_err(|e| match e.find(" at tests/spawn.rs:") {
+            Some(at) if cfg!(feature = "panic-info") => e[..at].to_string(),
+            _ => e,
+        })
+}
+
+#[test]
+fn spawn_activates_and_names_thread() {
+    let flower: Flower<String, ()> = Flower::spawn(7, |handle| {
+        assert!(handle.is_active());
+        handle
+            .send(std::thread::current().name().unwrap().to_string())
+            .unwrap();
+        handle.ok(());
+    });
+    assert_eq!(
+        flower.iter().collect::<Vec<_>>(),
+        vec!["flower-7".to_string()]
+    );
+    assert_eq!(flower.wait(), Ok(()));
+}
+
+#[test]
+fn spawn_result_sets_the_result() {
+    let flower: Flower<u32, u32> = Flower::spawn_result(1, |handle| {
+        handle.send(1).unwrap();
+        Ok(2)
+    });
+    assert_eq!(flower.iter().collect::<Vec<_>>(), vec![1]);
+    assert_eq!(flower.wait(), Ok(2));
+
+    let flower: Flower<(), u32> = Flower::spawn_result(2, |_| Err("failed".to_string()));
+    assert_eq!(flower.wait(), Err("failed".to_string()));
+}
+
+#[test]
+fn spawn_reports_panic() {
+    let flower: Flower<(), ()> = Flower::spawn(3, |_| panic!("boom"));
+    assert_eq!(panicked(flower), Err("worker panicked: boom".to_string()));
+}
+
+#[test]
+fn spawn_reports_panic_message() {
+    let flower: Flower<(), ()> = Flower::spawn(4, |_| panic!("failed at step {}", 2));
+    assert_eq!(
+        panicked(flower),
+        Err("worker panicked: failed at step 2".to_string())
+    );
+
+    let flower: Flower<(), ()> = Flower::spawn(5, |_| std::panic::panic_any(42));
+    assert_eq!(
+        panicked(flower),
+        Err("worker panicked: Box<dyn Any>".to_string())
+    );
+}
+
+#[test]
+fn spawn_panic_with_cloned_handle() {
+    let flower: Flower<(), ()> = Flower::spawn(6, |handle| {
+        let _clone = handle.clone();
+        panic!("boom")
+    });
+    assert_eq!(panicked(flower), Err("worker panicked: boom".to_string()));
+}
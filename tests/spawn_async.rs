@@ -0,0 +1,36 @@
+#![cfg(feature = "tokio")]
+use flowync::Flower;
+
+#[tokio::test]
+async fn spawn_async_sets_the_result() {
+    let flower: Flower<u32, u32> = Flower::spawn_async(1, |handle| async move {
+        assert!(handle.is_active());
+        handle.send_async(1).await.unwrap();
+        Ok(2)
+    });
+    let mut values = Vec::new();
+    loop {
+        let mut done = None;
+        flower.then(|value| values.extend(value), |result| done = Some(result));
+        if let Some(result) = done {
+            assert_eq!(result, Ok(2));
+            break;
+        }
+        tokio::task::yield_now().await;
+    }
+    assert_eq!(values, vec![1]);
+
+    let flower: Flower<(), u32> = Flower::spawn_async(2, |_| async { Err("failed".to_string()) });
+    assert_eq!(flower.await_result().await, Err("failed".to_string()));
+}
+
+#[tokio::test]
+async fn spawn_async_catches_panic() {
+    let flower: Flower<(), ()> = Flower::spawn_async(3, |_| async { panic!("boom") });
+    let error = flower.await_result().await.unwrap_err();
+    if cfg!(feature = "panic-info") {
+        assert!(error.starts_with("worker panicked: boom at tests/spawn_async.rs:"));
+    } else {
+        assert_eq!(error, "worker panicked: boom");
+    }
+}
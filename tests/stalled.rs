@@ -0,0 +1,38 @@
+use flowync::Flower;
+use std::time::Duration;
+
+#[test]
+fn never_activated_has_no_activity() {
+    let flower: Flower<u32, ()> = Flower::new(1);
+    assert_eq!(flower.time_since_last_activity(), None);
+    assert!(!flower.is_stalled(Duration::ZERO));
+}
+
+#[test]
+fn activity_resets_the_stall_clock() {
+    let flower: Flower<u32, ()> = flowync::FlowerBuilder::new(2).capacity(4).build();
+    let handle = flower.handle();
+    handle.activate();
+    std::thread::sleep(Duration::from_millis(30));
+    assert!(flower.is_stalled(Duration::from_millis(20)));
+
+    handle.send(1).unwrap();
+    assert!(!flower.is_stalled(Duration::from_millis(20)));
+    assert!(flower.time_since_last_activity().unwrap() < Duration::from_millis(20));
+
+    std::thread::sleep(Duration::from_millis(30));
+    assert!(flower.is_stalled(Duration::from_millis(20)));
+    handle.set_progress(0.5);
+    assert!(!flower.is_stalled(Duration::from_millis(20)));
+}
+
+#[test]
+fn finished_flower_is_not_stalled() {
+    let flower: Flower<u32, ()> = Flower::new(3);
+    let handle = flower.handle();
+    handle.activate();
+    handle.ok(());
+    std::thread::sleep(Duration::from_millis(30));
+    assert!(flower.time_since_last_activity().unwrap() >= Duration::from_millis(30));
+    assert!(!flower.is_stalled(Duration::from_millis(20)));
+}
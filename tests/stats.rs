@@ -0,0 +1,79 @@
+#![cfg(feature = "stats")]
+use flowync::{Backpressure, Flower, FlowerBuilder, FlowerStats};
+use std::time::Duration;
+
+#[test]
+fn counts_sends_deliveries_and_empty_polls() {
+    let flower: Flower<u32, ()> = Flower::new_buffered(1);
+    let handle = flower.handle();
+    handle.activate();
+    assert_eq!(flower.stats(), FlowerStats::default());
+
+    flower.then(|_| (), |_| ());
+    flower.then(|_| (), |_| ());
+    for i in 0..3 {
+        handle.send(i).unwrap();
+    }
+    for _ in 0..3 {
+        flower.then(|_| (), |_| ());
+    }
+    let stats = flower.stats();
+    assert_eq!(stats.sends, 3);
+    assert_eq!(stats.delivered, 3);
+    assert_eq!(stats.empty_polls, 2);
+    assert_eq!(stats.dropped, 0);
+}
+
+#[test]
+fn counts_dropped_values() {
+    let flower: Flower<u32, ()> = Flower::new_buffered(2);
+    let handle = flower.handle();
+    handle.activate();
+    handle.send_latest(1);
+    handle.send_latest(2);
+    assert!(handle.send_throttled(3, Duration::from_secs(60)));
+    assert!(!handle.send_throttled(4, Duration::from_secs(60)));
+    assert_eq!(flower.latest(), Some(3));
+    let stats = flower.stats();
+    assert_eq!(stats.sends, 4);
+    assert_eq!(stats.delivered, 1);
+    assert_eq!(stats.dropped, 3);
+
+    let flower: Flower<u32, ()> = FlowerBuilder::new(3)
+        .capacity(1)
+        .backpressure(Backpressure::DropOldest)
+        .build();
+    let handle = flower.handle();
+    handle.activate();
+    handle.send(1).unwrap();
+    handle.send(2).unwrap();
+    assert_eq!(flower.stats().dropped, 1);
+}
+
+#[test]
+fn measures_blocked_senders() {
+    let flower: Flower<u32, ()> = Flower::new(4);
+    let handle = flower.handle();
+    handle.activate();
+    let worker = std::thread::spawn(move || handle.send(1).unwrap());
+    while !flower.channel_is_present() {
+        std::thread::yield_now();
+    }
+    std::thread::sleep(Duration::from_millis(20));
+    flower.then(|_| (), |_| ());
+    worker.join().unwrap();
+    assert!(flower.stats().blocked >= Duration::from_millis(10));
+}
+
+#[test]
+fn reset_stats_clears_the_counters() {
+    let flower: Flower<u32, ()> = Flower::new_buffered(5);
+    let handle = flower.handle();
+    handle.activate();
+    handle.send(1).unwrap();
+    flower.then(|_| (), |_| ());
+    flower.then(|_| (), |_| ());
+    assert_ne!(flower.stats(), FlowerStats::default());
+    flower.reset_stats();
+    assert_eq!(flower.stats(), FlowerStats::default());
+}
@@ -0,0 +1,33 @@
+#![cfg(feature = "stream")]
+
+use flowync::{Flower, FlowerEvent};
+use futures_core::Stream;
+use std::{future::poll_fn, pin::Pin};
+
+#[tokio::test(flavor = "multi_thread")]
+async fn stream_channel_then_result() {
+    let flower = Flower::<u32, String>::new(1);
+    std::thread::spawn({
+        let handle = flower.handle();
+        handle.activate();
+        move || {
+            for i in 0..10 {
+                handle.send(i).unwrap();
+            }
+            handle.ok("Ok".to_string());
+        }
+    });
+
+    let mut stream = flower.into_stream();
+    let mut received = Vec::new();
+    let mut result = None;
+    while let Some(event) = poll_fn(|cx| Pin::new(&mut stream).poll_next(cx)).await {
+        match event {
+            FlowerEvent::Channel(value) => received.push(value),
+            FlowerEvent::Done(r) => result = Some(r),
+        }
+    }
+
+    assert_eq!(received, (0..10).collect::<Vec<_>>());
+    assert_eq!(result, Some(Ok("Ok".to_string())));
+}
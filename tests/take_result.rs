@@ -0,0 +1,75 @@
+use flowync::{Flower, FlowerBuilder, PanicPolicy};
+use std::panic::{self, AssertUnwindSafe};
+use std::time::Duration;
+
+#[test]
+fn take_ok_then_take_err() {
+    let flower = Flower::<(), u32>::new(1);
+    assert_eq!(flower.take_ok(), None);
+    assert_eq!(flower.take_err(), None);
+
+    let handle = flower.handle();
+    handle.activate();
+    handle.ok(1);
+    assert_eq!(flower.take_err(), None);
+    assert!(flower.is_active());
+    assert_eq!(flower.take_ok(), Some(1));
+    assert!(!flower.is_active());
+    assert_eq!(flower.take_ok(), None);
+}
+
+#[test]
+fn take_err_then_take_ok() {
+    let flower = Flower::<(), u32>::new(1);
+    let handle = flower.handle();
+    handle.activate();
+    handle.err("Err");
+    assert_eq!(flower.take_ok(), None);
+    assert!(flower.result_is_ready());
+    assert_eq!(flower.take_err(), Some("Err".to_string()));
+    assert!(!flower.result_is_ready());
+    assert_eq!(flower.take_err(), None);
+}
+
+#[test]
+fn take_err_stores_the_hard_deadline() {
+    let flower: Flower<(), u32> = FlowerBuilder::new(1)
+        .deadline(Duration::from_millis(10))
+        .hard_deadline(true)
+        .build();
+    let handle = flower.handle();
+    handle.activate();
+    std::thread::sleep(Duration::from_millis(20));
+    assert_eq!(flower.take_ok(), None);
+    // Stored by `take_ok`, the late success is discarded.
+    handle.ok(1);
+    assert_eq!(flower.take_err(), Some("deadline exceeded".to_string()));
+}
+
+#[test]
+fn take_ok_resumes_a_propagated_panic() {
+    let flower: Flower<(), ()> = FlowerBuilder::new(1)
+        .panic_policy(PanicPolicy::Propagate)
+        .build();
+    let handle = flower.handle();
+    handle.activate();
+    if let Err(payload) = panic::catch_unwind(|| panic!("boom")) {
+        handle.err_panic(payload);
+    }
+    let payload = panic::catch_unwind(AssertUnwindSafe(|| flower.take_ok())).unwrap_err();
+    assert_eq!(payload.downcast_ref::<&str>(), Some(&"boom"));
+}
+
+#[test]
+fn broadcast_clones_take_their_own_result() {
+    let flower: Flower<(), u32> = FlowerBuilder::new(1).build_broadcast();
+    let other = flower.clone();
+    let handle = flower.handle();
+    handle.activate();
+    handle.ok(1);
+    assert_eq!(flower.take_ok(), Some(1));
+    assert_eq!(flower.take_ok(), None);
+    assert!(flower.is_active());
+    assert_eq!(other.take_ok(), Some(1));
+    assert!(!flower.is_active());
+}
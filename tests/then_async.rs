@@ -0,0 +1,45 @@
+use flowync::Flower;
+use std::time::Duration;
+
+#[tokio::test]
+async fn then_async_wakes_sender_before_handler() {
+    let flower: Flower<u32, u32> = Flower::new(1);
+    let handle = flower.handle();
+    handle.activate();
+    let (sent, mut sent_rx) = tokio::sync::mpsc::unbounded_channel();
+    std::thread::spawn(move || {
+        for i in 0..3 {
+            handle.send(i).unwrap();
+            sent.send(i).unwrap();
+        }
+        handle.ok(3);
+    });
+
+    let mut values = Vec::new();
+    let mut result = None;
+    while result.is_none() {
+        flower
+            .then_async(
+                |value| {
+                    let values = &mut values;
+                    let sent_rx = &mut sent_rx;
+                    async move {
+                        if let Some(value) = value {
+                            // The worker returns from `send` while the handler is still running.
+                            assert_eq!(sent_rx.recv().await, Some(value));
+                            values.push(value);
+                        } else {
+                            tokio::time::sleep(Duration::from_millis(1)).await;
+                        }
+                    }
+                },
+                |r| {
+                    result = Some(r);
+                    async {}
+                },
+            )
+            .await;
+    }
+    assert_eq!(values, vec![0, 1, 2]);
+    assert_eq!(result, Some(Ok(3)));
+}
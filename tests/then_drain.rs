@@ -0,0 +1,48 @@
+use flowync::{Flower, FlowerBuilder};
+
+#[test]
+fn drains_buffered_values_and_result_at_once() {
+    let flower: Flower<u32, String> = FlowerBuilder::new(1).capacity(4).build();
+    let handle = flower.handle();
+    handle.activate();
+    for i in 1..=3 {
+        handle.send(i).unwrap();
+    }
+    handle.ok("done".into());
+
+    let mut values = Vec::new();
+    let mut result = None;
+    flower.then_drain(|value| values.push(value), |r| result = Some(r));
+    assert_eq!(values, vec![1, 2, 3]);
+    assert_eq!(result, Some(Ok("done".to_string())));
+    assert!(!flower.is_active());
+}
+
+#[test]
+fn delivers_last_value_with_result() {
+    let flower: Flower<u32, String> = Flower::new(2);
+    let handle = flower.handle();
+    handle.activate();
+    handle.send_latest(100);
+    handle.ok("done".into());
+
+    let mut values = Vec::new();
+    let mut result = None;
+    flower.then_drain(|value| values.push(value), |r| result = Some(r));
+    assert_eq!(values, vec![100]);
+    assert_eq!(result, Some(Ok("done".to_string())));
+}
+
+#[test]
+fn leaves_result_which_is_not_ready() {
+    let flower: Flower<u32, String> = FlowerBuilder::new(3).capacity(4).build();
+    let handle = flower.handle();
+    handle.activate();
+    handle.send(1).unwrap();
+
+    let mut values = Vec::new();
+    flower.then_drain(|value| values.push(value), |_| panic!("no result yet"));
+    assert_eq!(values, vec![1]);
+    flower.then_drain(|_| panic!("nothing queued"), |_| panic!("no result yet"));
+    assert!(flower.is_active());
+}
@@ -0,0 +1,26 @@
+use flowync::Flower;
+
+#[test]
+fn then_finished_returns_true_once_the_result_is_delivered() {
+    let flower: Flower<u32, String> = Flower::new(1);
+    let handle = flower.handle();
+    handle.activate();
+    let worker = std::thread::spawn(move || {
+        for i in 0..10 {
+            handle.send(i).unwrap();
+        }
+        handle.ok("Ok".into());
+    });
+
+    let (mut values, mut result) = (Vec::new(), None);
+    loop {
+        if flower.then_finished(|value| values.extend(value), |r| result = Some(r)) {
+            break;
+        }
+        assert!(result.is_none());
+    }
+    worker.join().unwrap();
+    assert_eq!(values, (0..10).collect::<Vec<_>>());
+    assert_eq!(result, Some(Ok("Ok".to_string())));
+    assert!(!flower.then_finished(|value| assert_eq!(value, None), |_| unreachable!()));
+}
@@ -0,0 +1,79 @@
+use flowync::{Flower, FlowerBuilder};
+use std::time::{Duration, Instant};
+
+#[test]
+fn then_wait_times_out_like_then() {
+    let flower: Flower<u32, ()> = Flower::new(1);
+    let handle = flower.handle();
+    handle.activate();
+
+    let start = Instant::now();
+    let mut idle = false;
+    let delivered = flower.then_wait(
+        Duration::from_millis(50),
+        |value| idle = value.is_none(),
+        |_| panic!("no result yet"),
+    );
+    assert!(!delivered);
+    assert!(idle);
+    assert!(start.elapsed() >= Duration::from_millis(50));
+}
+
+#[test]
+fn then_wait_is_woken_by_send_and_ok() {
+    let flower: Flower<u32, String> = Flower::new(2);
+    let handle = flower.handle();
+    handle.activate();
+    let worker = std::thread::spawn(move || {
+        std::thread::sleep(Duration::from_millis(20));
+        handle.send(1).unwrap();
+        std::thread::sleep(Duration::from_millis(20));
+        handle.ok("done".into());
+    });
+
+    let mut value = None;
+    assert!(flower.then_wait(
+        Duration::from_secs(5),
+        |v| value = v,
+        |_| panic!("value first")
+    ));
+    assert_eq!(value, Some(1));
+    let mut result = None;
+    assert!(flower.then_wait(
+        Duration::from_secs(5),
+        |_| panic!("no value"),
+        |r| result = Some(r)
+    ));
+    assert_eq!(result, Some(Ok("done".to_string())));
+    worker.join().unwrap();
+}
+
+#[test]
+fn then_wait_mixes_with_then() {
+    let flower: Flower<u32, ()> = FlowerBuilder::new(3).capacity(4).build();
+    let handle = flower.handle();
+    handle.activate();
+    handle.send(1).unwrap();
+    handle.send(2).unwrap();
+
+    let mut values = Vec::new();
+    flower.then(|v| values.extend(v), |_| ());
+    assert!(flower.then_wait(Duration::from_secs(5), |v| values.extend(v), |_| ()));
+    assert_eq!(values, vec![1, 2]);
+}
+
+#[test]
+fn then_wait_on_broadcast_waits_for_an_unobserved_result() {
+    let flower: Flower<u32, u32> = FlowerBuilder::new(4).build_broadcast();
+    let other = flower.clone();
+    let handle = flower.handle();
+    handle.activate();
+    handle.ok(1);
+
+    let mut result = None;
+    assert!(flower.then_wait(Duration::from_secs(5), |_| (), |r| result = Some(r)));
+    assert_eq!(result, Some(Ok(1)));
+    // Already observed by this clone, the other one still gets it.
+    assert!(!flower.then_wait(Duration::from_millis(20), |_| (), |_| panic!("observed")));
+    assert!(other.then_wait(Duration::from_secs(5), |_| (), |_| ()));
+}
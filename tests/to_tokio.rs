@@ -0,0 +1,85 @@
+#![cfg(feature = "tokio")]
+use flowync::{Flower, FlowerBuilder, SendErrorReason};
+use std::time::Duration;
+
+#[tokio::test]
+async fn handles_feed_the_tokio_receivers() {
+    let flower: Flower<u32, String> = Flower::new(1);
+    let (mut values, result) = flower.to_tokio();
+    let handle = flower.handle();
+    handle.activate();
+    tokio::spawn(async move {
+        for i in 0..3 {
+            handle.send_async(i).await.unwrap();
+        }
+        handle.ok("done".into());
+    });
+
+    let mut received = Vec::new();
+    while let Some(value) = values.recv().await {
+        received.push(value);
+    }
+    assert_eq!(received, vec![0, 1, 2]);
+    assert_eq!(result.await, Ok(Ok("done".to_string())));
+    assert!(!flower.is_active());
+}
+
+#[tokio::test]
+async fn full_receiver_applies_backpressure() {
+    let flower: Flower<u32, ()> = FlowerBuilder::new(2).capacity(2).build();
+    let (mut values, _result) = flower.to_tokio();
+    let handle = flower.handle();
+    handle.activate();
+    let worker = std::thread::spawn(move || {
+        for i in 0..4 {
+            handle.send(i).unwrap();
+        }
+    });
+    tokio::time::sleep(Duration::from_millis(20)).await;
+    // Two values fit, the worker is blocked on the third one.
+    assert!(!worker.is_finished());
+    for i in 0..4 {
+        assert_eq!(values.recv().await, Some(i));
+    }
+    worker.join().unwrap();
+}
+
+#[tokio::test]
+async fn dropped_receivers_fail_the_sends() {
+    let flower: Flower<u32, String> = Flower::new(3);
+    let (values, result) = flower.to_tokio();
+    let handle = flower.handle();
+    handle.activate();
+    drop(values);
+    drop(result);
+
+    let err = handle.send_async(1).await.unwrap_err();
+    assert_eq!(err.reason(), SendErrorReason::ConsumerDropped);
+    let worker = std::thread::spawn(move || {
+        let err = handle.send(2).unwrap_err();
+        assert_eq!(err.reason(), SendErrorReason::ConsumerDropped);
+        handle.ok("kept".into());
+    });
+    worker.join().unwrap();
+    assert_eq!(flower.try_result(), Some(Ok("kept".to_string())));
+}
+
+#[tokio::test]
+async fn cancel_releases_a_blocked_send() {
+    let flower: Flower<u32, ()> = Flower::new(4);
+    let (_values, _result) = flower.to_tokio();
+    let handle = flower.handle();
+    handle.activate();
+    handle.send_async(1).await.unwrap();
+    let task = tokio::spawn(async move { handle.send_async(2).await });
+    tokio::time::sleep(Duration::from_millis(20)).await;
+    assert!(!task.is_finished());
+    flower.cancel();
+    let err = tokio::time::timeout(Duration::from_secs(5), task)
+        .await
+        .unwrap()
+        .unwrap()
+        .unwrap_err();
+    assert_eq!(err.reason(), SendErrorReason::Canceled);
+    assert_eq!(err.into_inner(), 2);
+}
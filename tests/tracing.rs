@@ -0,0 +1,165 @@
+#![cfg(feature = "tracing")]
+use flowync::{Flower, FlowerBuilder};
+use std::{
+    io::{self, Write},
+    panic::{self, AssertUnwindSafe},
+    sync::{Arc, Mutex},
+};
+use tracing_subscriber::fmt::MakeWriter;
+
+#[derive(Clone, Default)]
+struct Captured(Arc<Mutex<Vec<u8>>>);
+
+impl Write for Captured {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.0.lock().unwrap().extend_from_slice(buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+impl<'a> MakeWriter<'a> for Captured {
+    type Writer = Self;
+
+    fn make_writer(&'a self) -> Self {
+        self.clone()
+    }
+}
+
+// The events logged on the current thread while running `f`, one line each.
+fn traced(max_level: tracing::Level, f: impl FnOnce()) -> Vec<String> {
+    let captured = Captured::default();
+    let subscriber = tracing_subscriber::fmt()
+        .with_writer(captured.clone())
+        .with_max_level(max_level)
+        .with_ansi(false)
+        .without_time()
+        .with_target(false)
+        .finish();
+    tracing::subscriber::with_default(subscriber, f);
+    let output = String::from_utf8(captured.0.lock().unwrap().clone()).unwrap();
+    output.lines().map(|line| line.trim().to_string()).collect()
+}
+
+#[test]
+fn events_fire_in_order() {
+    let lines = traced(tracing::Level::TRACE, || {
+        let flower: Flower<u32, ()> = FlowerBuilder::new(1).label("job").capacity(4).build();
+        flower.trace_values();
+        let handle = flower.handle();
+        handle.activate();
+        handle.send(7).unwrap();
+        handle.ok(());
+        handle.err("ignored");
+        let _ = flower.try_result();
+        flower.cancel();
+    });
+    let expected = [
+        "DEBUG flower{id=1 label=\"job\"}: activate",
+        "TRACE flower{id=1 label=\"job\"}: send value=7",
+        "DEBUG flower{id=1 label=\"job\"}: ok",
+        "TRACE flower{id=1 label=\"job\"}: result discarded",
+        "DEBUG flower{id=1 label=\"job\"}: cancel",
+    ];
+    assert_eq!(lines, expected);
+}
+
+#[test]
+fn values_are_hidden_unless_enabled() {
+    let lines = traced(tracing::Level::TRACE, || {
+        let flower: Flower<u32, ()> = FlowerBuilder::new(2).capacity(1).build();
+        let handle = flower.handle();
+        handle.activate();
+        handle.send(7).unwrap();
+    });
+    assert_eq!(lines[1], "TRACE flower{id=2}: send value=..");
+}
+
+#[test]
+fn err_and_cancel_ack_tell_the_kind() {
+    let lines = traced(tracing::Level::DEBUG, || {
+        let flower: Flower<(), ()> = Flower::new(3);
+        let handle = flower.handle();
+        handle.activate();
+        handle.err("broken");
+        let _ = flower.try_result();
+
+        flower.cancel();
+        let handle = flower.handle();
+        handle.activate();
+        handle.cancel_ack();
+    });
+    assert_eq!(
+        lines,
+        [
+            "DEBUG flower{id=3}: activate",
+            "DEBUG flower{id=3}: err kind=Custom",
+            "DEBUG flower{id=3}: cancel",
+            "DEBUG flower{id=3}: activate",
+            "DEBUG flower{id=3}: cancel acknowledged",
+        ]
+    );
+}
+
+#[test]
+fn dropped_handles_warn() {
+    let lines = traced(tracing::Level::INFO, || {
+        let flower: Flower<(), ()> = Flower::new(4);
+        let handle = flower.handle();
+        handle.activate();
+        let _ = panic::catch_unwind(AssertUnwindSafe(move || {
+            let _handle = handle;
+            panic!("worker panicked");
+        }));
+
+        let flower: Flower<(), ()> = Flower::new(5);
+        let handle = flower.handle();
+        handle.activate();
+        drop(handle);
+    });
+    assert_eq!(
+        lines,
+        [
+            "WARN flower{id=4}: flower handle dropped while panicking",
+            "WARN flower{id=5}: flower handles dropped without result",
+        ]
+    );
+}
+
+#[test]
+fn set_label_is_recorded_on_the_span() {
+    let lines = traced(tracing::Level::DEBUG, || {
+        let flower: Flower<u32, ()> = Flower::new(5);
+        flower.set_label("late");
+        let handle = flower.handle();
+        handle.activate();
+        handle.ok(());
+    });
+    assert_eq!(lines[0], "DEBUG flower{id=5 label=\"late\"}: activate");
+}
+
+#[test]
+fn send_timeout_events() {
+    let lines = traced(tracing::Level::TRACE, || {
+        let flower: Flower<u32, ()> = FlowerBuilder::new(3).capacity(1).build();
+        flower.trace_values();
+        let handle = flower.handle();
+        handle.activate();
+        handle
+            .send_timeout(7, std::time::Duration::from_millis(1))
+            .unwrap();
+        let _ = handle.send_timeout(8, std::time::Duration::from_millis(1));
+        handle.ok(());
+    });
+    let expected = [
+        "DEBUG flower{id=3}: activate",
+        "TRACE flower{id=3}: send_timeout value=7",
+        "TRACE flower{id=3}: send_timeout value=8",
+        "DEBUG flower{id=3}: send_timeout rejected",
+        "DEBUG flower{id=3}: ok",
+    ];
+    assert_eq!(lines, expected);
+}
@@ -0,0 +1,26 @@
+use flowync::Flower;
+
+#[test]
+fn try_result_discards_channel() {
+    let flower = Flower::<u32, u32>::new(1);
+    std::thread::spawn({
+        let handle = flower.handle();
+        handle.activate();
+        move || {
+            for i in 0..10 {
+                handle.send(i).unwrap();
+            }
+            handle.ok(42);
+        }
+    });
+
+    let result = loop {
+        if let Some(result) = flower.try_result() {
+            break result;
+        }
+    };
+
+    assert_eq!(result, Ok(42));
+    assert!(!flower.is_active());
+    assert_eq!(flower.try_result(), None);
+}
@@ -0,0 +1,129 @@
+use flowync::{Flower, FlowerBuilder};
+use std::sync::{
+    atomic::{AtomicUsize, Ordering},
+    mpsc, Arc,
+};
+use std::time::{Duration, Instant};
+
+#[test]
+fn try_then_behaves_like_then_when_uncontended() {
+    let flower: Flower<u32, String> = Flower::new(1);
+    let handle = flower.handle();
+    handle.activate();
+
+    let mut idle = false;
+    assert!(flower.try_then(|value| idle = value.is_none(), |_| panic!("no result yet")));
+    assert!(idle);
+
+    handle.ok("done".into());
+    let mut result = None;
+    assert!(flower.try_then(|_| panic!("no value"), |r| result = Some(r)));
+    assert_eq!(result, Some(Ok("done".to_string())));
+    assert!(!flower.is_active());
+}
+
+#[test]
+fn try_then_returns_promptly_while_the_lock_is_held() {
+    let flower: Flower<u32, String> = FlowerBuilder::new(2).capacity(1).build();
+    let handle = flower.handle();
+    handle.activate();
+    handle.send(1).unwrap();
+    handle.ok("done".into());
+
+    // `peek_channel` runs its closure with the channel locked, like a slow `send`.
+    let (locked, wait_locked) = mpsc::channel();
+    let holder = std::thread::spawn({
+        let flower = flower.clone();
+        move || {
+            flower.peek_channel(|_| {
+                locked.send(()).unwrap();
+                std::thread::sleep(Duration::from_millis(300));
+            })
+        }
+    });
+    wait_locked.recv().unwrap();
+
+    let start = Instant::now();
+    assert!(!flower.try_then(
+        |_| panic!("nothing is invoked"),
+        |_| panic!("nothing is invoked")
+    ));
+    assert!(start.elapsed() < Duration::from_millis(100));
+    // The flags are untouched, the result is still there.
+    assert!(flower.result_is_ready());
+    assert!(flower.is_active());
+
+    holder.join().unwrap();
+    let mut value = None;
+    assert!(flower.try_then(|v| value = v, |_| panic!("the value comes first")));
+    assert_eq!(value, Some(1));
+    let mut result = None;
+    assert!(flower.try_then(|_| (), |r| result = Some(r)));
+    assert_eq!(result, Some(Ok("done".to_string())));
+}
+
+#[test]
+fn result_is_taken_while_the_channel_is_locked() {
+    let flower: Flower<u32, String> = Flower::new(3);
+    let handle = flower.handle();
+    handle.activate();
+    handle.ok("done".into());
+
+    let (locked, wait_locked) = mpsc::channel();
+    let (release, wait_release) = mpsc::channel::<()>();
+    let holder = std::thread::spawn({
+        let flower = flower.clone();
+        move || {
+            flower.peek_channel(|_| {
+                locked.send(()).unwrap();
+                let _ = wait_release.recv();
+            })
+        }
+    });
+    wait_locked.recv().unwrap();
+
+    let mut result = None;
+    assert!(flower.try_then(|_| panic!("no value"), |r| result = Some(r)));
+    assert_eq!(result, Some(Ok("done".to_string())));
+    release.send(()).unwrap();
+    holder.join().unwrap();
+}
+
+#[test]
+fn contended_try_then_keeps_the_pending_wake() {
+    let flower: Flower<u32, String> = FlowerBuilder::new(4).capacity(4).build();
+    let wakes = Arc::new(AtomicUsize::new(0));
+    flower.set_wake_notifier({
+        let wakes = wakes.clone();
+        move || {
+            wakes.fetch_add(1, Ordering::SeqCst);
+        }
+    });
+    let handle = flower.handle();
+    handle.activate();
+    handle.send(1).unwrap();
+    assert_eq!(wakes.load(Ordering::SeqCst), 1);
+
+    let (locked, wait_locked) = mpsc::channel();
+    let (release, wait_release) = mpsc::channel::<()>();
+    let holder = std::thread::spawn({
+        let flower = flower.clone();
+        move || {
+            flower.peek_channel(|_| {
+                locked.send(()).unwrap();
+                let _ = wait_release.recv();
+            })
+        }
+    });
+    wait_locked.recv().unwrap();
+    assert!(!flower.try_then(|_| (), |_| ()));
+    release.send(()).unwrap();
+    holder.join().unwrap();
+
+    // Still waiting for the poll of the first wake.
+    handle.send(2).unwrap();
+    assert_eq!(wakes.load(Ordering::SeqCst), 1);
+    assert!(flower.try_then(|_| (), |_| ()));
+    handle.send(3).unwrap();
+    assert_eq!(wakes.load(Ordering::SeqCst), 2);
+}
@@ -0,0 +1,44 @@
+use flowync::Flower;
+use std::time::Duration;
+
+#[test]
+fn wait_for_result() {
+    let flower = Flower::<u32, u32>::new(1);
+    std::thread::spawn({
+        let handle = flower.handle();
+        handle.activate();
+        move || {
+            for i in 0..10 {
+                std::thread::sleep(Duration::from_millis(5));
+                handle.send(i).unwrap();
+            }
+            handle.ok(42);
+        }
+    });
+
+    assert_eq!(flower.wait(), Ok(42));
+    assert!(!flower.is_active());
+}
+
+#[test]
+fn wait_timeout_deadline() {
+    let flower = Flower::<u32, u32>::new(1);
+    let handle = flower.handle();
+    handle.activate();
+    let worker = std::thread::spawn(move || {
+        // Keep sending progress, the deadline must not be reset by it.
+        for i in 0..40 {
+            std::thread::sleep(Duration::from_millis(10));
+            handle.send(i).unwrap();
+        }
+        handle.ok(42);
+    });
+
+    let instant = std::time::Instant::now();
+    assert_eq!(flower.wait_timeout(Duration::from_millis(100)), None);
+    assert!(instant.elapsed() < Duration::from_millis(300));
+    assert!(flower.is_active());
+
+    assert_eq!(flower.wait_timeout(Duration::from_secs(5)), Some(Ok(42)));
+    worker.join().unwrap();
+}
@@ -0,0 +1,61 @@
+#![cfg(target_arch = "wasm32")]
+use flowync::Flower;
+use std::{
+    future::Future,
+    pin::Pin,
+    task::{Context, Poll},
+};
+use wasm_bindgen_test::wasm_bindgen_test;
+
+// Give the spawned worker a chance to run, like waiting for the next frame.
+struct NextFrame(bool);
+
+impl Future for NextFrame {
+    type Output = ();
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<()> {
+        if self.0 {
+            return Poll::Ready(());
+        }
+        self.0 = true;
+        cx.waker().wake_by_ref();
+        Poll::Pending
+    }
+}
+
+#[wasm_bindgen_test]
+async fn async_worker_polled_consumer() {
+    let flower: Flower<u32, String> = Flower::new(1);
+    let handle = flower.handle();
+    handle.activate();
+    wasm_bindgen_futures::spawn_local(async move {
+        for i in 0..3 {
+            handle.send_async(i).await.unwrap();
+        }
+        handle.ok("done".into());
+    });
+
+    let mut values = Vec::new();
+    let mut result = None;
+    while result.is_none() {
+        flower.then(|value| values.extend(value), |r| result = Some(r));
+        NextFrame(false).await;
+    }
+    assert_eq!(values, vec![0, 1, 2]);
+    assert_eq!(result, Some(Ok("done".to_string())));
+}
+
+#[wasm_bindgen_test]
+fn send_never_blocks() {
+    let flower: Flower<u32, ()> = Flower::new(2);
+    let handle = flower.handle();
+    handle.activate();
+    handle.send(1).unwrap();
+    handle.send(2).unwrap();
+    handle.ok(());
+    let mut values = Vec::new();
+    let mut done = false;
+    while !done {
+        flower.then(|value| values.extend(value), |_| done = true);
+    }
+    assert_eq!(values, vec![2]);
+}
@@ -0,0 +1,63 @@
+use flowync::{Flower, FlowerBuilder};
+use std::sync::{
+    atomic::{AtomicBool, Ordering},
+    Arc,
+};
+
+// Flags when the state holding it is freed.
+struct Tracked(Arc<AtomicBool>);
+
+impl Drop for Tracked {
+    fn drop(&mut self) {
+        self.0.store(true, Ordering::SeqCst);
+    }
+}
+
+#[test]
+fn upgrade_gives_the_same_flower() {
+    let flower: Flower<u32, ()> = Flower::new(1);
+    let weak = flower.downgrade();
+    assert!(weak.is_alive());
+    let upgraded = weak.upgrade().unwrap();
+    assert!(upgraded.same_flower(&flower));
+
+    let handle = flower.handle();
+    handle.activate();
+    handle.send_latest(3);
+    let mut value = None;
+    upgraded.then(|v| value = v, |_| ());
+    assert_eq!(value, Some(3));
+}
+
+#[test]
+fn upgrade_fails_once_the_flowers_are_dropped() {
+    let freed = Arc::new(AtomicBool::new(false));
+    let flower: Flower<(), Tracked> = Flower::new(2);
+    let weak = flower.downgrade();
+    let handle = flower.handle();
+    handle.activate();
+    handle.ok(Tracked(Arc::clone(&freed)));
+
+    drop(flower.clone());
+    assert!(weak.is_alive());
+    drop(flower);
+    // The handle keeps the state, but no consumer is left to bring back.
+    assert!(!weak.is_alive());
+    assert!(weak.upgrade().is_none());
+    assert!(!freed.load(Ordering::SeqCst));
+
+    drop(handle);
+    assert!(freed.load(Ordering::SeqCst));
+    assert!(weak.upgrade().is_none());
+}
+
+#[test]
+fn weak_flower_does_not_hold_off_cancel_on_drop() {
+    let flower: Flower<(), ()> = FlowerBuilder::new(3).cancel_on_drop(true).build();
+    let weak = flower.downgrade();
+    let handle = flower.handle();
+    handle.activate();
+    drop(flower);
+    assert!(handle.should_cancel());
+    assert!(weak.upgrade().is_none());
+}